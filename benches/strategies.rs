@@ -0,0 +1,92 @@
+// Multiplication strategies and decompositions over a range of sizes.
+//
+// Run with `cargo bench`, or `cargo bench --all-features` to include the
+// threaded kernels. A trailing argument keeps only benchmarks whose name
+// contains it, e.g. `cargo bench -- mult`.
+
+use matrix::matrix::Matrix;
+use matrix::random::SplitMix64;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 4] = [32, 64, 128, 256];
+const TARGET: Duration = Duration::from_millis(300);
+const SAMPLES: usize = 11;
+
+/// Runs `f` in batches until each of `SAMPLES` batches takes about
+/// `TARGET / SAMPLES`, then reports the median time per call.
+fn bench<R, F: FnMut() -> R>(filter: Option<&str>, name: &str, n: usize, mut f: F) {
+    let label = format!("{}/{}", name, n);
+    if filter.is_some_and(|filter| !label.contains(filter)) {
+        return;
+    }
+
+    let mut batch = 1u32;
+    loop {
+        let start = Instant::now();
+        for _ in 0..batch {
+            black_box(f());
+        }
+        if start.elapsed() * SAMPLES as u32 >= TARGET || batch >= 1 << 20 {
+            break;
+        }
+        batch *= 2;
+    }
+
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..batch {
+                black_box(f());
+            }
+            start.elapsed() / batch
+        })
+        .collect();
+    samples.sort();
+    println!("{:<28} {:>12.1?}", label, samples[SAMPLES / 2]);
+}
+
+/// A diagonally dominant, symmetric positive-definite `n`x`n` matrix, so
+/// every decomposition below succeeds.
+fn spd(n: usize, rng: &mut SplitMix64) -> Matrix<f64> {
+    Matrix::random_spd(n, n as f64, rng).unwrap()
+}
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    let filter = filter.as_deref();
+    let mut rng = SplitMix64::new(0x5EED);
+
+    for n in SIZES {
+        let a: Matrix<f64> = Matrix::random_uniform(n, n, &mut rng).unwrap();
+        let b: Matrix<f64> = Matrix::random_uniform(n, n, &mut rng).unwrap();
+
+        bench(filter, "mult_naive", n, || a.mult_naive(&b).unwrap());
+        bench(filter, "mult_blocked_64", n, || {
+            a.mult_blocked(&b, 64).unwrap()
+        });
+        bench(filter, "mult_strassen", n, || a.mult_strassen(&b).unwrap());
+        bench(filter, "mult", n, || a.mult(&b).unwrap());
+        #[cfg(feature = "parallel")]
+        bench(filter, "par_mult_naive", n, || {
+            a.par_mult_naive(&b).unwrap()
+        });
+    }
+
+    for n in SIZES {
+        let a = spd(n, &mut rng);
+        let rhs: Matrix<f64> = Matrix::random_uniform(n, 1, &mut rng).unwrap();
+
+        bench(filter, "lu", n, || a.lu().unwrap());
+        let lu = a.lu().unwrap();
+        bench(filter, "lu_solve", n, || lu.solve(&rhs).unwrap());
+        bench(filter, "qr", n, || a.qr());
+        bench(filter, "cholesky", n, || a.cholesky().unwrap());
+        if n <= 128 {
+            bench(filter, "symmetric_eigen", n, || {
+                a.symmetric_eigen().unwrap()
+            });
+            bench(filter, "svd", n, || a.svd());
+        }
+    }
+}
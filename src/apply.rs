@@ -0,0 +1,94 @@
+use crate::matrix::Matrix;
+
+impl<T: Clone> Matrix<T> {
+    /// Calls `f` on every row in place, e.g. to normalize each row. Like
+    /// [`Matrix::map_rows`], an `m`x`0` matrix still has `m` empty rows.
+    pub fn apply_rows<F: FnMut(&mut [T])>(&mut self, mut f: F) -> &mut Self {
+        if self.cols == 0 {
+            for _ in 0..self.rows {
+                f(&mut []);
+            }
+            return self;
+        }
+        for row in self.values.chunks_mut(self.cols) {
+            f(row);
+        }
+        self
+    }
+
+    /// Calls `f` on every column in place. Columns are strided, so each is
+    /// copied into a scratch buffer for `f` and written back afterwards.
+    pub fn apply_cols<F: FnMut(&mut [T])>(&mut self, mut f: F) -> &mut Self {
+        let mut column = Vec::with_capacity(self.rows);
+        for j in 0..self.cols {
+            column.clear();
+            column.extend((0..self.rows).map(|i| self.values[i * self.cols + j].clone()));
+            f(&mut column);
+            for (i, value) in column.drain(..).enumerate() {
+                self.values[i * self.cols + j] = value;
+            }
+        }
+        self
+    }
+
+    /// One result per row, such as a row norm or maximum.
+    pub fn map_rows<U, F: FnMut(&[T]) -> U>(&self, f: F) -> Vec<U> {
+        if self.cols == 0 {
+            let empty: &[T] = &[];
+            return (0..self.rows).map(|_| empty).map(f).collect();
+        }
+        self.values.chunks(self.cols).map(f).collect()
+    }
+
+    /// One result per column.
+    pub fn map_cols<U, F: FnMut(&[T]) -> U>(&self, mut f: F) -> Vec<U> {
+        let mut column = Vec::with_capacity(self.rows);
+        (0..self.cols)
+            .map(|j| {
+                column.clear();
+                column.extend((0..self.rows).map(|i| self.values[i * self.cols + j].clone()));
+                f(&column)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_apply() {
+        let mut matrix: Matrix<f64> = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+
+        matrix.apply_rows(|row| {
+            let total: f64 = row.iter().sum();
+            row.iter_mut().for_each(|v| *v /= total);
+        });
+        assert_eq!(
+            matrix.map_rows(|row| row.iter().sum::<f64>()),
+            vec![1.0, 1.0]
+        );
+
+        matrix.apply_cols(|col| {
+            let mean = col.iter().sum::<f64>() / col.len() as f64;
+            col.iter_mut().for_each(|v| *v -= mean);
+        });
+        assert_eq!(matrix.map_cols(|col| col.iter().sum::<f64>()), vec![0.0; 3]);
+        assert_eq!(matrix.map_cols(|col| col.len()), vec![2; 3]);
+    }
+
+    #[test]
+    fn check_apply_empty() {
+        let mut tall: Matrix<i32> = Matrix::new(3, 0, vec![]);
+        let mut calls = 0;
+        tall.apply_rows(|row| {
+            assert!(row.is_empty());
+            calls += 1;
+        })
+        .apply_cols(|_| unreachable!());
+        assert_eq!(calls, 3);
+        assert_eq!(tall.map_rows(|row| row.len()), vec![0; 3]);
+        assert!(tall.map_cols(|col| col.len()).is_empty());
+    }
+}
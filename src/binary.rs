@@ -0,0 +1,104 @@
+use crate::dtype::DType;
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+
+const MAGIC: &[u8; 4] = b"LRMX";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 24;
+
+/// Layout, all integers little-endian:
+///
+/// | bytes  | field                                  |
+/// |--------|----------------------------------------|
+/// | 0..4   | magic `LRMX`                           |
+/// | 4      | format version, currently 1            |
+/// | 5      | dtype kind, `f`, `i` or `u` as ASCII   |
+/// | 6      | dtype size in bytes                    |
+/// | 7      | reserved, 0                            |
+/// | 8..16  | rows as `u64`                          |
+/// | 16..24 | cols as `u64`                          |
+/// | 24..   | values in row-major order              |
+///
+/// This is the crate's format for persisting matrices to disk or sending
+/// them over the network; there is no `serde` feature, since the crate has
+/// no dependencies. A caller already using serde can store the `rows`,
+/// `cols` and row-major [`Matrix::into_vec`] triple and read it back
+/// through [`Matrix::try_new`], which rejects a mismatched length.
+impl<T: DType> Matrix<T> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.values.len() * T::SIZE);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[VERSION, T::KIND as u8, T::SIZE as u8, 0]);
+        bytes.extend_from_slice(&(self.rows as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.cols as u64).to_le_bytes());
+        for &value in &self.values {
+            value.write_le(&mut bytes);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Matrix<T>, MatrixError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(MatrixError::ParseError("Missing matrix header".to_string()));
+        }
+        if bytes[4] != VERSION {
+            return Err(MatrixError::ParseError(format!(
+                "Unsupported matrix format version {}",
+                bytes[4]
+            )));
+        }
+        if bytes[5] != T::KIND as u8 || bytes[6] as usize != T::SIZE {
+            return Err(MatrixError::ParseError(format!(
+                "Stored dtype {}{} does not match the requested {}{}",
+                bytes[5] as char,
+                bytes[6],
+                T::KIND,
+                T::SIZE
+            )));
+        }
+
+        let dimension = |range: std::ops::Range<usize>| {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(&bytes[range]);
+            usize::try_from(u64::from_le_bytes(buffer)).map_err(|_| {
+                MatrixError::SizeOverflow("Stored dimension does not fit in usize".to_string())
+            })
+        };
+        let rows = dimension(8..16)?;
+        let cols = dimension(16..24)?;
+
+        let len = checked_len(rows, cols)?;
+        let data = &bytes[HEADER_LEN..];
+        if Some(data.len()) != len.checked_mul(T::SIZE) {
+            return Err(MatrixError::ParseError(format!(
+                "Expected {} values for a {}x{} matrix, found {} bytes",
+                len,
+                rows,
+                cols,
+                data.len()
+            )));
+        }
+
+        let values = data.chunks_exact(T::SIZE).map(T::read_le).collect();
+        Ok(Matrix::new(rows, cols, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_bytes_round_trip() {
+        let matrix: Matrix<i16> = Matrix::new(2, 2, vec![1, -2, 300, i16::MIN]);
+        let bytes = matrix.to_bytes();
+
+        assert_eq!(&bytes[..8], b"LRMX\x01i\x02\x00");
+        assert_eq!(bytes.len(), 24 + 4 * 2);
+        assert_eq!(Matrix::<i16>::from_bytes(&bytes).unwrap(), matrix);
+
+        assert!(Matrix::<u16>::from_bytes(&bytes).is_err());
+        assert!(Matrix::<i16>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Matrix::<i16>::from_bytes(&bytes[1..]).is_err());
+    }
+}
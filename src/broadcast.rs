@@ -0,0 +1,78 @@
+use crate::error::{elementwise_mismatch, MatrixError, Op};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// Combines every element of `self` with the element of `matrix_b` in
+    /// the same position, where a `1`x`n` row of `matrix_b` is reused for
+    /// every row and an `m`x`1` column for every column.
+    fn zip_broadcast(
+        &self,
+        matrix_b: &Matrix<T>,
+        op: Op,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let rows_match = matrix_b.rows == self.rows || matrix_b.rows == 1;
+        let cols_match = matrix_b.cols == self.cols || matrix_b.cols == 1;
+        if !rows_match || !cols_match {
+            return Err(elementwise_mismatch(
+                op,
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let row_step = if matrix_b.rows == 1 { 0 } else { matrix_b.cols };
+        let col_step = if matrix_b.cols == 1 { 0 } else { 1 };
+        Ok(Matrix::from_fn(self.rows, self.cols, |i, j| {
+            f(
+                self.values[i * self.cols + j].clone(),
+                matrix_b.values[i * row_step + j * col_step].clone(),
+            )
+        }))
+    }
+
+    /// `self + matrix_b`, where `matrix_b` may also be a `1`x`cols` row
+    /// added to every row, an `rows`x`1` column added to every column, or a
+    /// `1`x`1` matrix added everywhere.
+    pub fn add_broadcast(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_broadcast(matrix_b, Op::Add, |a, b| a + b)
+    }
+
+    /// `self - matrix_b` with the broadcasting of [`Matrix::add_broadcast`],
+    /// so `data.subtract_broadcast(&column_means)` centers every column.
+    pub fn subtract_broadcast(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_broadcast(matrix_b, Op::Subtract, |a, b| a - b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_broadcast() {
+        let data: Matrix<f64> = Matrix::new(3, 2, vec![1.0, 10.0, 2.0, 20.0, 3.0, 30.0]);
+        let mut means = data.col_sums();
+        means.mult_scalar(1.0 / 3.0);
+
+        let centered = data.subtract_broadcast(&means).unwrap();
+        assert_eq!(centered.values, vec![-1.0, -10.0, 0.0, 0.0, 1.0, 10.0]);
+        assert_eq!(centered.col_sums(), Matrix::new(1, 2, vec![0.0, 0.0]));
+
+        let offsets = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+        assert_eq!(
+            data.add_broadcast(&offsets).unwrap().values,
+            vec![2.0, 11.0, 4.0, 22.0, 6.0, 33.0]
+        );
+        assert_eq!(data.add_broadcast(&data).unwrap(), data.add(&data).unwrap());
+        assert_eq!(
+            data.add_broadcast(&Matrix::from_scalar(1.0))
+                .unwrap()
+                .values[0],
+            2.0
+        );
+        assert!(data.add_broadcast(&means.transpose()).is_err());
+        assert!(data.add_broadcast(&Matrix::new(1, 0, vec![])).is_err());
+    }
+}
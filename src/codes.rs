@@ -0,0 +1,185 @@
+use crate::error::{MatrixError, Op};
+use crate::gf2::MatrixGF2;
+
+/// Binary linear codes, with a code given by the row space of a generator
+/// (or parity-check) matrix.
+impl MatrixGF2 {
+    /// A basis of the dual code, as rows: the parity-check matrix when
+    /// `self` is a generator matrix and vice versa. Linearly dependent rows
+    /// in `self` are allowed.
+    pub fn dual(&self) -> Result<MatrixGF2, MatrixError> {
+        let mut reduced = self.clone();
+        let pivots = reduced.rref_mut();
+        let free: Vec<usize> = (0..self.get_cols())
+            .filter(|col| !pivots.contains(col))
+            .collect();
+
+        let mut dual = MatrixGF2::zeros(free.len(), self.get_cols())?;
+        for (row, &free_col) in free.iter().enumerate() {
+            dual.set_bit(row, free_col, true);
+            for (pivot_row, &pivot_col) in pivots.iter().enumerate() {
+                dual.set_bit(row, pivot_col, reduced.bit(pivot_row, free_col));
+            }
+        }
+        Ok(dual)
+    }
+
+    pub fn parity_check_from_generator(&self) -> Result<MatrixGF2, MatrixError> {
+        self.dual()
+    }
+
+    pub fn generator_from_parity_check(&self) -> Result<MatrixGF2, MatrixError> {
+        self.dual()
+    }
+
+    /// Rewrites a full-rank generator matrix as `[I | P]`. Column `j` of the
+    /// result is column `permutation[j]` of `self`, so the permutation is the
+    /// identity whenever the leading columns are already independent.
+    pub fn systematic_form(&self) -> Result<(MatrixGF2, Vec<usize>), MatrixError> {
+        let mut reduced = self.clone();
+        let pivots = reduced.rref_mut();
+        if pivots.len() != self.get_rows() {
+            return Err(MatrixError::InvalidArgument(format!(
+                "A generator matrix with {} rows has only rank {}",
+                self.get_rows(),
+                pivots.len()
+            )));
+        }
+
+        let mut permutation = pivots.clone();
+        permutation.extend((0..self.get_cols()).filter(|col| !pivots.contains(col)));
+
+        let mut systematic = MatrixGF2::zeros(self.get_rows(), self.get_cols())?;
+        for row in 0..self.get_rows() {
+            for (col, &source) in permutation.iter().enumerate() {
+                systematic.set_bit(row, col, reduced.bit(row, source));
+            }
+        }
+        Ok((systematic, permutation))
+    }
+
+    /// `H w` for a parity-check matrix `H`: all zero exactly when `word` is a
+    /// codeword, and equal to the sum of the columns at the error positions.
+    pub fn syndrome(&self, word: &[bool]) -> Result<Vec<bool>, MatrixError> {
+        if word.len() != self.get_cols() {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Multiply,
+                lhs: (self.get_rows(), self.get_cols()),
+                rhs: (word.len(), 1),
+            });
+        }
+
+        let mut packed = MatrixGF2::zeros(1, word.len())?;
+        for (col, &bit) in word.iter().enumerate() {
+            packed.set_bit(0, col, bit);
+        }
+
+        Ok((0..self.get_rows())
+            .map(|row| {
+                let ones: u32 = self
+                    .row(row)
+                    .iter()
+                    .zip(packed.row(0))
+                    .map(|(a, b)| (a & b).count_ones())
+                    .sum();
+                ones % 2 == 1
+            })
+            .collect())
+    }
+
+    /// The minimum Hamming distance of the code generated by `self`, found by
+    /// searching for the smallest set of parity-check columns that sums to
+    /// zero. Returns `None` if the distance exceeds `max_weight`; the search
+    /// visits up to `n choose max_weight` column sets.
+    pub fn minimum_distance(&self, max_weight: usize) -> Result<Option<usize>, MatrixError> {
+        let columns = self.dual()?.transpose();
+        let words = columns.row(0).len();
+
+        fn search(columns: &MatrixGF2, start: usize, remaining: usize, sum: &mut [u64]) -> bool {
+            if remaining == 0 {
+                return sum.iter().all(|&w| w == 0);
+            }
+            for col in start..columns.get_rows() {
+                for (s, c) in sum.iter_mut().zip(columns.row(col)) {
+                    *s ^= c;
+                }
+                let found = search(columns, col + 1, remaining - 1, sum);
+                for (s, c) in sum.iter_mut().zip(columns.row(col)) {
+                    *s ^= c;
+                }
+                if found {
+                    return true;
+                }
+            }
+            false
+        }
+
+        let mut sum = vec![0u64; words];
+        for weight in 1..=max_weight.min(self.get_cols()) {
+            if search(&columns, 0, weight, &mut sum) {
+                return Ok(Some(weight));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gf2::MatrixGF2;
+
+    fn hamming_generator() -> MatrixGF2 {
+        MatrixGF2::from_values(
+            4,
+            7,
+            &[
+                1, 0, 0, 0, 1, 1, 0, //
+                0, 1, 0, 0, 0, 1, 1, //
+                0, 0, 1, 0, 1, 1, 1, //
+                0, 0, 0, 1, 1, 0, 1,
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn check_hamming_code() {
+        let generator = hamming_generator();
+        let parity_check = generator.parity_check_from_generator().unwrap();
+
+        assert_eq!(parity_check.get_rows(), 3);
+        assert_eq!(
+            generator.mult(&parity_check.transpose()).unwrap(),
+            MatrixGF2::zeros(4, 3).unwrap()
+        );
+        assert_eq!(
+            parity_check.generator_from_parity_check().unwrap().rank(),
+            4
+        );
+        assert_eq!(generator.minimum_distance(5).unwrap(), Some(3));
+        assert_eq!(generator.minimum_distance(2).unwrap(), None);
+
+        let codeword: Vec<bool> = [1, 0, 1, 1, 1, 0, 0].iter().map(|&b| b == 1).collect();
+        assert_eq!(parity_check.syndrome(&codeword).unwrap(), vec![false; 3]);
+
+        let mut received = codeword.clone();
+        received[5] = !received[5];
+        let column: Vec<bool> = (0..3)
+            .map(|row| parity_check.value_at(row, 5).unwrap())
+            .collect();
+        assert_eq!(parity_check.syndrome(&received).unwrap(), column);
+    }
+
+    #[test]
+    fn check_systematic_form() {
+        let generator = MatrixGF2::from_values(2, 4, &[1, 1, 0, 1, 1, 1, 1, 0]).unwrap();
+        let (systematic, permutation) = generator.systematic_form().unwrap();
+
+        assert_eq!(permutation, vec![0, 2, 1, 3]);
+        assert_eq!(systematic.to_values(), vec![1, 0, 1, 1, 0, 1, 0, 1]);
+        assert!(MatrixGF2::from_values(2, 2, &[1, 1, 1, 1])
+            .unwrap()
+            .systematic_form()
+            .is_err());
+    }
+}
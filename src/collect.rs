@@ -0,0 +1,107 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+
+impl<T> Matrix<T> {
+    /// Collects exactly `rows * cols` elements, in row-major order, without
+    /// an intermediate `Vec`. An iterator that runs out early or has
+    /// elements left over is an error; the leftovers are not consumed, so
+    /// an endless iterator is rejected rather than drained.
+    pub fn from_iter_shape<I>(rows: usize, cols: usize, iter: I) -> Result<Matrix<T>, MatrixError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let len = checked_len(rows, cols)?;
+        let mut iter = iter.into_iter();
+        let values: Vec<T> = iter.by_ref().take(len).collect();
+        if values.len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+        if iter.next().is_some() {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Iterator yields more than the {} elements of a {}x{} matrix",
+                len, rows, cols
+            )));
+        }
+        Ok(Matrix::new(rows, cols, values))
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Appends `row` below the last row. A matrix with no rows or columns
+    /// takes its width from the first row pushed, so `Matrix::new(0, 0,
+    /// vec![])` can start a stream of records of unknown count.
+    pub fn push_row(&mut self, row: &[T]) -> Result<&mut Self, MatrixError> {
+        if self.rows == 0 && self.cols == 0 {
+            self.cols = row.len();
+        } else if row.len() != self.cols {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.cols,
+                actual: row.len(),
+            });
+        }
+        self.rows = self
+            .rows
+            .checked_add(1)
+            .ok_or_else(|| MatrixError::SizeOverflow("Row count overflows usize".to_string()))?;
+        self.values.extend_from_slice(row);
+        Ok(self)
+    }
+
+    /// Pushes every row in turn. On a row of the wrong length the rows
+    /// before it stay appended and the rest are not read.
+    pub fn extend_rows<I, R>(&mut self, rows: I) -> Result<&mut Self, MatrixError>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[T]>,
+    {
+        for row in rows {
+            self.push_row(row.as_ref())?;
+        }
+        Ok(self)
+    }
+}
+
+/// `iter.collect_matrix(rows, cols)` for any iterator, the method form of
+/// [`Matrix::from_iter_shape`].
+pub trait CollectMatrix: Iterator + Sized {
+    fn collect_matrix(self, rows: usize, cols: usize) -> Result<Matrix<Self::Item>, MatrixError> {
+        Matrix::from_iter_shape(rows, cols, self)
+    }
+}
+
+impl<I: Iterator> CollectMatrix for I {}
+
+#[cfg(test)]
+mod tests {
+    use crate::collect::CollectMatrix;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_collect_matrix() {
+        let matrix = (0..6).map(|k| k * k).collect_matrix(2, 3).unwrap();
+        assert_eq!(matrix, Matrix::new(2, 3, vec![0, 1, 4, 9, 16, 25]));
+
+        assert!((0..5).collect_matrix(2, 3).is_err());
+        assert!((0..7).collect_matrix(2, 3).is_err());
+        assert!((0..).collect_matrix(2, 3).is_err());
+        assert!(Matrix::from_iter_shape(0, 3, Vec::<i32>::new()).is_ok());
+    }
+
+    #[test]
+    fn check_push_rows() {
+        let mut matrix: Matrix<i32> = Matrix::new(0, 0, vec![]);
+        matrix.push_row(&[1, 2]).unwrap();
+        matrix.extend_rows((3..5).map(|k| vec![k, k * 10])).unwrap();
+        assert_eq!(matrix, Matrix::new(3, 2, vec![1, 2, 3, 30, 4, 40]));
+
+        assert!(matrix.push_row(&[1, 2, 3]).is_err());
+        assert!(matrix.extend_rows([vec![5, 6], vec![7]]).is_err());
+        assert_eq!(matrix.get_rows(), 4);
+
+        let mut wide: Matrix<i32> = Matrix::new(0, 3, vec![]);
+        assert!(wide.push_row(&[1]).is_err());
+    }
+}
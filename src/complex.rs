@@ -0,0 +1,121 @@
+use crate::identity_element::IdentityElement;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Clone,
+{
+    type Output = Complex<T>;
+
+    fn mul(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re.clone() * other.re.clone() - self.im.clone() * other.im.clone(),
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+/// Lets complex matrices use the by-reference product kernels. The parts
+/// are cloned, which costs nothing for the usual `Copy` parts.
+impl<T> Mul<&Complex<T>> for &Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Clone,
+{
+    type Output = Complex<T>;
+
+    fn mul(self, other: &Complex<T>) -> Complex<T> {
+        self.clone() * other.clone()
+    }
+}
+
+impl<T: AddAssign> AddAssign for Complex<T> {
+    fn add_assign(&mut self, other: Complex<T>) {
+        self.re += other.re;
+        self.im += other.im;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: IdentityElement> IdentityElement for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+pub trait Conjugate {
+    fn conjugate(&self) -> Self;
+}
+
+impl<T: Neg<Output = T> + Clone> Conjugate for Complex<T> {
+    fn conjugate(&self) -> Self {
+        Complex::new(self.re.clone(), -self.im.clone())
+    }
+}
+
+macro_rules! impl_real_conjugate {
+    ($($t:ty),*) => {
+        $(
+            impl Conjugate for $t {
+                fn conjugate(&self) -> Self {
+                    *self
+                }
+            }
+        )*
+    };
+}
+
+impl_real_conjugate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use crate::complex::{Complex, Conjugate};
+
+    #[test]
+    fn check_complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!(a.conjugate(), Complex::new(1.0, -2.0));
+    }
+}
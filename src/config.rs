@@ -0,0 +1,159 @@
+use crate::error::MatrixError;
+use std::cell::RefCell;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    PureRust,
+    /// Reserved. The crate links no BLAS or LAPACK so that it stays free of
+    /// dependencies; [`ColumnMajorMatrix`](crate::layout::ColumnMajorMatrix)
+    /// hands its buffer to a caller's own cblas or LAPACK binding without a
+    /// copy.
+    Blas,
+    Gpu,
+}
+
+impl Backend {
+    pub fn is_available(self) -> bool {
+        matches!(self, Backend::PureRust)
+    }
+}
+
+/// Library-wide defaults. `threads == 0` means one thread per available
+/// core. Parallel element-wise operations on fewer than
+/// `parallel_threshold` elements run on the calling thread.
+///
+/// `fused_multiply_add` makes floating-point kernels such as the iterative
+/// solvers round `a * b + c` once instead of twice; without hardware FMA
+/// this is much slower. `flush_denormals` makes the same kernels replace
+/// subnormal intermediates with zero, which keeps nearly-converged solves
+/// from crawling through microcode-assisted arithmetic. The flush happens
+/// in software because Rust cannot soundly change the processor's
+/// floating-point mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub backend: Backend,
+    pub threads: usize,
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    pub parallel_threshold: usize,
+    pub fused_multiply_add: bool,
+    pub flush_denormals: bool,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            backend: Backend::PureRust,
+            threads: 0,
+            tolerance: 1e-10,
+            max_iterations: 1000,
+            parallel_threshold: 1 << 16,
+            fused_multiply_add: false,
+            flush_denormals: false,
+        }
+    }
+}
+
+impl Config {
+    fn validate(&self) -> Result<(), MatrixError> {
+        if !self.backend.is_available() {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Backend {:?} is not available in this build",
+                self.backend
+            )));
+        }
+        if self.tolerance.is_nan() || self.tolerance < 0.0 {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Tolerance must be non-negative, got {}",
+                self.tolerance
+            )));
+        }
+        Ok(())
+    }
+
+    /// `threads` with 0 resolved to the number of available cores.
+    pub fn thread_count(&self) -> usize {
+        match self.threads {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+    }
+}
+
+static GLOBAL: OnceLock<Config> = OnceLock::new();
+
+thread_local! {
+    static OVERRIDES: RefCell<Vec<Config>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sets the process-wide configuration. This can happen once, and only
+/// before anything has read the configuration.
+pub fn init(config: Config) -> Result<(), MatrixError> {
+    config.validate()?;
+    GLOBAL.set(config).map_err(|_| {
+        MatrixError::InvalidArgument("The global configuration is already initialized".to_string())
+    })
+}
+
+/// The innermost [`with_config`] override on this thread, or else the
+/// global configuration.
+pub fn current() -> Config {
+    OVERRIDES
+        .with(|overrides| overrides.borrow().last().cloned())
+        .unwrap_or_else(|| GLOBAL.get_or_init(Config::default).clone())
+}
+
+struct OverrideGuard;
+
+impl Drop for OverrideGuard {
+    fn drop(&mut self) {
+        OVERRIDES.with(|overrides| overrides.borrow_mut().pop());
+    }
+}
+
+/// Runs `f` with `config` in effect on the current thread. Overrides nest,
+/// and the previous configuration is restored even if `f` panics.
+pub fn with_config<R>(config: Config, f: impl FnOnce() -> R) -> Result<R, MatrixError> {
+    config.validate()?;
+    OVERRIDES.with(|overrides| overrides.borrow_mut().push(config));
+    let _guard = OverrideGuard;
+    Ok(f())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{self, Backend, Config};
+
+    #[test]
+    fn check_scoped_config() {
+        let base = config::current();
+        let loose = Config {
+            tolerance: 1e-3,
+            threads: 2,
+            ..Config::default()
+        };
+
+        let inner = config::with_config(loose.clone(), || {
+            let strict = Config {
+                tolerance: 1e-14,
+                ..config::current()
+            };
+            let nested = config::with_config(strict, config::current).unwrap();
+            (nested, config::current())
+        })
+        .unwrap();
+
+        assert_eq!(inner.0.tolerance, 1e-14);
+        assert_eq!(inner.0.threads, 2);
+        assert_eq!(inner.1, loose);
+        assert_eq!(config::current(), base);
+        assert!(config::init(base).is_err());
+
+        let gpu = Config {
+            backend: Backend::Gpu,
+            ..Config::default()
+        };
+        assert!(config::with_config(gpu, || ()).is_err());
+    }
+}
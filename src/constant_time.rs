@@ -0,0 +1,335 @@
+use crate::error::{product_mismatch, MatrixError};
+use crate::identity_element::IdentityElement;
+use crate::matrix::{checked_len, Matrix};
+use std::hint::black_box;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// An unsigned integer element whose matrix operations never branch on, or
+/// index memory by, the element values.
+///
+/// Arithmetic wraps instead of checking for overflow, and there is
+/// deliberately no `PartialEq`; compare values with [`ConstantTime::ct_eq`].
+/// The kernels are written to be branch-free, but the compiler and CPU give
+/// no hard guarantees, so verify the generated code for your target.
+///
+/// Constant-time in the element values: the wrapping operators, the `ct_*`
+/// masks, and the modular kernels [`Matrix::mult_mod_ct`] and
+/// [`Matrix::systematic_form_mod_ct`], which reduce by Barrett
+/// multiplication rather than `%`. Not constant-time: anything depending
+/// on the modulus or the matrix dimensions, both treated as public, and
+/// every other `Matrix` method, which may branch on values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConstantTime<T>(pub T);
+
+macro_rules! impl_constant_time {
+    ($($t:ty),*) => {
+        $(
+            impl Add for ConstantTime<$t> {
+                type Output = ConstantTime<$t>;
+                fn add(self, other: ConstantTime<$t>) -> ConstantTime<$t> {
+                    ConstantTime(self.0.wrapping_add(other.0))
+                }
+            }
+
+            impl Sub for ConstantTime<$t> {
+                type Output = ConstantTime<$t>;
+                fn sub(self, other: ConstantTime<$t>) -> ConstantTime<$t> {
+                    ConstantTime(self.0.wrapping_sub(other.0))
+                }
+            }
+
+            impl Mul for ConstantTime<$t> {
+                type Output = ConstantTime<$t>;
+                fn mul(self, other: ConstantTime<$t>) -> ConstantTime<$t> {
+                    ConstantTime(self.0.wrapping_mul(other.0))
+                }
+            }
+
+            impl Mul<&ConstantTime<$t>> for &ConstantTime<$t> {
+                type Output = ConstantTime<$t>;
+                fn mul(self, other: &ConstantTime<$t>) -> ConstantTime<$t> {
+                    ConstantTime(self.0.wrapping_mul(other.0))
+                }
+            }
+
+            impl AddAssign for ConstantTime<$t> {
+                fn add_assign(&mut self, other: ConstantTime<$t>) {
+                    self.0 = self.0.wrapping_add(other.0);
+                }
+            }
+
+            impl IdentityElement for ConstantTime<$t> {
+                fn zero() -> Self {
+                    ConstantTime(0)
+                }
+                fn one() -> Self {
+                    ConstantTime(1)
+                }
+            }
+
+            impl ConstantTime<$t> {
+                /// All ones if the value is zero, otherwise all zeros.
+                pub fn ct_is_zero(self) -> ConstantTime<$t> {
+                    let x = self.0;
+                    let top = (x | x.wrapping_neg()) >> (<$t>::BITS - 1);
+                    ConstantTime(black_box(top.wrapping_sub(1)))
+                }
+
+                /// All ones if both values are equal, otherwise all zeros.
+                pub fn ct_eq(self, other: ConstantTime<$t>) -> ConstantTime<$t> {
+                    ConstantTime(self.0 ^ other.0).ct_is_zero()
+                }
+
+                /// Picks `a` where `mask` is all ones and `b` where it is all zeros.
+                pub fn ct_select(
+                    mask: ConstantTime<$t>,
+                    a: ConstantTime<$t>,
+                    b: ConstantTime<$t>,
+                ) -> ConstantTime<$t> {
+                    ConstantTime((a.0 & mask.0) | (b.0 & !mask.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_constant_time!(u8, u16, u32, u64);
+
+/// A public modulus with its Barrett constant `floor(2^64 / modulus)`.
+///
+/// Building it divides by the modulus, so only the element values are kept
+/// secret, never the modulus. Every reduction afterwards is one widening
+/// multiply and a masked conditional subtract, with no hardware division.
+#[derive(Debug, Clone, Copy)]
+struct Modulus {
+    value: u64,
+    barrett: u64,
+}
+
+impl Modulus {
+    fn new(modulus: u32) -> Modulus {
+        Modulus {
+            value: modulus as u64,
+            barrett: ((1u128 << 64) / modulus as u128) as u64,
+        }
+    }
+
+    /// `x mod modulus` for any `x`. The Barrett quotient is at most one
+    /// short, so the remainder before the final subtract is below
+    /// `2 * modulus`.
+    fn reduce(self, x: u64) -> u32 {
+        let quotient = ((x as u128 * self.barrett as u128) >> 64) as u64;
+        let remainder = x.wrapping_sub(quotient.wrapping_mul(self.value));
+        let difference = remainder.wrapping_sub(self.value);
+        // All ones when `remainder < modulus`, which sets the top bit of
+        // `difference` because both are below 2^34.
+        let keep = (difference >> 63).wrapping_neg();
+        ((remainder & keep) | (difference & !keep)) as u32
+    }
+
+    fn mul(self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 * b as u64)
+    }
+
+    fn add(self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 + b as u64)
+    }
+
+    fn sub(self, a: u32, b: u32) -> u32 {
+        self.reduce(a as u64 + self.value - b as u64)
+    }
+
+    // Fermat inversion with a fixed, public exponent, so the sequence of
+    // operations depends on the modulus only.
+    fn inv(self, a: u32) -> u32 {
+        let exponent = self.value as u32 - 2;
+        let mut result = self.reduce(1);
+        let mut base = self.reduce(a as u64);
+        for bit in 0..u32::BITS {
+            let take = ConstantTime((exponent >> bit) & 1).ct_is_zero();
+            let multiplied = ConstantTime(self.mul(result, base));
+            result = ConstantTime::<u32>::ct_select(take, ConstantTime(result), multiplied).0;
+            base = self.mul(base, base);
+        }
+        result
+    }
+}
+
+impl Matrix<ConstantTime<u32>> {
+    fn check_modulus(modulus: u32) -> Result<Modulus, MatrixError> {
+        if modulus < 2 {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Modulus must be at least 2, got {}",
+                modulus
+            )));
+        }
+        Ok(Modulus::new(modulus))
+    }
+
+    pub fn mult_mod_ct(
+        &self,
+        matrix_b: &Matrix<ConstantTime<u32>>,
+        modulus: u32,
+    ) -> Result<Matrix<ConstantTime<u32>>, MatrixError> {
+        let modulus = Self::check_modulus(modulus)?;
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let mut new_values = vec![ConstantTime(0); checked_len(self.rows, matrix_b.cols)?];
+
+        for i in 0..self.rows {
+            for j in 0..matrix_b.cols {
+                let mut sum = 0;
+                for k in 0..self.cols {
+                    let product = modulus.mul(
+                        modulus.reduce(self.values[i * self.cols + k].0 as u64),
+                        modulus.reduce(matrix_b.values[k * matrix_b.cols + j].0 as u64),
+                    );
+                    sum = modulus.add(sum, product);
+                }
+                new_values[i * matrix_b.cols + j] = ConstantTime(sum);
+            }
+        }
+
+        Ok(Matrix::new(self.rows, matrix_b.cols, new_values))
+    }
+
+    /// Brings the matrix into systematic form `[I | X]` over GF(`modulus`)
+    /// (`modulus` must be prime; use 2 for GF(2)) without secret-dependent
+    /// branches or row swaps.
+    ///
+    /// Instead of swapping a pivot row into place, every lower row is
+    /// conditionally added to the pivot row, and every row is eliminated
+    /// whether or not it needs to be. The returned mask is all ones when the
+    /// leading square block was invertible and all zeros otherwise.
+    pub fn systematic_form_mod_ct(
+        &self,
+        modulus: u32,
+    ) -> Result<(Matrix<ConstantTime<u32>>, ConstantTime<u32>), MatrixError> {
+        let modulus = Self::check_modulus(modulus)?;
+        if self.rows > self.cols {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Cannot bring a {}x{} matrix into systematic form, it has more rows than columns",
+                self.rows, self.cols
+            )));
+        }
+
+        let cols = self.cols;
+        let mut values: Vec<u32> = self
+            .values
+            .iter()
+            .map(|v| modulus.reduce(v.0 as u64))
+            .collect();
+        let mut success = ConstantTime(u32::MAX);
+
+        for p in 0..self.rows {
+            for k in (p + 1)..self.rows {
+                let pivot_is_zero = ConstantTime(values[p * cols + p]).ct_is_zero();
+                for j in 0..cols {
+                    let sum = ConstantTime(modulus.add(values[p * cols + j], values[k * cols + j]));
+                    values[p * cols + j] = ConstantTime::<u32>::ct_select(
+                        pivot_is_zero,
+                        sum,
+                        ConstantTime(values[p * cols + j]),
+                    )
+                    .0;
+                }
+            }
+
+            let pivot = values[p * cols + p];
+            success = ConstantTime(success.0 & !ConstantTime(pivot).ct_is_zero().0);
+
+            let inverse = modulus.inv(pivot);
+            for j in 0..cols {
+                values[p * cols + j] = modulus.mul(values[p * cols + j], inverse);
+            }
+
+            for k in 0..self.rows {
+                let is_pivot_row = ConstantTime(k as u32).ct_eq(ConstantTime(p as u32));
+                let factor = ConstantTime::<u32>::ct_select(
+                    is_pivot_row,
+                    ConstantTime(0),
+                    ConstantTime(values[k * cols + p]),
+                )
+                .0;
+                for j in 0..cols {
+                    let scaled = modulus.mul(factor, values[p * cols + j]);
+                    values[k * cols + j] = modulus.sub(values[k * cols + j], scaled);
+                }
+            }
+        }
+
+        let new_values = values.into_iter().map(ConstantTime).collect();
+        Ok((Matrix::new(self.rows, self.cols, new_values), success))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constant_time::{ConstantTime, Modulus};
+    use crate::matrix::Matrix;
+
+    fn ct(values: &[u32]) -> Vec<ConstantTime<u32>> {
+        values.iter().map(|v| ConstantTime(*v)).collect()
+    }
+
+    fn plain(matrix: &Matrix<ConstantTime<u32>>) -> Vec<u32> {
+        matrix.get_values().iter().map(|v| v.0).collect()
+    }
+
+    #[test]
+    fn check_masks() {
+        assert_eq!(ConstantTime(0u8).ct_is_zero().0, u8::MAX);
+        assert_eq!(ConstantTime(7u64).ct_is_zero().0, 0);
+        assert_eq!(ConstantTime(5u32).ct_eq(ConstantTime(5)).0, u32::MAX);
+
+        let mask = ConstantTime(3u16).ct_eq(ConstantTime(4));
+        assert_eq!(
+            ConstantTime::<u16>::ct_select(mask, ConstantTime(1), ConstantTime(2)).0,
+            2
+        );
+    }
+
+    #[test]
+    fn check_barrett_reduction() {
+        for modulus in [2, 3, 7, 65_521, 2_147_483_647, u32::MAX] {
+            let barrett = Modulus::new(modulus);
+            let m = modulus as u64;
+            for x in [0, 1, m - 1, m, m + 1, m * m - 1, u64::MAX, u64::MAX / 3] {
+                assert_eq!(barrett.reduce(x) as u64, x % m, "{} mod {}", x, m);
+            }
+        }
+    }
+
+    #[test]
+    fn check_mult_mod_ct() {
+        let matrix_a = Matrix::new(2, 2, ct(&[1, 2, 3, 4]));
+        let matrix_b = Matrix::new(2, 2, ct(&[5, 6, 7, 8]));
+
+        let product = matrix_a.mult_mod_ct(&matrix_b, 7).unwrap();
+        assert_eq!(plain(&product), vec![5, 1, 1, 1]);
+        assert!(matrix_a.mult_mod_ct(&matrix_b, 1).is_err());
+    }
+
+    #[test]
+    fn check_systematic_form_mod_ct() {
+        // The zero in the top-left corner forces a pivot repair.
+        let matrix = Matrix::new(2, 3, ct(&[0, 1, 1, 1, 1, 0]));
+        let (reduced, success) = matrix.systematic_form_mod_ct(2).unwrap();
+        assert_eq!(success.0, u32::MAX);
+        assert_eq!(plain(&reduced), vec![1, 0, 1, 0, 1, 1]);
+
+        let matrix = Matrix::new(2, 3, ct(&[2, 1, 3, 4, 5, 6]));
+        let (reduced, success) = matrix.systematic_form_mod_ct(7).unwrap();
+        assert_eq!(success.0, u32::MAX);
+        assert_eq!(plain(&reduced), vec![1, 0, 5, 0, 1, 0]);
+
+        let singular = Matrix::new(2, 2, ct(&[1, 1, 1, 1]));
+        let (_, success) = singular.systematic_form_mod_ct(2).unwrap();
+        assert_eq!(success.0, 0);
+    }
+}
@@ -0,0 +1,318 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Skip the first non-blank line when reading.
+    pub has_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> CsvOptions {
+        CsvOptions {
+            delimiter: ',',
+            has_header: false,
+        }
+    }
+}
+
+/// Reads the record starting at the next character, as RFC 4180 does: a
+/// field wrapped in double quotes may contain the delimiter and line
+/// breaks, and `""` inside it stands for one quote. Unquoted fields are
+/// trimmed and may not contain a quote; quoted fields may have whitespace
+/// around the quotes. `line` is the record's first line and is advanced
+/// past it. A blank line gives `None`.
+fn read_record(
+    chars: &mut Peekable<Chars<'_>>,
+    delimiter: char,
+    line: &mut usize,
+) -> Result<Option<Vec<String>>, MatrixError> {
+    let start = *line;
+    let error = |message: String| MatrixError::ParseError(format!("{} at line {}", message, start));
+    let is_space = |c: &char| *c != delimiter && *c != '\n' && c.is_whitespace();
+    let mut fields = Vec::new();
+    let mut quoted = false;
+    loop {
+        while chars.next_if(is_space).is_some() {}
+
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            quoted = true;
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => {
+                        if c == '\n' {
+                            *line += 1;
+                        }
+                        field.push(c);
+                    }
+                    None => {
+                        return Err(error(format!(
+                            "Unterminated quote in field {}",
+                            fields.len() + 1
+                        )))
+                    }
+                }
+            }
+            while chars.next_if(is_space).is_some() {}
+            if chars.peek().is_some_and(|&c| c != delimiter && c != '\n') {
+                return Err(error(format!(
+                    "Unexpected text after the closing quote of field {}",
+                    fields.len() + 1
+                )));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != delimiter && c != '\n') {
+                if c == '"' {
+                    return Err(error(format!("Stray quote in field {}", fields.len() + 1)));
+                }
+                field.push(c);
+            }
+            field.truncate(field.trim_end().len());
+        }
+        fields.push(field);
+
+        match chars.next() {
+            Some(c) if c == delimiter => {}
+            Some(_) => {
+                *line += 1;
+                break;
+            }
+            None => break,
+        }
+    }
+
+    if !quoted && fields.len() == 1 && fields[0].is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(fields))
+    }
+}
+
+/// Quotes a field that would otherwise not read back as itself: one that is
+/// empty, has surrounding whitespace, or holds the delimiter, a quote or a
+/// line break.
+fn quote(field: String, delimiter: char) -> String {
+    if field.is_empty() || field.trim() != field || field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+impl<T: FromStr> Matrix<T> {
+    /// Reads one matrix row per record, normally one line. Blank lines are
+    /// ignored, fields are trimmed and may be quoted as in RFC 4180, so a
+    /// quoted field can span lines.
+    pub fn from_csv_reader<R: Read>(
+        mut reader: R,
+        options: &CsvOptions,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+
+        let mut values = Vec::new();
+        let mut cols = None;
+        let mut rows = 0;
+        let mut skip_header = options.has_header;
+        let mut chars = text.chars().peekable();
+        let mut line = 1;
+
+        while chars.peek().is_some() {
+            let start = line;
+            let Some(record) = read_record(&mut chars, options.delimiter, &mut line)? else {
+                continue;
+            };
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+
+            for (field_index, field) in record.iter().enumerate() {
+                let value = field.parse().map_err(|_| {
+                    MatrixError::ParseError(format!(
+                        "Cannot parse '{}' at line {}, field {}",
+                        field,
+                        start,
+                        field_index + 1
+                    ))
+                })?;
+                values.push(value);
+            }
+
+            match cols {
+                None => cols = Some(record.len()),
+                Some(expected) if expected != record.len() => {
+                    return Err(MatrixError::ParseError(format!(
+                        "Line {} has {} fields, expected {}",
+                        start,
+                        record.len(),
+                        expected
+                    )));
+                }
+                Some(_) => {}
+            }
+            rows += 1;
+        }
+
+        Ok(Matrix::new(rows, cols.unwrap_or(0), values))
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Writes one row per line, preceded by `header` when one is given.
+    /// Fields holding the delimiter, a quote or a line break are quoted.
+    pub fn to_csv_writer<W: Write>(
+        &self,
+        mut writer: W,
+        options: &CsvOptions,
+        header: Option<&[&str]>,
+    ) -> Result<(), MatrixError> {
+        let delimiter = options.delimiter.to_string();
+
+        if let Some(header) = header {
+            if header.len() != self.cols {
+                return Err(MatrixError::LengthMismatch {
+                    expected: self.cols,
+                    actual: header.len(),
+                });
+            }
+            let header: Vec<String> = header
+                .iter()
+                .map(|label| quote(label.to_string(), options.delimiter))
+                .collect();
+            writeln!(writer, "{}", header.join(&delimiter))?;
+        }
+
+        for row in self.values.chunks(self.cols.max(1)) {
+            let fields: Vec<String> = row
+                .iter()
+                .map(|value| quote(value.to_string(), options.delimiter))
+                .collect();
+            writeln!(writer, "{}", fields.join(&delimiter))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::csv::CsvOptions;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_csv_round_trip() {
+        let matrix: Matrix<f64> = Matrix::new(2, 3, vec![1.0, 2.5, -3.0, 4.0, 5.0, 6.25]);
+        let options = CsvOptions {
+            delimiter: ';',
+            has_header: true,
+        };
+
+        let mut buffer = Vec::new();
+        matrix
+            .to_csv_writer(&mut buffer, &options, Some(&["a", "b", "c"]))
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "a;b;c\n1;2.5;-3\n4;5;6.25\n"
+        );
+
+        let read: Matrix<f64> = Matrix::from_csv_reader(buffer.as_slice(), &options).unwrap();
+        assert_eq!(read, matrix);
+    }
+
+    #[test]
+    fn check_csv_errors() {
+        let options = CsvOptions::default();
+        let quoted: Matrix<i32> =
+            Matrix::from_csv_reader("\"1\", 2\r\n3,4\n\n".as_bytes(), &options).unwrap();
+        assert_eq!(quoted, Matrix::new(2, 2, vec![1, 2, 3, 4]));
+
+        let ragged = Matrix::<i32>::from_csv_reader("1,2\n3\n".as_bytes(), &options);
+        assert_eq!(
+            ragged.unwrap_err().to_string(),
+            "Parse Error: Line 2 has 1 fields, expected 2"
+        );
+
+        let malformed = Matrix::<i32>::from_csv_reader("1,x\n".as_bytes(), &options);
+        assert_eq!(
+            malformed.unwrap_err().to_string(),
+            "Parse Error: Cannot parse 'x' at line 1, field 2"
+        );
+
+        // A quoted delimiter stays inside its field.
+        let split = Matrix::<i32>::from_csv_reader("\"1,5\",2\n".as_bytes(), &options);
+        assert_eq!(
+            split.unwrap_err().to_string(),
+            "Parse Error: Cannot parse '1,5' at line 1, field 1"
+        );
+        for (line, message) in [
+            ("\"1,2\n", "Unterminated quote in field 1 at line 1"),
+            ("1,2\"3\n", "Stray quote in field 2 at line 1"),
+            (
+                "\"1\"2,3\n",
+                "Unexpected text after the closing quote of field 1 at line 1",
+            ),
+        ] {
+            let error = Matrix::<i32>::from_csv_reader(line.as_bytes(), &options).unwrap_err();
+            assert_eq!(error.to_string(), format!("Parse Error: {}", message));
+        }
+    }
+
+    #[test]
+    fn check_csv_quoting() {
+        let matrix: Matrix<String> = Matrix::new(
+            3,
+            3,
+            ["a,b", "say \"hi\"", "", " pad ", "a\nb", "x", "", "", ""]
+                .map(String::from)
+                .to_vec(),
+        );
+        let mut buffer = Vec::new();
+        matrix
+            .to_csv_writer(
+                &mut buffer,
+                &CsvOptions::default(),
+                Some(&["x", "y;z", "w"]),
+            )
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "x,y;z,w\n\"a,b\",\"say \"\"hi\"\"\",\"\"\n\" pad \",\"a\nb\",x\n\"\",\"\",\"\"\n"
+        );
+
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let read: Matrix<String> = Matrix::from_csv_reader(buffer.as_slice(), &options).unwrap();
+        assert_eq!(read, matrix);
+
+        // A single empty field is written quoted, so it is not a blank line.
+        let empty: Matrix<String> = Matrix::new(2, 1, vec![String::new(); 2]);
+        let mut buffer = Vec::new();
+        empty
+            .to_csv_writer(&mut buffer, &CsvOptions::default(), None)
+            .unwrap();
+        let read: Matrix<String> =
+            Matrix::from_csv_reader(buffer.as_slice(), &CsvOptions::default()).unwrap();
+        assert_eq!(read, empty);
+
+        // Line numbers count the breaks inside quoted fields.
+        let error =
+            Matrix::<String>::from_csv_reader("\"a\nb\",c\nd\n".as_bytes(), &CsvOptions::default())
+                .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Parse Error: Line 3 has 1 fields, expected 2"
+        );
+    }
+}
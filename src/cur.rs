@@ -0,0 +1,149 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// A CUR decomposition `A ≈ C U R`, where `C` holds actual columns of `A`
+/// and `R` actual rows, so the factors keep the meaning of the data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cur<T> {
+    pub c: Matrix<T>,
+    pub u: Matrix<T>,
+    pub r: Matrix<T>,
+    pub col_indices: Vec<usize>,
+    pub row_indices: Vec<usize>,
+}
+
+/// Indices of the `count` largest scores, returned in ascending order.
+fn top_indices<T: Float>(scores: &[T], count: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| {
+        scores[b]
+            .partial_cmp(&scores[a])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    order.truncate(count);
+    order.sort_unstable();
+    order
+}
+
+impl<T: Float> Matrix<T> {
+    fn check_rank_argument(&self, rank: usize) -> Result<(), MatrixError> {
+        if rank == 0 || rank > self.rows.min(self.cols) {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Rank {} is not between 1 and {} for a {}x{} matrix",
+                rank,
+                self.rows.min(self.cols),
+                self.rows,
+                self.cols
+            )));
+        }
+        Ok(())
+    }
+
+    /// The rank-`rank` leverage score of each column: the squared norm of
+    /// its entries in the top `rank` right singular vectors, divided by
+    /// `rank` so the scores sum to one.
+    pub fn column_leverage_scores(&self, rank: usize) -> Result<Vec<T>, MatrixError> {
+        self.check_rank_argument(rank)?;
+        let v_t = self.svd()?.v_t().clone();
+        let scale = T::one() / T::from_f64(rank as f64);
+
+        Ok((0..self.cols)
+            .map(|j| {
+                (0..rank).fold(T::zero(), |acc, i| {
+                    let v = v_t.values[i * self.cols + j];
+                    acc + v * v
+                }) * scale
+            })
+            .collect())
+    }
+
+    /// The `count` columns with the highest rank-`rank` leverage scores, in
+    /// ascending index order. Selection is deterministic.
+    pub fn select_columns_by_leverage(
+        &self,
+        rank: usize,
+        count: usize,
+    ) -> Result<Vec<usize>, MatrixError> {
+        if count > self.cols {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Cannot select {} of {} columns",
+                count, self.cols
+            )));
+        }
+        Ok(top_indices(&self.column_leverage_scores(rank)?, count))
+    }
+
+    fn gather(&self, rows: &[usize], cols: &[usize]) -> Matrix<T> {
+        let values = rows
+            .iter()
+            .flat_map(|&i| cols.iter().map(move |&j| self.values[i * self.cols + j]))
+            .collect();
+        Matrix::new(rows.len(), cols.len(), values)
+    }
+
+    /// Picks `cols` columns and `rows` rows by rank-`rank` leverage and sets
+    /// `U = C^+ A R^+`, the choice of `U` closest to `A` in Frobenius norm.
+    pub fn cur(&self, rank: usize, cols: usize, rows: usize) -> Result<Cur<T>, MatrixError> {
+        let col_indices = self.select_columns_by_leverage(rank, cols)?;
+        let row_indices = self.transpose().select_columns_by_leverage(rank, rows)?;
+
+        let all_rows: Vec<usize> = (0..self.rows).collect();
+        let all_cols: Vec<usize> = (0..self.cols).collect();
+        let c = self.gather(&all_rows, &col_indices);
+        let r = self.gather(&row_indices, &all_cols);
+        let u = c
+            .pseudo_inverse()?
+            .mult_naive(self)?
+            .mult_naive(&r.pseudo_inverse()?)?;
+
+        Ok(Cur {
+            c,
+            u,
+            r,
+            col_indices,
+            row_indices,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_leverage_scores() {
+        let matrix: Matrix<f64> = Matrix::new(
+            3,
+            4,
+            vec![5.0, 0.0, 0.1, 0.0, 0.0, 3.0, 0.0, 0.1, 0.0, 0.0, 0.0, 0.2],
+        );
+        let scores = matrix.column_leverage_scores(2).unwrap();
+
+        assert!((scores.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert_eq!(matrix.select_columns_by_leverage(2, 2).unwrap(), vec![0, 1]);
+        assert!(matrix.column_leverage_scores(4).is_err());
+    }
+
+    #[test]
+    fn check_cur_exact_for_low_rank() {
+        let matrix: Matrix<f64> = Matrix::new(
+            4,
+            3,
+            vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0, 3.0, 4.0, 7.0],
+        );
+        let cur = matrix.cur(2, 2, 2).unwrap();
+
+        assert_eq!(cur.c.get_cols(), 2);
+        assert_eq!(cur.r.get_rows(), 2);
+        let approximation = cur
+            .c
+            .mult_naive(&cur.u)
+            .unwrap()
+            .mult_naive(&cur.r)
+            .unwrap();
+        for (a, b) in approximation.values.iter().zip(&matrix.values) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+}
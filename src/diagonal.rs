@@ -0,0 +1,100 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::Scalar;
+
+/// Which diagonal (`i`, `j`) lies on: 0 for the main diagonal, positive
+/// above it and negative below. Widened so no dimension can overflow.
+fn diagonal_of(i: usize, j: usize) -> i128 {
+    j as i128 - i as i128
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// A `rows`x`cols` matrix with ones on the `k`-th diagonal and zeros
+    /// elsewhere; `eye(n, n, 0)` is [`Matrix::identity`]. A diagonal that
+    /// misses the matrix entirely gives all zeros.
+    pub fn eye(rows: usize, cols: usize, k: isize) -> Result<Matrix<T>, MatrixError> {
+        let mut values: Vec<T> = vec![T::zero(); checked_len(rows, cols)?];
+
+        for i in 0..rows {
+            let j = i as i128 + k as i128;
+            if (0..cols as i128).contains(&j) {
+                values[i * cols + j as usize] = T::one();
+            }
+        }
+
+        Ok(Matrix::new(rows, cols, values))
+    }
+
+    /// The elements on and above the `k`-th diagonal, with the rest set to
+    /// zero. `k = 0` is the main diagonal, `k > 0` lies above it and
+    /// `k < 0` below, as in NumPy.
+    pub fn triu(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            if diagonal_of(i, j) >= k as i128 {
+                self.values[i * self.cols + j].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// The elements on and below the `k`-th diagonal, with the rest set to
+    /// zero.
+    pub fn tril(&self, k: isize) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            if diagonal_of(i, j) <= k as i128 {
+                self.values[i * self.cols + j].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_triangular_parts() {
+        let matrix: Matrix<i32> = Matrix::new(3, 4, (1..=12).collect());
+
+        assert_eq!(
+            matrix.triu(0).values,
+            vec![1, 2, 3, 4, 0, 6, 7, 8, 0, 0, 11, 12]
+        );
+        assert_eq!(
+            matrix.tril(-1).values,
+            vec![0, 0, 0, 0, 5, 0, 0, 0, 9, 10, 0, 0]
+        );
+        assert_eq!(matrix.triu(2).add(&matrix.tril(1)).unwrap(), matrix);
+        assert_eq!(matrix.triu(-5), matrix);
+        assert_eq!(matrix.tril(-3), Matrix::new(3, 4, vec![0; 12]));
+    }
+
+    #[test]
+    fn check_eye() {
+        assert_eq!(
+            Matrix::<i32>::eye(3, 3, 0).unwrap(),
+            Matrix::identity(3).unwrap()
+        );
+        assert_eq!(
+            Matrix::<i32>::eye(2, 3, 1).unwrap().values,
+            vec![0, 1, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            Matrix::<i32>::eye(3, 2, -1).unwrap().values,
+            vec![0, 0, 1, 0, 0, 1]
+        );
+        assert_eq!(Matrix::<i32>::eye(2, 2, 2).unwrap().values, vec![0; 4]);
+        assert!(Matrix::<i32>::eye(usize::MAX, 2, 0).is_err());
+
+        // eye(n, n, 1) - I is the forward difference operator.
+        let x: Matrix<i32> = Matrix::new(3, 1, vec![1, 4, 9]);
+        let difference = Matrix::eye(3, 3, 1)
+            .unwrap()
+            .subtract(&Matrix::identity(3).unwrap())
+            .unwrap();
+        assert_eq!(difference.mult(&x).unwrap().values, vec![3, 5, -9]);
+    }
+}
@@ -0,0 +1,38 @@
+/// A primitive element type with a fixed little-endian byte layout, used by
+/// the binary file formats.
+pub trait DType: Copy {
+    /// The NumPy kind character: `f`, `i` or `u`.
+    const KIND: char;
+    const SIZE: usize;
+
+    fn write_le(self, out: &mut Vec<u8>);
+    /// `bytes` must be exactly `SIZE` long.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_dtype {
+    ($($t:ty => $kind:expr),*) => {
+        $(
+            impl DType for $t {
+                const KIND: char = $kind;
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn write_le(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut buffer = [0u8; std::mem::size_of::<$t>()];
+                    buffer.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_dtype!(
+    f32 => 'f', f64 => 'f',
+    i8 => 'i', i16 => 'i', i32 => 'i', i64 => 'i',
+    u8 => 'u', u16 => 'u', u32 => 'u', u64 => 'u'
+);
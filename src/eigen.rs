@@ -0,0 +1,249 @@
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+const MAX_SWEEPS: usize = 60;
+
+/// Eigenvalues in non-increasing order, with the matching unit eigenvectors
+/// as the columns of `vectors`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen<T> {
+    pub values: Vec<T>,
+    pub vectors: Matrix<T>,
+}
+
+impl<T: Float> Matrix<T> {
+    fn check_square(&self) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+        Ok(())
+    }
+
+    /// The lower-triangular `L` with `self = L L^T`. Only the lower triangle
+    /// of `self` is read.
+    pub fn cholesky(&self) -> Result<Matrix<T>, MatrixError> {
+        self.check_square()?;
+        let n = self.rows;
+        let mut l = Matrix::new(n, n, vec![T::zero(); n * n]);
+
+        for j in 0..n {
+            let mut diagonal = self.values[j * n + j];
+            for k in 0..j {
+                diagonal = diagonal - l.values[j * n + k] * l.values[j * n + k];
+            }
+            if diagonal <= T::zero() {
+                return Err(MatrixError::NotPositiveDefinite { column: j });
+            }
+            let pivot = diagonal.sqrt();
+            l.values[j * n + j] = pivot;
+
+            for i in j + 1..n {
+                let mut sum = self.values[i * n + j];
+                for k in 0..j {
+                    sum = sum - l.values[i * n + k] * l.values[j * n + k];
+                }
+                l.values[i * n + j] = sum / pivot;
+            }
+        }
+
+        Ok(l)
+    }
+
+    /// Eigen-decomposition of a symmetric matrix by cyclic Jacobi rotations.
+    /// Only symmetry of the input is assumed, not checked. Fails with
+    /// [`MatrixError::NonConvergent`] if the off-diagonal part is not
+    /// negligible after `MAX_SWEEPS` sweeps.
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.check_square()?;
+        let n = self.rows;
+        let mut a = self.clone();
+        let mut v: Matrix<T> = Matrix::identity_within(n);
+
+        for sweep in 0..=MAX_SWEEPS {
+            let off_diagonal = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .fold(T::zero(), |acc, (i, j)| {
+                    acc + a.values[i * n + j] * a.values[i * n + j]
+                });
+            let diagonal = (0..n).fold(T::zero(), |acc, i| {
+                acc + a.values[i * n + i] * a.values[i * n + i]
+            });
+            if off_diagonal <= T::epsilon() * T::epsilon() * diagonal {
+                break;
+            }
+            if sweep == MAX_SWEEPS {
+                return Err(MatrixError::NonConvergent {
+                    iterations: MAX_SWEEPS,
+                    residual: off_diagonal.sqrt().to_f64(),
+                });
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let apq = a.values[p * n + q];
+                    if apq == T::zero() {
+                        continue;
+                    }
+                    let theta = (a.values[q * n + q] - a.values[p * n + p]) / (apq + apq);
+                    let t = T::one() / (theta.abs() + (T::one() + theta * theta).sqrt());
+                    let t = if theta < T::zero() { -t } else { t };
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    for k in 0..n {
+                        let akp = a.values[k * n + p];
+                        let akq = a.values[k * n + q];
+                        a.values[k * n + p] = c * akp - s * akq;
+                        a.values[k * n + q] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let apk = a.values[p * n + k];
+                        let aqk = a.values[q * n + k];
+                        a.values[p * n + k] = c * apk - s * aqk;
+                        a.values[q * n + k] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let vkp = v.values[k * n + p];
+                        let vkq = v.values[k * n + q];
+                        v.values[k * n + p] = c * vkp - s * vkq;
+                        v.values[k * n + q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&x, &y| {
+            a.values[y * n + y]
+                .partial_cmp(&a.values[x * n + x])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let mut vectors = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for (target, &source) in order.iter().enumerate() {
+            for k in 0..n {
+                vectors.values[k * n + target] = v.values[k * n + source];
+            }
+        }
+
+        Ok(SymmetricEigen {
+            values: order.iter().map(|&i| a.values[i * n + i]).collect(),
+            vectors,
+        })
+    }
+
+    /// Solves `self v = lambda matrix_b v` for symmetric `self` and symmetric
+    /// positive definite `matrix_b`, by reducing to the standard problem for
+    /// `L^-1 self L^-T` with `matrix_b = L L^T`. The eigenvectors are
+    /// normalized so that `v^T matrix_b v = 1`.
+    pub fn generalized_symmetric_eigen(
+        &self,
+        matrix_b: &Matrix<T>,
+    ) -> Result<SymmetricEigen<T>, MatrixError> {
+        self.check_square()?;
+        if matrix_b.rows != self.rows || matrix_b.cols != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Pair,
+                lhs: (self.rows, self.cols),
+                rhs: (matrix_b.rows, matrix_b.cols),
+            });
+        }
+        let n = self.rows;
+        let l = matrix_b.cholesky()?;
+
+        // Solves L X = rhs in place, column by column.
+        let forward = |rhs: &mut Matrix<T>| {
+            for c in 0..n {
+                for i in 0..n {
+                    let mut sum = rhs.values[i * n + c];
+                    for k in 0..i {
+                        sum = sum - l.values[i * n + k] * rhs.values[k * n + c];
+                    }
+                    rhs.values[i * n + c] = sum / l.values[i * n + i];
+                }
+            }
+        };
+
+        let mut reduced = self.clone();
+        forward(&mut reduced);
+        reduced.transpose_mut();
+        forward(&mut reduced);
+
+        let standard = reduced.symmetric_eigen()?;
+        let mut vectors = standard.vectors;
+        for c in 0..n {
+            for i in (0..n).rev() {
+                let mut sum = vectors.values[i * n + c];
+                for k in i + 1..n {
+                    sum = sum - l.values[k * n + i] * vectors.values[k * n + c];
+                }
+                vectors.values[i * n + c] = sum / l.values[i * n + i];
+            }
+        }
+
+        Ok(SymmetricEigen {
+            values: standard.values,
+            vectors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_symmetric_eigen() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![2.0, 1.0, 0.0, 1.0, 2.0, 0.0, 0.0, 0.0, 5.0]);
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        for (value, expected) in eigen.values.iter().zip([5.0, 3.0, 1.0]) {
+            assert!((value - expected).abs() < 1e-12);
+        }
+        let product = matrix.mult_naive(&eigen.vectors).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let scaled = eigen.vectors.values[i * 3 + j] * eigen.values[j];
+                assert!((product.values[i * 3 + j] - scaled).abs() < 1e-12);
+            }
+        }
+
+        let l = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 3.0])
+            .cholesky()
+            .unwrap();
+        assert_eq!(l.values, vec![2.0, 0.0, 1.0, 2f64.sqrt()]);
+        assert!(matches!(
+            Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]).cholesky(),
+            Err(MatrixError::NotPositiveDefinite { column: 1 })
+        ));
+        assert!(matches!(
+            Matrix::<f64>::new(2, 3, vec![0.0; 6]).symmetric_eigen(),
+            Err(MatrixError::NotSquare { dims: (2, 3) })
+        ));
+        assert!(matches!(
+            Matrix::new(2, 2, vec![1.0, f64::NAN, f64::NAN, 1.0]).symmetric_eigen(),
+            Err(MatrixError::NonConvergent { iterations: 60, .. })
+        ));
+    }
+
+    #[test]
+    fn check_generalized_symmetric_eigen() {
+        let a: Matrix<f64> = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+        let b: Matrix<f64> = Matrix::new(2, 2, vec![4.0, 1.0, 1.0, 2.0]);
+        let eigen = a.generalized_symmetric_eigen(&b).unwrap();
+
+        let av = a.mult_naive(&eigen.vectors).unwrap();
+        let bv = b.mult_naive(&eigen.vectors).unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected = bv.values[i * 2 + j] * eigen.values[j];
+                assert!((av.values[i * 2 + j] - expected).abs() < 1e-12);
+            }
+        }
+        assert!(eigen.values[0] >= eigen.values[1]);
+    }
+}
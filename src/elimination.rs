@@ -0,0 +1,210 @@
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::fmt;
+use std::ops::Div;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowOperation<T> {
+    Swap {
+        row_a: usize,
+        row_b: usize,
+    },
+    Scale {
+        row: usize,
+        factor: T,
+    },
+    AddMultiple {
+        source: usize,
+        target: usize,
+        factor: T,
+    },
+}
+
+/// Rows are printed 1-based, the way they are written on a blackboard.
+impl<T: fmt::Display> fmt::Display for RowOperation<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RowOperation::Swap { row_a, row_b } => write!(f, "R{} <-> R{}", row_a + 1, row_b + 1),
+            RowOperation::Scale { row, factor } => {
+                write!(f, "R{} <- ({}) * R{}", row + 1, factor, row + 1)
+            }
+            RowOperation::AddMultiple {
+                source,
+                target,
+                factor,
+            } => write!(
+                f,
+                "R{} <- R{} + ({}) * R{}",
+                target + 1,
+                target + 1,
+                factor,
+                source + 1
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationStep<T> {
+    pub operation: RowOperation<T>,
+    pub matrix: Matrix<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationTrace<T> {
+    pub steps: Vec<EliminationStep<T>>,
+    pub pivots: Vec<usize>,
+    pub result: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Scalar + Div<Output = T> + PartialEq,
+{
+    /// Gauss-Jordan elimination to reduced row echelon form. The first
+    /// nonzero entry in each column is used as the pivot, which keeps exact
+    /// arithmetic exact; round-off in floating point is not guarded against.
+    pub fn rref(&self) -> Matrix<T> {
+        self.eliminate(false).result
+    }
+
+    /// Like [`Matrix::rref`], but records every elementary row operation
+    /// together with the matrix it produced.
+    pub fn rref_explain(&self) -> EliminationTrace<T> {
+        self.eliminate(true)
+    }
+
+    fn eliminate(&self, record: bool) -> EliminationTrace<T> {
+        let mut matrix = self.clone();
+        let mut steps = Vec::new();
+        let mut pivots = Vec::new();
+        let mut row = 0;
+
+        let mut apply = |matrix: &mut Matrix<T>, operation: RowOperation<T>| {
+            let applied = match &operation {
+                RowOperation::Swap { row_a, row_b } => matrix.swap_rows(*row_a, *row_b).is_ok(),
+                RowOperation::Scale { row, factor } => {
+                    matrix.scale_row(*row, factor.clone()).is_ok()
+                }
+                RowOperation::AddMultiple {
+                    source,
+                    target,
+                    factor,
+                } => matrix
+                    .add_scaled_row(*source, *target, factor.clone())
+                    .is_ok(),
+            };
+            if applied && record {
+                steps.push(EliminationStep {
+                    operation,
+                    matrix: matrix.clone(),
+                });
+            }
+        };
+
+        for col in 0..matrix.cols {
+            if row == matrix.rows {
+                break;
+            }
+
+            let Some(pivot_row) =
+                (row..matrix.rows).find(|&r| matrix.values[r * matrix.cols + col] != T::zero())
+            else {
+                continue;
+            };
+
+            if pivot_row != row {
+                apply(
+                    &mut matrix,
+                    RowOperation::Swap {
+                        row_a: row,
+                        row_b: pivot_row,
+                    },
+                );
+            }
+
+            let pivot = matrix.values[row * matrix.cols + col].clone();
+            if pivot != T::one() {
+                apply(
+                    &mut matrix,
+                    RowOperation::Scale {
+                        row,
+                        factor: T::one() / pivot,
+                    },
+                );
+            }
+
+            for target in 0..matrix.rows {
+                let value = matrix.values[target * matrix.cols + col].clone();
+                if target != row && value != T::zero() {
+                    apply(
+                        &mut matrix,
+                        RowOperation::AddMultiple {
+                            source: row,
+                            target,
+                            factor: T::zero() - value,
+                        },
+                    );
+                }
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        EliminationTrace {
+            steps,
+            pivots,
+            result: matrix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::elimination::RowOperation;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_rref() {
+        let matrix: Matrix<f64> = Matrix::new(
+            3,
+            4,
+            vec![0.0, 2.0, 4.0, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0],
+        );
+        let expected_result: Matrix<f64> = Matrix::new(
+            3,
+            4,
+            vec![1.0, 0.0, -1.0, 0.0, 0.0, 1.0, 2.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+        );
+
+        assert_eq!(matrix.rref(), expected_result);
+    }
+
+    #[test]
+    fn check_rref_explain() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![0.0, 2.0, 1.0, 3.0]);
+        let trace = matrix.rref_explain();
+
+        let operations: Vec<String> = trace
+            .steps
+            .iter()
+            .map(|s| s.operation.to_string())
+            .collect();
+        assert_eq!(
+            operations,
+            vec!["R1 <-> R2", "R2 <- (0.5) * R2", "R1 <- R1 + (-3) * R2"]
+        );
+        assert_eq!(
+            trace.steps[0].operation,
+            RowOperation::Swap { row_a: 0, row_b: 1 }
+        );
+        assert_eq!(
+            trace.steps[0].matrix,
+            Matrix::new(2, 2, vec![1.0, 3.0, 0.0, 2.0])
+        );
+        assert_eq!(trace.pivots, vec![0, 1]);
+        assert_eq!(trace.result, Matrix::identity(2).unwrap());
+        assert_eq!(trace.steps.last().unwrap().matrix, trace.result);
+    }
+}
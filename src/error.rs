@@ -1,18 +1,423 @@
+use crate::units::Unit;
 use std::fmt;
+use std::ops::Range;
+
+/// The operation that raised a [`MatrixError::DimensionMismatch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    HorizontalStack,
+    VerticalStack,
+    /// Solving a linear system; `rhs` is the right-hand side.
+    Solve,
+    /// Stepping a linear system; `rhs` is the state as a column.
+    Step,
+    /// Pairing a matrix with the metric of a generalized eigenproblem.
+    Pair,
+    /// Writing into a caller-provided output; `lhs` is the output and `rhs`
+    /// the shape it needed.
+    Output,
+    /// Converting to a fixed shape; `rhs` is the target shape.
+    Convert,
+    /// Comparing two matrices element-wise.
+    Compare,
+    /// Applying a boolean mask; `rhs` is the mask.
+    Mask,
+}
+
+/// Which labelled axis a [`MatrixError::UnknownLabel`] was looked up on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Rows,
+    Cols,
+}
+
+impl fmt::Display for Axis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Axis::Rows => write!(f, "row"),
+            Axis::Cols => write!(f, "column"),
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum MatrixError {
-    DimensionMismatch(String),
-    InvalidIndex(String),
+    /// Operands of `op` have incompatible shapes, given as (rows, cols).
+    DimensionMismatch {
+        op: Op,
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    /// A buffer, label list or similar sequence has the wrong length.
+    LengthMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    /// Element `index` does not exist in a matrix of size `dims`.
+    IndexOutOfBounds {
+        index: (usize, usize),
+        dims: (usize, usize),
+    },
+    /// The operation needs a square matrix; `dims` is (rows, cols).
+    NotSquare {
+        dims: (usize, usize),
+    },
+    /// The operation needs a matrix with at least one element.
+    EmptyMatrix,
+    /// A Cholesky-style factorization met a non-positive pivot at `column`.
+    NotPositiveDefinite {
+        column: usize,
+    },
+    /// An iterative method stopped after `iterations` with the given
+    /// residual norm still above its tolerance.
+    NonConvergent {
+        iterations: usize,
+        residual: f64,
+    },
+    /// A factorization found no usable pivot at `column`.
+    Singular {
+        column: usize,
+    },
+    /// The block `rows` x `cols` does not fit in a matrix of size `dims`.
+    RangeOutOfBounds {
+        rows: Range<usize>,
+        cols: Range<usize>,
+        dims: (usize, usize),
+    },
+    /// A strided view of `shape` from `start` stepping by `steps` leaves a
+    /// matrix of size `dims`.
+    StridedOutOfBounds {
+        start: (usize, usize),
+        shape: (usize, usize),
+        steps: (isize, isize),
+        dims: (usize, usize),
+    },
+    /// No row or column carries `label`.
+    UnknownLabel {
+        axis: Axis,
+        label: String,
+    },
+    SizeOverflow(String),
+    InvalidArgument(String),
+    Io(std::io::Error),
+    /// Quantities in different units were added or subtracted.
+    UnitMismatch {
+        lhs: Unit,
+        rhs: Unit,
+    },
+    ParseError(String),
+}
+
+impl MatrixError {
+    /// For a mismatch from `add`, `subtract` or `mult` style operations,
+    /// which operand could be transposed to make the shapes compatible.
+    pub fn transpose_hint(&self) -> Option<TransposeHint> {
+        match *self {
+            MatrixError::DimensionMismatch { op, lhs, rhs } => match op {
+                Op::Add | Op::Subtract | Op::Compare => TransposeHint::for_elementwise(lhs, rhs),
+                Op::Multiply => TransposeHint::for_product(lhs, rhs),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn write_dimension_mismatch(
+    f: &mut fmt::Formatter<'_>,
+    op: Op,
+    (a, b): (usize, usize),
+    (c, d): (usize, usize),
+) -> fmt::Result {
+    let verb = match op {
+        Op::Add => "add",
+        Op::Subtract => "subtract",
+        Op::Multiply => "multiply",
+        Op::Compare => "compare",
+        Op::HorizontalStack => "horizontally stack",
+        Op::VerticalStack => "vertically stack",
+        Op::Solve => {
+            return write!(
+                f,
+                "Cannot solve a {}x{} system with a {}x{} right-hand side",
+                a, b, c, d
+            )
+        }
+        Op::Step => {
+            return write!(
+                f,
+                "Cannot step a {}-element state with a {}x{} system matrix",
+                c, a, b
+            )
+        }
+        Op::Pair => {
+            return write!(
+                f,
+                "Cannot pair a {}x{} matrix with a {}x{} metric",
+                a, b, c, d
+            )
+        }
+        Op::Mask => {
+            return write!(
+                f,
+                "Cannot mask a {}x{} matrix with a {}x{} mask",
+                a, b, c, d
+            )
+        }
+        Op::Output => return write!(f, "Output matrix is {}x{}, expected {}x{}", a, b, c, d),
+        Op::Convert => {
+            return write!(
+                f,
+                "Cannot convert a {}x{} matrix into a {}x{} matrix",
+                a, b, c, d
+            )
+        }
+    };
+    write!(
+        f,
+        "Cannot {} matricies of dimensions {}x{} and {}x{}",
+        verb, a, b, c, d
+    )
 }
 
 impl fmt::Display for MatrixError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            MatrixError::DimensionMismatch(msg) => write!(f, "Dimension Mismatch: {}", msg),
-            MatrixError::InvalidIndex(msg) => write!(f, "Invalid Index: {}", msg),
+            MatrixError::DimensionMismatch { op, lhs, rhs } => {
+                write!(f, "Dimension Mismatch: ")?;
+                write_dimension_mismatch(f, *op, *lhs, *rhs)?;
+                match self.transpose_hint() {
+                    Some(hint) => write!(f, "; {} would make them compatible", hint),
+                    None => Ok(()),
+                }
+            }
+            MatrixError::LengthMismatch { expected, actual } => write!(
+                f,
+                "Dimension Mismatch: Expected {} elements, got {}",
+                expected, actual
+            ),
+            MatrixError::IndexOutOfBounds { index, dims } => write!(
+                f,
+                "Invalid Index: Index ({}, {}) is out of bounds for matrix of size {}x{}",
+                index.0, index.1, dims.0, dims.1
+            ),
+            MatrixError::NotSquare { dims } => write!(
+                f,
+                "Not Square: Expected a square matrix, got {}x{}",
+                dims.0, dims.1
+            ),
+            MatrixError::EmptyMatrix => write!(f, "Empty Matrix: The matrix has no elements"),
+            MatrixError::NotPositiveDefinite { column } => write!(
+                f,
+                "Not Positive Definite: Non-positive pivot at column {}",
+                column
+            ),
+            MatrixError::NonConvergent {
+                iterations,
+                residual,
+            } => write!(
+                f,
+                "Non Convergent: Residual {:e} after {} iterations",
+                residual, iterations
+            ),
+            MatrixError::Singular { column } => {
+                write!(f, "Singular Matrix: No usable pivot at column {}", column)
+            }
+            MatrixError::RangeOutOfBounds { rows, cols, dims } => write!(
+                f,
+                "Invalid Index: Block {:?}x{:?} is out of bounds for matrix of size {}x{}",
+                rows, cols, dims.0, dims.1
+            ),
+            MatrixError::StridedOutOfBounds {
+                start,
+                shape,
+                steps,
+                dims,
+            } => write!(
+                f,
+                "Invalid Index: Strided view of {}x{} from ({}, {}) with steps ({}, {}) is out of bounds for matrix of size {}x{}",
+                shape.0, shape.1, start.0, start.1, steps.0, steps.1, dims.0, dims.1
+            ),
+            MatrixError::UnknownLabel { axis, label } => {
+                write!(f, "Invalid Index: No {} labelled \"{}\"", axis, label)
+            }
+            MatrixError::SizeOverflow(msg) => write!(f, "Size Overflow: {}", msg),
+            MatrixError::InvalidArgument(msg) => write!(f, "Invalid Argument: {}", msg),
+            MatrixError::Io(err) => write!(f, "I/O Error: {}", err),
+            MatrixError::UnitMismatch { lhs, rhs } => write!(
+                f,
+                "Unit Mismatch: Cannot combine quantities in {} and {}",
+                lhs, rhs
+            ),
+            MatrixError::ParseError(msg) => write!(f, "Parse Error: {}", msg),
         }
     }
 }
 
 impl std::error::Error for MatrixError {}
+
+impl From<std::io::Error> for MatrixError {
+    fn from(err: std::io::Error) -> Self {
+        MatrixError::Io(err)
+    }
+}
+
+/// Which operand of a failed binary operation, if transposed, would have
+/// made the shapes compatible. Mismatch errors from [`Matrix::mult`] and
+/// [`Matrix::add`] and their relatives offer it through
+/// [`MatrixError::transpose_hint`] and end their messages with it, since
+/// passing an operand in the wrong orientation is the most common cause.
+///
+/// [`Matrix::mult`]: crate::matrix::Matrix::mult
+/// [`Matrix::add`]: crate::matrix::Matrix::add
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransposeHint {
+    Lhs,
+    Rhs,
+    Both,
+}
+
+impl TransposeHint {
+    /// The hint for multiplying an `lhs`-shaped matrix by an `rhs`-shaped
+    /// one, preferring to transpose a single operand.
+    pub fn for_product(lhs: (usize, usize), rhs: (usize, usize)) -> Option<TransposeHint> {
+        if lhs.1 == rhs.0 {
+            None
+        } else if lhs.1 == rhs.1 {
+            Some(TransposeHint::Rhs)
+        } else if lhs.0 == rhs.0 {
+            Some(TransposeHint::Lhs)
+        } else if lhs.0 == rhs.1 {
+            Some(TransposeHint::Both)
+        } else {
+            None
+        }
+    }
+
+    /// The hint for an elementwise operation, which keeps the left
+    /// operand's shape.
+    pub fn for_elementwise(lhs: (usize, usize), rhs: (usize, usize)) -> Option<TransposeHint> {
+        if lhs != rhs && lhs == (rhs.1, rhs.0) {
+            Some(TransposeHint::Rhs)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for TransposeHint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransposeHint::Lhs => write!(f, "transposing the left operand"),
+            TransposeHint::Rhs => write!(f, "transposing the right operand"),
+            TransposeHint::Both => write!(f, "transposing both operands"),
+        }
+    }
+}
+
+pub(crate) fn product_mismatch(lhs: (usize, usize), rhs: (usize, usize)) -> MatrixError {
+    MatrixError::DimensionMismatch {
+        op: Op::Multiply,
+        lhs,
+        rhs,
+    }
+}
+
+pub(crate) fn elementwise_mismatch(
+    op: Op,
+    lhs: (usize, usize),
+    rhs: (usize, usize),
+) -> MatrixError {
+    MatrixError::DimensionMismatch { op, lhs, rhs }
+}
+
+/// The failure of a per-element operation such as [`Matrix::try_map`],
+/// tagged with the element's position.
+///
+/// [`Matrix::try_map`]: crate::matrix::Matrix::try_map
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementError<E> {
+    pub row: usize,
+    pub col: usize,
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for ElementError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Element ({}, {}): {}", self.row, self.col, self.error)
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ElementError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{MatrixError, Op, TransposeHint};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_transpose_hints() {
+        assert_eq!(
+            TransposeHint::for_product((2, 3), (2, 3)),
+            Some(TransposeHint::Rhs)
+        );
+        assert_eq!(
+            TransposeHint::for_product((3, 2), (3, 4)),
+            Some(TransposeHint::Lhs)
+        );
+        assert_eq!(
+            TransposeHint::for_product((2, 3), (4, 2)),
+            Some(TransposeHint::Both)
+        );
+        assert_eq!(TransposeHint::for_product((2, 3), (4, 5)), None);
+        assert_eq!(
+            TransposeHint::for_elementwise((2, 3), (3, 2)),
+            Some(TransposeHint::Rhs)
+        );
+        assert_eq!(TransposeHint::for_elementwise((2, 2), (3, 3)), None);
+
+        let a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let error = a.mult_naive(&a).unwrap_err();
+        assert_eq!(error.transpose_hint(), Some(TransposeHint::Rhs));
+        assert!(error
+            .to_string()
+            .ends_with("; transposing the right operand would make them compatible"));
+
+        let error = a.add(&Matrix::new(1, 1, vec![0])).unwrap_err();
+        assert_eq!(error.transpose_hint(), None);
+        assert!(!error.to_string().contains("transposing"));
+    }
+
+    #[test]
+    fn check_structured_errors() {
+        let a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        match a.vstack(&a.transpose()).unwrap_err() {
+            MatrixError::DimensionMismatch { op, lhs, rhs } => {
+                assert_eq!((op, lhs, rhs), (Op::VerticalStack, (2, 3), (3, 2)))
+            }
+            other => panic!("unexpected error {:?}", other),
+        }
+        assert!(matches!(
+            a.value_at(2, 0),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (2, 0),
+                dims: (2, 3)
+            })
+        ));
+        assert!(matches!(
+            Matrix::try_new(2, 2, vec![1, 2, 3]),
+            Err(MatrixError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        ));
+        assert_eq!(
+            a.value_at(5, 5).unwrap_err().to_string(),
+            "Invalid Index: Index (5, 5) is out of bounds for matrix of size 2x3"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use crate::error::{elementwise_mismatch, product_mismatch, MatrixError, Op};
+use crate::identity_element::IdentityElement;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::view::MatrixView;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// A lazily evaluated matrix expression.
+///
+/// `&a + &b` and the combinators below only build a tree of borrowed
+/// operands; nothing is computed until [`MatrixExpr::eval`] or
+/// [`MatrixExpr::eval_into`] fills every output entry in a single pass, so
+/// `(&a + &b).mult(&c).scale(2.0)` allocates only its result. Shapes are
+/// checked when the expression is evaluated. Because [`Matrix::mult`] is
+/// eager, a lazy product of two plain matrices is spelled
+/// `MatrixExpr::mult(&a, &b)`.
+///
+/// A product entry is a dot product over its operands' entries, so an
+/// expensive expression used as a factor is re-evaluated for every output
+/// column or row; evaluate such factors first.
+pub trait MatrixExpr<T>: Sized {
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+    /// Checks that the shapes of all operands are compatible.
+    fn check(&self) -> Result<(), MatrixError>;
+    /// The value at (`row`, `col`). Only meaningful after [`MatrixExpr::check`].
+    fn entry(&self, row: usize, col: usize) -> T;
+
+    fn eval(&self) -> Result<Matrix<T>, MatrixError> {
+        self.check()?;
+        let values = (0..self.rows())
+            .flat_map(|i| (0..self.cols()).map(move |j| self.entry(i, j)))
+            .collect();
+        Ok(Matrix::new(self.rows(), self.cols(), values))
+    }
+
+    /// Evaluates into an existing matrix of the right shape, without
+    /// allocating.
+    fn eval_into(&self, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        self.check()?;
+        if out.rows != self.rows() || out.cols != self.cols() {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Output,
+                lhs: (out.rows, out.cols),
+                rhs: (self.rows(), self.cols()),
+            });
+        }
+        let cols = self.cols();
+        for (index, value) in out.values.iter_mut().enumerate() {
+            *value = self.entry(index / cols, index % cols);
+        }
+        Ok(())
+    }
+
+    fn plus<R: MatrixExpr<T>>(self, rhs: R) -> Sum<Self, R> {
+        Sum { lhs: self, rhs }
+    }
+
+    fn minus<R: MatrixExpr<T>>(self, rhs: R) -> Difference<Self, R> {
+        Difference { lhs: self, rhs }
+    }
+
+    fn mult<R: MatrixExpr<T>>(self, rhs: R) -> Product<Self, R> {
+        Product { lhs: self, rhs }
+    }
+
+    fn scale(self, factor: T) -> Scaled<Self, T> {
+        Scaled { expr: self, factor }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sum<L, R> {
+    lhs: L,
+    rhs: R,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Difference<L, R> {
+    lhs: L,
+    rhs: R,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Product<L, R> {
+    lhs: L,
+    rhs: R,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<E, T> {
+    expr: E,
+    factor: T,
+}
+
+fn check_same_shape<T, L: MatrixExpr<T>, R: MatrixExpr<T>>(
+    lhs: &L,
+    rhs: &R,
+    op: Op,
+) -> Result<(), MatrixError> {
+    lhs.check()?;
+    rhs.check()?;
+    if lhs.rows() != rhs.rows() || lhs.cols() != rhs.cols() {
+        return Err(elementwise_mismatch(
+            op,
+            (lhs.rows(), lhs.cols()),
+            (rhs.rows(), rhs.cols()),
+        ));
+    }
+    Ok(())
+}
+
+impl<T: Clone> MatrixExpr<T> for &Matrix<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+    fn cols(&self) -> usize {
+        self.cols
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        Ok(())
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        self.values[row * self.cols + col].clone()
+    }
+}
+
+/// Views join expressions like matrices, so a block of a matrix, or one
+/// backed by other storage, can be multiplied without copying it first.
+impl<T: Clone> MatrixExpr<T> for MatrixView<'_, T> {
+    fn rows(&self) -> usize {
+        self.get_rows()
+    }
+    fn cols(&self) -> usize {
+        self.get_cols()
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        Ok(())
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        self.element(row, col).clone()
+    }
+}
+
+impl<T: Add<Output = T>, L: MatrixExpr<T>, R: MatrixExpr<T>> MatrixExpr<T> for Sum<L, R> {
+    fn rows(&self) -> usize {
+        self.lhs.rows()
+    }
+    fn cols(&self) -> usize {
+        self.lhs.cols()
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        check_same_shape(&self.lhs, &self.rhs, Op::Add)
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        self.lhs.entry(row, col) + self.rhs.entry(row, col)
+    }
+}
+
+impl<T: Sub<Output = T>, L: MatrixExpr<T>, R: MatrixExpr<T>> MatrixExpr<T> for Difference<L, R> {
+    fn rows(&self) -> usize {
+        self.lhs.rows()
+    }
+    fn cols(&self) -> usize {
+        self.lhs.cols()
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        check_same_shape(&self.lhs, &self.rhs, Op::Subtract)
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        self.lhs.entry(row, col) - self.rhs.entry(row, col)
+    }
+}
+
+impl<T, L, R> MatrixExpr<T> for Product<L, R>
+where
+    T: Mul<Output = T> + AddAssign + IdentityElement,
+    L: MatrixExpr<T>,
+    R: MatrixExpr<T>,
+{
+    fn rows(&self) -> usize {
+        self.lhs.rows()
+    }
+    fn cols(&self) -> usize {
+        self.rhs.cols()
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        self.lhs.check()?;
+        self.rhs.check()?;
+        if self.lhs.cols() != self.rhs.rows() {
+            return Err(product_mismatch(
+                (self.lhs.rows(), self.lhs.cols()),
+                (self.rhs.rows(), self.rhs.cols()),
+            ));
+        }
+        Ok(())
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        let mut sum = T::zero();
+        for k in 0..self.lhs.cols() {
+            sum += self.lhs.entry(row, k) * self.rhs.entry(k, col);
+        }
+        sum
+    }
+}
+
+impl<T: Mul<Output = T> + Clone, E: MatrixExpr<T>> MatrixExpr<T> for Scaled<E, T> {
+    fn rows(&self) -> usize {
+        self.expr.rows()
+    }
+    fn cols(&self) -> usize {
+        self.expr.cols()
+    }
+    fn check(&self) -> Result<(), MatrixError> {
+        self.expr.check()
+    }
+    fn entry(&self, row: usize, col: usize) -> T {
+        self.factor.clone() * self.expr.entry(row, col)
+    }
+}
+
+impl<'a, 'b, T> Add<&'b Matrix<T>> for &'a Matrix<T>
+where
+    T: Add<Output = T> + Clone,
+{
+    type Output = Sum<&'a Matrix<T>, &'b Matrix<T>>;
+
+    fn add(self, rhs: &'b Matrix<T>) -> Self::Output {
+        Sum { lhs: self, rhs }
+    }
+}
+
+impl<'a, 'b, T> Sub<&'b Matrix<T>> for &'a Matrix<T>
+where
+    T: Sub<Output = T> + Clone,
+{
+    type Output = Difference<&'a Matrix<T>, &'b Matrix<T>>;
+
+    fn sub(self, rhs: &'b Matrix<T>) -> Self::Output {
+        Difference { lhs: self, rhs }
+    }
+}
+
+/// `expression + &matrix` and `expression - &matrix` for every node type,
+/// so operator chains can keep growing from the left.
+macro_rules! impl_matrix_rhs_ops {
+    ($($node:ident<$a:ident, $b:ident>),*) => {
+        $(
+            impl<'m, T, $a, $b> Add<&'m Matrix<T>> for $node<$a, $b>
+            where
+                T: Scalar,
+                $node<$a, $b>: MatrixExpr<T>,
+            {
+                type Output = Sum<$node<$a, $b>, &'m Matrix<T>>;
+
+                fn add(self, rhs: &'m Matrix<T>) -> Self::Output {
+                    Sum { lhs: self, rhs }
+                }
+            }
+
+            impl<'m, T, $a, $b> Sub<&'m Matrix<T>> for $node<$a, $b>
+            where
+                T: Scalar,
+                $node<$a, $b>: MatrixExpr<T>,
+            {
+                type Output = Difference<$node<$a, $b>, &'m Matrix<T>>;
+
+                fn sub(self, rhs: &'m Matrix<T>) -> Self::Output {
+                    Difference { lhs: self, rhs }
+                }
+            }
+        )*
+    };
+}
+
+impl_matrix_rhs_ops!(Sum<L, R>, Difference<L, R>, Product<L, R>, Scaled<E, S>);
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::MatrixExpr;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_fused_expression() {
+        let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b: Matrix<f64> = Matrix::new(2, 2, vec![0.5, 0.5, -1.0, 0.0]);
+        let c: Matrix<f64> = Matrix::new(2, 3, vec![1.0, 0.0, 2.0, 0.0, 1.0, -1.0]);
+
+        let mut expected = a.add(&b).unwrap().mult_naive(&c).unwrap();
+        expected.mult_scalar(2.0);
+        assert_eq!((&a + &b).mult(&c).scale(2.0).eval().unwrap(), expected);
+        assert_eq!(
+            ((&a + &b).scale(2.0) + &a - &b).eval().unwrap().values,
+            vec![3.5, 6.5, 8.0, 12.0]
+        );
+
+        let mut out = Matrix::new(2, 2, vec![0.0; 4]);
+        (&a - &b + &a).eval_into(&mut out).unwrap();
+        assert_eq!(out.values, vec![1.5, 3.5, 7.0, 8.0]);
+        assert!((&a + &b).mult(&c).eval_into(&mut out).is_err());
+    }
+
+    #[test]
+    fn check_expression_shapes() {
+        let a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b: Matrix<i32> = Matrix::new(3, 2, vec![1, 0, 0, 1, 1, 1]);
+
+        assert!((&a + &b).eval().is_err());
+        assert!(MatrixExpr::mult(&a, &a).eval().is_err());
+        assert!(MatrixExpr::mult(&a, &b)
+            .plus(MatrixExpr::mult(&b, &a))
+            .eval()
+            .is_err());
+        let block = a.view(0..2, 1..3).unwrap();
+        assert_eq!(
+            block.mult(b.view(0..2, 0..2).unwrap()).eval().unwrap(),
+            Matrix::new(2, 2, vec![2, 3, 5, 6])
+        );
+        assert_eq!(
+            MatrixExpr::mult(&a, &b)
+                .minus(MatrixExpr::mult(&a, &b))
+                .eval()
+                .unwrap(),
+            Matrix::new(2, 2, vec![0; 4])
+        );
+    }
+}
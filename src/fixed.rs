@@ -0,0 +1,343 @@
+use crate::error::{product_mismatch, MatrixError, Op};
+use crate::identity_element::IdentityElement;
+use crate::matrix::{checked_len, Matrix};
+use crate::random::RandomSource;
+use crate::rounding::Rounding;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// A signed 32-bit fixed-point number with `FRAC` fractional bits.
+///
+/// All arithmetic saturates at the representable range instead of wrapping,
+/// and products and quotients are computed in 64 bits and rounded to nearest.
+/// `FRAC` must be less than 32; a larger one fails to compile as soon as a
+/// value is constructed from bits or a float, or multiplied or divided.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed32<const FRAC: u32>(i32);
+
+pub type I16F16 = Fixed32<16>;
+pub type I8F24 = Fixed32<24>;
+pub type I1F31 = Fixed32<31>;
+
+impl<const FRAC: u32> Fixed32<FRAC> {
+    pub const MIN: Fixed32<FRAC> = Fixed32(i32::MIN);
+    pub const MAX: Fixed32<FRAC> = Fixed32(i32::MAX);
+
+    /// Rejects `FRAC >= 32` at compile time, before any shift by it.
+    const fn check_frac() {
+        const { assert!(FRAC < 32, "Fixed32 needs fewer than 32 fractional bits") }
+    }
+
+    pub const fn from_bits(bits: i32) -> Fixed32<FRAC> {
+        Self::check_frac();
+        Fixed32(bits)
+    }
+
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    pub fn from_f64(value: f64) -> Fixed32<FRAC> {
+        Self::check_frac();
+        Fixed32((value * (1u64 << FRAC) as f64).round() as i32)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << FRAC) as f64
+    }
+
+    /// Rounds a value carrying `2 * FRAC` fractional bits back to `FRAC`.
+    fn narrow(wide: i64) -> Fixed32<FRAC> {
+        Self::check_frac();
+        let half = if FRAC == 0 { 0 } else { 1i64 << (FRAC - 1) };
+        let shifted = wide.saturating_add(half) >> FRAC;
+        Fixed32(shifted.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    /// Like `narrow`, but rounds up with probability equal to the
+    /// discarded fraction.
+    fn narrow_stochastic(wide: i64, rng: &mut dyn RandomSource) -> Fixed32<FRAC> {
+        Self::check_frac();
+        let noise = (rng.next_u64() & ((1u64 << FRAC) - 1)) as i64;
+        let shifted = wide.saturating_add(noise) >> FRAC;
+        Fixed32(shifted.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+
+    fn narrow_rounded(wide: i64, noise: Option<&mut dyn RandomSource>) -> Fixed32<FRAC> {
+        match noise {
+            None => Fixed32::narrow(wide),
+            Some(rng) => Fixed32::narrow_stochastic(wide, rng),
+        }
+    }
+}
+
+impl<const FRAC: u32> Add for Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    fn add(self, other: Fixed32<FRAC>) -> Fixed32<FRAC> {
+        Fixed32(self.0.saturating_add(other.0))
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    fn sub(self, other: Fixed32<FRAC>) -> Fixed32<FRAC> {
+        Fixed32(self.0.saturating_sub(other.0))
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    fn mul(self, other: Fixed32<FRAC>) -> Fixed32<FRAC> {
+        Fixed32::narrow(self.0 as i64 * other.0 as i64)
+    }
+}
+
+impl<const FRAC: u32> Mul<&Fixed32<FRAC>> for &Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    fn mul(self, other: &Fixed32<FRAC>) -> Fixed32<FRAC> {
+        *self * *other
+    }
+}
+
+impl<const FRAC: u32> Div for Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    /// Division by zero saturates towards the sign of the dividend.
+    fn div(self, other: Fixed32<FRAC>) -> Fixed32<FRAC> {
+        Self::check_frac();
+        if other.0 == 0 {
+            return if self.0 < 0 { Self::MIN } else { Self::MAX };
+        }
+        let (dividend, divisor) = ((self.0 as i64) << FRAC, other.0 as i64);
+        // Pushing the dividend half a divisor away from zero turns the
+        // truncating division into rounding to nearest, ties away from zero.
+        let half = (divisor.abs() / 2) * dividend.signum();
+        let quotient = (dividend + half) / divisor;
+        Fixed32(quotient.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+}
+
+impl<const FRAC: u32> Neg for Fixed32<FRAC> {
+    type Output = Fixed32<FRAC>;
+
+    fn neg(self) -> Fixed32<FRAC> {
+        Fixed32(self.0.saturating_neg())
+    }
+}
+
+impl<const FRAC: u32> AddAssign for Fixed32<FRAC> {
+    fn add_assign(&mut self, other: Fixed32<FRAC>) {
+        *self = *self + other;
+    }
+}
+
+impl<const FRAC: u32> IdentityElement for Fixed32<FRAC> {
+    fn zero() -> Self {
+        Fixed32(0)
+    }
+
+    /// Saturates to the largest value below one when `FRAC` is 31.
+    fn one() -> Self {
+        Self::check_frac();
+        Fixed32((1i64 << FRAC).min(i32::MAX as i64) as i32)
+    }
+}
+
+impl<const FRAC: u32> fmt::Display for Fixed32<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl<const FRAC: u32> Matrix<Fixed32<FRAC>> {
+    /// Multiplies with a widened accumulator, the way a DSP MAC unit does:
+    /// each dot product is summed exactly in 64 bits and rounded and
+    /// saturated once at the end. Unlike `mult_naive`, which rounds every
+    /// product, small terms are not lost and a partial sum may leave the
+    /// 32-bit range as long as the final result is back inside it.
+    pub fn mult_fixed(
+        &self,
+        matrix_b: &Matrix<Fixed32<FRAC>>,
+    ) -> Result<Matrix<Fixed32<FRAC>>, MatrixError> {
+        self.mult_fixed_rounded(matrix_b, Rounding::Nearest)
+    }
+
+    /// [`Matrix::mult_fixed`] with a choice of how each accumulated dot
+    /// product is rounded back to `FRAC` fractional bits.
+    pub fn mult_fixed_rounded(
+        &self,
+        matrix_b: &Matrix<Fixed32<FRAC>>,
+        mut rounding: Rounding<'_>,
+    ) -> Result<Matrix<Fixed32<FRAC>>, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let mut new_values = Vec::with_capacity(checked_len(self.rows, matrix_b.cols)?);
+
+        for i in 0..self.rows {
+            for j in 0..matrix_b.cols {
+                let mut sum: i64 = 0;
+                for k in 0..self.cols {
+                    let a = self.values[i * self.cols + k].0 as i64;
+                    let b = matrix_b.values[k * matrix_b.cols + j].0 as i64;
+                    sum = sum.saturating_add(a * b);
+                }
+                new_values.push(Fixed32::narrow_rounded(sum, rounding.noise()));
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<Fixed32<FRAC>>(
+            2 * self.rows * self.cols * matrix_b.cols,
+            self.values.len() + matrix_b.values.len() + new_values.len(),
+        );
+
+        Ok(Matrix::new(self.rows, matrix_b.cols, new_values))
+    }
+
+    /// `self <- self + alpha * update`, with each product kept exact in 64
+    /// bits and the sum rounded once, by `rounding`, and saturated. Under
+    /// round-to-nearest a step whose product is below half of the last
+    /// fractional bit never moves the accumulator; stochastic rounding
+    /// keeps it in expectation.
+    pub fn add_scaled_fixed(
+        &mut self,
+        alpha: Fixed32<FRAC>,
+        update: &Matrix<Fixed32<FRAC>>,
+        mut rounding: Rounding<'_>,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(update, Op::Add)?;
+
+        for (value, u) in self.values.iter_mut().zip(&update.values) {
+            let wide = ((value.0 as i64) << FRAC).saturating_add(alpha.0 as i64 * u.0 as i64);
+            *value = Fixed32::narrow_rounded(wide, rounding.noise());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixed::{Fixed32, I16F16};
+    use crate::matrix::Matrix;
+    use crate::random::SplitMix64;
+    use crate::rounding::Rounding;
+
+    fn fixed(values: &[f64]) -> Vec<I16F16> {
+        values.iter().map(|&v| I16F16::from_f64(v)).collect()
+    }
+
+    #[test]
+    fn check_fixed_arithmetic() {
+        let a = I16F16::from_f64(1.5);
+        let b = I16F16::from_f64(-2.25);
+
+        assert_eq!((a + b).to_f64(), -0.75);
+        assert_eq!((a * b).to_f64(), -3.375);
+        assert_eq!((b / a).to_f64(), -1.5);
+        let (one, three) = (I16F16::from_f64(1.0), I16F16::from_f64(3.0));
+        assert_eq!((one / three).to_bits(), 21845);
+        assert_eq!((-(one + one) / three).to_bits(), -43691);
+        assert_eq!(
+            (I16F16::from_bits(1) / I16F16::from_f64(-2.0)).to_bits(),
+            -1
+        );
+        assert_eq!(
+            I16F16::from_f64(200.0) * I16F16::from_f64(200.0),
+            I16F16::MAX
+        );
+        assert_eq!(Fixed32::<31>::MAX + Fixed32::<31>::MAX, Fixed32::<31>::MAX);
+
+        let matrix: Matrix<I16F16> = Matrix::new(2, 2, fixed(&[1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(
+            matrix.mult_naive(&Matrix::identity(2).unwrap()).unwrap(),
+            matrix.clone()
+        );
+    }
+
+    #[test]
+    fn check_mult_fixed_widening() {
+        let tiny = 1.0 / 512.0;
+        let matrix_a: Matrix<I16F16> = Matrix::new(1, 4, fixed(&[tiny; 4]));
+        let matrix_b: Matrix<I16F16> = Matrix::new(4, 1, fixed(&[tiny; 4]));
+
+        assert_eq!(
+            matrix_a.mult_naive(&matrix_b).unwrap().values[0].to_bits(),
+            0
+        );
+        assert_eq!(
+            matrix_a.mult_fixed(&matrix_b).unwrap().values[0].to_bits(),
+            1
+        );
+
+        let matrix_a: Matrix<I16F16> = Matrix::new(1, 2, fixed(&[200.0, 200.0]));
+        let matrix_b: Matrix<I16F16> = Matrix::new(2, 1, fixed(&[200.0, -199.0]));
+        assert_eq!(
+            matrix_a.mult_fixed(&matrix_b).unwrap(),
+            Matrix::new(1, 1, fixed(&[200.0]))
+        );
+        assert!(matrix_a.mult_fixed(&matrix_a).is_err());
+    }
+
+    #[test]
+    fn check_mult_fixed_stochastic() {
+        let quarter_ulp: Matrix<I16F16> = Matrix::new(1, 1, fixed(&[1.0 / 512.0]));
+        let nearest = quarter_ulp.mult_fixed(&quarter_ulp).unwrap();
+        assert_eq!(nearest.values[0].to_bits(), 0);
+
+        let mut rng = SplitMix64::new(0);
+        let rounded_up = (0..1000)
+            .map(|_| {
+                quarter_ulp
+                    .mult_fixed_rounded(&quarter_ulp, Rounding::Stochastic(&mut rng))
+                    .unwrap()
+                    .values[0]
+                    .to_bits()
+            })
+            .sum::<i32>();
+        assert!((200..300).contains(&rounded_up));
+    }
+
+    #[test]
+    fn check_add_scaled_fixed() {
+        // A learning rate times a gradient that is a quarter of the last
+        // fractional bit.
+        let rate = I16F16::from_f64(1.0 / 256.0);
+        let gradient: Matrix<I16F16> = Matrix::new(1, 2, fixed(&[1.0 / 1024.0, -1.0 / 1024.0]));
+        let mut nearest: Matrix<I16F16> = Matrix::new(1, 2, fixed(&[1.0, 1.0]));
+        let mut stochastic = nearest.clone();
+        let mut rng = SplitMix64::new(0);
+        for _ in 0..4000 {
+            nearest
+                .add_scaled_fixed(rate, &gradient, Rounding::Nearest)
+                .unwrap();
+            stochastic
+                .add_scaled_fixed(rate, &gradient, Rounding::Stochastic(&mut rng))
+                .unwrap();
+        }
+        assert_eq!(nearest.values, fixed(&[1.0, 1.0]));
+        let drift = [1000, -1000];
+        for (value, expected) in stochastic.values.iter().zip(drift) {
+            let moved = value.to_bits() - I16F16::from_f64(1.0).to_bits();
+            assert!((moved - expected).abs() < 100, "{}", moved);
+        }
+
+        let mut saturated: Matrix<I16F16> = Matrix::new(1, 1, vec![I16F16::MAX]);
+        saturated
+            .add_scaled_fixed(I16F16::MAX, &saturated.clone(), Rounding::Nearest)
+            .unwrap();
+        assert_eq!(saturated.values[0], I16F16::MAX);
+        assert!(saturated
+            .add_scaled_fixed(rate, &gradient, Rounding::Nearest)
+            .is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::config::{self, Config};
+use crate::identity_element::IdentityElement;
+use crate::scalar::MulRef;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// The floating-point operations needed by the decompositions.
+pub trait Float:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + MulRef
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + Copy
+    + Default
+    + PartialOrd
+    + IdentityElement
+{
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn epsilon() -> Self;
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+    /// `self * a + b` with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn is_subnormal(self) -> bool;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tanh(self) -> Self;
+    fn round(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+                fn from_f64(value: f64) -> Self {
+                    value as $t
+                }
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    $t::mul_add(self, a, b)
+                }
+                fn is_subnormal(self) -> bool {
+                    $t::is_subnormal(self)
+                }
+                fn exp(self) -> Self {
+                    $t::exp(self)
+                }
+                fn ln(self) -> Self {
+                    $t::ln(self)
+                }
+                fn powi(self, n: i32) -> Self {
+                    $t::powi(self, n)
+                }
+                fn powf(self, n: Self) -> Self {
+                    $t::powf(self, n)
+                }
+                fn sin(self) -> Self {
+                    $t::sin(self)
+                }
+                fn cos(self) -> Self {
+                    $t::cos(self)
+                }
+                fn tanh(self) -> Self {
+                    $t::tanh(self)
+                }
+                fn round(self) -> Self {
+                    $t::round(self)
+                }
+                fn floor(self) -> Self {
+                    $t::floor(self)
+                }
+                fn ceil(self) -> Self {
+                    $t::ceil(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// The floating-point kernel controls of a [`Config`], read once per call.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FloatEnv {
+    fma: bool,
+    flush: bool,
+}
+
+impl FloatEnv {
+    pub(crate) fn new(config: &Config) -> FloatEnv {
+        FloatEnv {
+            fma: config.fused_multiply_add,
+            flush: config.flush_denormals,
+        }
+    }
+
+    pub(crate) fn current() -> FloatEnv {
+        FloatEnv::new(&config::current())
+    }
+
+    /// `a * b + c`, fused if requested, then flushed.
+    pub(crate) fn mul_add<T: Float>(self, a: T, b: T, c: T) -> T {
+        self.flush(if self.fma { a.mul_add(b, c) } else { a * b + c })
+    }
+
+    pub(crate) fn flush<T: Float>(self, value: T) -> T {
+        if self.flush && value.is_subnormal() {
+            T::zero()
+        } else {
+            value
+        }
+    }
+
+    pub(crate) fn dot<T: Float>(self, a: &[T], b: &[T]) -> T {
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |sum, (&x, &y)| self.mul_add(x, y, sum))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::float::FloatEnv;
+
+    #[test]
+    fn check_float_env() {
+        let tiny = f64::MIN_POSITIVE / 4.0;
+        let plain = FloatEnv::new(&Config::default());
+        let flushing = FloatEnv::new(&Config {
+            flush_denormals: true,
+            ..Config::default()
+        });
+        assert_eq!(plain.mul_add(tiny, 1.0, 0.0), tiny);
+        assert_eq!(flushing.mul_add(tiny, 1.0, 0.0), 0.0);
+        assert_eq!(flushing.mul_add(2.0, 3.0, 1.0), 7.0);
+
+        let a = 1.0 + f64::EPSILON;
+        let b = 1.0 - f64::EPSILON;
+        let fused = FloatEnv::new(&Config {
+            fused_multiply_add: true,
+            ..Config::default()
+        });
+        assert_eq!(plain.mul_add(a, b, -1.0), 0.0);
+        assert_eq!(fused.mul_add(a, b, -1.0), -f64::EPSILON * f64::EPSILON);
+        assert_eq!(fused.dot(&[1.0, 2.0], &[3.0, 4.0]), 11.0);
+    }
+}
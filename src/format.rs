@@ -0,0 +1,238 @@
+use crate::matrix::Matrix;
+use std::fmt::{self, Display, LowerExp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexEnvironment {
+    /// Parentheses, `\begin{pmatrix}`.
+    PMatrix,
+    /// Square brackets, `\begin{bmatrix}`.
+    BMatrix,
+    /// Vertical bars for determinants, `\begin{vmatrix}`.
+    VMatrix,
+}
+
+impl LatexEnvironment {
+    fn name(self) -> &'static str {
+        match self {
+            LatexEnvironment::PMatrix => "pmatrix",
+            LatexEnvironment::BMatrix => "bmatrix",
+            LatexEnvironment::VMatrix => "vmatrix",
+        }
+    }
+}
+
+/// Controls how [`Matrix::format`] and [`Matrix::format_scientific`] render
+/// a matrix. Matrices larger than
+/// `max_rows` x `max_cols` show their leading and trailing rows and columns
+/// around an ellipsis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Digits after the decimal point; `None` prints the shortest form.
+    pub precision: Option<usize>,
+    pub max_rows: usize,
+    pub max_cols: usize,
+    /// Minimum cell width. Cells are widened to fit the longest entry.
+    pub width: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            precision: None,
+            max_rows: 10,
+            max_cols: 10,
+            width: 0,
+        }
+    }
+}
+
+/// The indices kept out of `len` when at most `max` may be shown, with a
+/// `None` marking where the elided run goes.
+fn shown_indices(len: usize, max: usize) -> Vec<Option<usize>> {
+    if len <= max {
+        return (0..len).map(Some).collect();
+    }
+    let head = max.div_ceil(2);
+    let tail = max / 2;
+    (0..head)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - tail..len).map(Some))
+        .collect()
+}
+
+impl<T> Matrix<T> {
+    /// Lays out the shown cells, rendered by `cell`, as right-aligned
+    /// columns.
+    fn layout<F: Fn(&T) -> String>(&self, options: &FormatOptions, cell: F) -> String {
+        if self.rows == 0 || self.cols == 0 {
+            return String::new();
+        }
+        let rows = shown_indices(self.rows, options.max_rows);
+        let cols = shown_indices(self.cols, options.max_cols);
+
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                cols.iter()
+                    .map(|col| match (row, col) {
+                        (Some(i), Some(j)) => cell(&self.values[i * self.cols + j]),
+                        _ => "...".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.chars().count())
+            .fold(options.width, usize::max);
+        cells
+            .iter()
+            .map(|row| {
+                let line: Vec<String> = row
+                    .iter()
+                    .map(|cell| format!("{:>width$}", cell, width = width))
+                    .collect();
+                line.join("  ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Renders the matrix as right-aligned columns, one line per row. An
+    /// empty matrix renders as the empty string.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        self.layout(options, |value| match options.precision {
+            Some(digits) => format!("{:.*}", digits, value),
+            None => value.to_string(),
+        })
+    }
+}
+
+impl<T: LowerExp> Matrix<T> {
+    /// As [`Matrix::format`], with every element in scientific notation.
+    pub fn format_scientific(&self, options: &FormatOptions) -> String {
+        self.layout(options, |value| match options.precision {
+            Some(digits) => format!("{:.*e}", digits, value),
+            None => format!("{:e}", value),
+        })
+    }
+}
+
+impl<T: Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format(&FormatOptions::default()))
+    }
+}
+
+impl<T: Display> Matrix<T> {
+    /// Formats every element, with `precision` digits after the decimal
+    /// point for floating-point types. Integers ignore the precision.
+    fn formatted_rows(&self, precision: Option<usize>) -> Vec<Vec<String>> {
+        self.values
+            .chunks(self.cols.max(1))
+            .take(self.rows)
+            .map(|row| {
+                row.iter()
+                    .map(|value| match precision {
+                        Some(digits) => format!("{:.*}", digits, value),
+                        None => value.to_string(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn to_latex(&self, environment: LatexEnvironment, precision: Option<usize>) -> String {
+        let rows: Vec<String> = self
+            .formatted_rows(precision)
+            .iter()
+            .map(|row| row.join(" & "))
+            .collect();
+
+        format!(
+            "\\begin{{{name}}}\n{}\n\\end{{{name}}}",
+            rows.join(" \\\\\n"),
+            name = environment.name()
+        )
+    }
+
+    /// A GitHub-flavored Markdown table with right-aligned columns headed by
+    /// their 1-based index.
+    pub fn to_markdown_table(&self, precision: Option<usize>) -> String {
+        let header: Vec<String> = (1..=self.cols).map(|col| col.to_string()).collect();
+        let mut lines = vec![
+            format!("| {} |", header.join(" | ")),
+            format!("|{}", "---:|".repeat(self.cols)),
+        ];
+        for row in self.formatted_rows(precision) {
+            lines.push(format!("| {} |", row.join(" | ")));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{FormatOptions, LatexEnvironment};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_latex() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, -0.5, 1.0 / 3.0, 4.0]);
+
+        assert_eq!(
+            matrix.to_latex(LatexEnvironment::BMatrix, Some(2)),
+            "\\begin{bmatrix}\n1.00 & -0.50 \\\\\n0.33 & 4.00\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            Matrix::new(1, 2, vec![1, 2]).to_latex(LatexEnvironment::PMatrix, None),
+            "\\begin{pmatrix}\n1 & 2\n\\end{pmatrix}"
+        );
+    }
+
+    #[test]
+    fn check_markdown_table() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 2.5, -3.0, 0.125]);
+
+        assert_eq!(
+            matrix.to_markdown_table(Some(1)),
+            "| 1 | 2 |\n|---:|---:|\n| 1.0 | 2.5 |\n| -3.0 | 0.1 |"
+        );
+    }
+
+    #[test]
+    fn check_format() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, -0.5, 1250.0, 4.0]);
+
+        assert_eq!(matrix.to_string(), "   1  -0.5\n1250     4");
+        let options = FormatOptions {
+            precision: Some(1),
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            matrix.format_scientific(&options),
+            "  1.0e0  -5.0e-1\n  1.2e3    4.0e0"
+        );
+        assert_eq!(
+            Matrix::new(1, 2, vec![true, false]).to_string(),
+            " true  false"
+        );
+
+        let large = Matrix::new(5, 5, (0..25).collect());
+        let options = FormatOptions {
+            max_rows: 2,
+            max_cols: 3,
+            width: 3,
+            ..FormatOptions::default()
+        };
+        assert_eq!(
+            large.format(&options),
+            "  0    1  ...    4\n...  ...  ...  ...\n 20   21  ...   24"
+        );
+    }
+}
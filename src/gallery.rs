@@ -0,0 +1,226 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::{checked_len, Matrix};
+use crate::random::RandomSource;
+
+/// The `n`x`n` Hilbert matrix `1 / (i + j + 1)`, a classic example of
+/// severe ill-conditioning: its condition number grows like `e^(3.5 n)`.
+pub fn hilbert<T: Float>(n: usize) -> Matrix<T> {
+    Matrix::from_fn(n, n, |i, j| T::one() / T::from_f64((i + j + 1) as f64))
+}
+
+/// The matrix that is constant along each diagonal, with first column
+/// `first_col` and first row `first_row`. Where they overlap at (0, 0),
+/// `first_col[0]` wins.
+pub fn toeplitz<T: Clone>(first_col: &[T], first_row: &[T]) -> Matrix<T> {
+    Matrix::from_fn(first_col.len(), first_row.len(), |i, j| {
+        if i >= j {
+            first_col[i - j].clone()
+        } else {
+            first_row[j - i].clone()
+        }
+    })
+}
+
+/// The matrix that is constant along each anti-diagonal, with first column
+/// `first_col` and last row `last_row`. Where they overlap at the bottom
+/// left, `first_col`'s last element wins.
+pub fn hankel<T: Clone>(first_col: &[T], last_row: &[T]) -> Matrix<T> {
+    let rows = first_col.len();
+    Matrix::from_fn(rows, last_row.len(), |i, j| {
+        if i + j < rows {
+            first_col[i + j].clone()
+        } else {
+            last_row[i + j + 1 - rows].clone()
+        }
+    })
+}
+
+/// The square matrix whose columns are successive cyclic shifts of
+/// `first_col`.
+pub fn circulant<T: Clone>(first_col: &[T]) -> Matrix<T> {
+    let n = first_col.len();
+    Matrix::from_fn(n, n, |i, j| first_col[(n + i - j) % n].clone())
+}
+
+/// An `n`x`n` magic square of `1..=n^2`, laid out as MATLAB's `magic(n)`.
+/// There is no magic square of order 2.
+pub fn magic<T: Float>(n: usize) -> Result<Matrix<T>, MatrixError> {
+    checked_len(n, n)?;
+    let square = match n {
+        2 => {
+            return Err(MatrixError::InvalidArgument(
+                "No magic square of order 2 exists".to_string(),
+            ))
+        }
+        _ if n % 2 == 1 => odd_magic(n),
+        _ if n.is_multiple_of(4) => Matrix::from_fn(n, n, |i, j| {
+            let k = i * n + j + 1;
+            if i % 4 == j % 4 || i % 4 + j % 4 == 3 {
+                n * n + 1 - k
+            } else {
+                k
+            }
+        }),
+        _ => singly_even_magic(n),
+    };
+    Ok(square.map(|&v| T::from_f64(v as f64)))
+}
+
+/// The Siamese method: walk up and to the right, dropping down a row
+/// whenever the next cell is taken.
+fn odd_magic(n: usize) -> Matrix<usize> {
+    let mut values = vec![0; n * n];
+    let (mut i, mut j) = (0, n / 2);
+    for k in 1..=n * n {
+        values[i * n + j] = k;
+        let (up, right) = ((i + n - 1) % n, (j + 1) % n);
+        if values[up * n + right] == 0 {
+            (i, j) = (up, right);
+        } else {
+            i = (i + 1) % n;
+        }
+    }
+    Matrix::new(n, n, values)
+}
+
+/// The LUX construction from four odd squares of order `n / 2`, with
+/// column swaps between the top and bottom halves.
+fn singly_even_magic(n: usize) -> Matrix<usize> {
+    let p = n / 2;
+    let quarter = odd_magic(p);
+    let offsets = [[0, 2], [3, 1]];
+    let mut values = Matrix::from_fn(n, n, |i, j| {
+        quarter.values[(i % p) * p + j % p] + offsets[i / p][j / p] * p * p
+    })
+    .values;
+
+    let k = (n - 2) / 4;
+    let swap = |values: &mut Vec<usize>, i: usize, j: usize| {
+        values.swap(i * n + j, (i + p) * n + j);
+    };
+    for i in 0..p {
+        for j in (0..k).chain(n + 1 - k..n) {
+            swap(&mut values, i, j);
+        }
+    }
+    swap(&mut values, k, 0);
+    swap(&mut values, k, k);
+    Matrix::new(n, n, values)
+}
+
+/// A random `n`x`n` matrix with 2-norm condition number `condition`,
+/// built as `U S V` from two random Householder reflections and singular
+/// values spaced geometrically from 1 down to `1 / condition`. A small
+/// `condition` gives a well-conditioned test matrix, a large one an
+/// ill-conditioned matrix whose difficulty is known exactly. A generator in
+/// the same state gives the same matrix.
+pub fn random_with_condition<T: Float, R: RandomSource + ?Sized>(
+    n: usize,
+    condition: f64,
+    rng: &mut R,
+) -> Result<Matrix<T>, MatrixError> {
+    if !condition.is_finite() || condition < 1.0 {
+        return Err(MatrixError::InvalidArgument(
+            "Condition number must be finite and at least 1".to_string(),
+        ));
+    }
+
+    let mut reflector = || {
+        let u: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+        let norm_squared: f64 = u.iter().map(|v| v * v).sum();
+        move |i: usize, j: usize| {
+            let identity = if i == j { 1.0 } else { 0.0 };
+            identity - 2.0 * u[i] * u[j] / norm_squared
+        }
+    };
+    let left = reflector();
+    let right = reflector();
+    let singular = |k: usize| {
+        if n > 1 {
+            condition.powf(-(k as f64) / (n - 1) as f64)
+        } else {
+            1.0
+        }
+    };
+
+    Ok(Matrix::from_fn(n, n, |i, j| {
+        let entry: f64 = (0..n).map(|k| left(i, k) * singular(k) * right(k, j)).sum();
+        T::from_f64(entry)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gallery::{circulant, hankel, hilbert, magic, random_with_condition, toeplitz};
+    use crate::matrix::Matrix;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn check_structured_matrices() {
+        let h: Matrix<f64> = hilbert(3);
+        assert_eq!(h.values[..3], [1.0, 0.5, 1.0 / 3.0]);
+        assert_eq!(h, h.transpose());
+
+        assert_eq!(
+            toeplitz(&[1, 2, 3], &[9, 4, 5, 6]).values,
+            vec![1, 4, 5, 6, 2, 1, 4, 5, 3, 2, 1, 4]
+        );
+        assert_eq!(
+            hankel(&[1, 2, 3], &[9, 4, 5]).values,
+            vec![1, 2, 3, 2, 3, 4, 3, 4, 5]
+        );
+        assert_eq!(
+            circulant(&[1, 2, 3]).values,
+            vec![1, 3, 2, 2, 1, 3, 3, 2, 1]
+        );
+        assert!(circulant::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn check_magic_squares() {
+        assert_eq!(
+            magic::<f64>(3).unwrap().values,
+            vec![8.0, 1.0, 6.0, 3.0, 5.0, 7.0, 4.0, 9.0, 2.0]
+        );
+        assert!(magic::<f64>(2).is_err());
+
+        for n in [1, 4, 5, 6, 8, 10] {
+            let square: Matrix<f64> = magic(n).unwrap();
+            let target = (n * (n * n + 1) / 2) as f64;
+            assert!(square.row_sums().values.iter().all(|&s| s == target));
+            assert!(square.col_sums().values.iter().all(|&s| s == target));
+            assert_eq!(square.trace().unwrap(), target);
+            assert_eq!(square.fliplr().trace().unwrap(), target);
+
+            let mut entries = square.values.clone();
+            entries.sort_by(f64::total_cmp);
+            assert!(entries
+                .iter()
+                .enumerate()
+                .all(|(i, &v)| v == (i + 1) as f64));
+        }
+    }
+
+    #[test]
+    fn check_random_with_condition() {
+        let a: Matrix<f64> = random_with_condition(4, 1e3, &mut SplitMix64::new(7)).unwrap();
+        assert_eq!(
+            a,
+            random_with_condition(4, 1e3, &mut SplitMix64::new(7)).unwrap()
+        );
+
+        // A^T A has eigenvalues sigma^2, so its extremes give the condition.
+        let gram = a.transpose().mult(&a).unwrap();
+        let eigen = gram.symmetric_eigen().unwrap();
+        let (min, max) = eigen
+            .values
+            .iter()
+            .fold((f64::INFINITY, 0.0f64), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        assert!(((max / min).sqrt() / 1e3 - 1.0).abs() < 1e-6);
+
+        assert!(random_with_condition::<f64, _>(3, 0.5, &mut SplitMix64::new(1)).is_err());
+    }
+}
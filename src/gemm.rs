@@ -0,0 +1,101 @@
+use crate::error::{product_mismatch, MatrixError, Op};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+/// Whether [`gemm`] reads an operand as stored or as its transpose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    No,
+    Yes,
+}
+
+impl Transpose {
+    fn shape<T>(self, matrix: &Matrix<T>) -> (usize, usize) {
+        match self {
+            Transpose::No => (matrix.rows, matrix.cols),
+            Transpose::Yes => (matrix.cols, matrix.rows),
+        }
+    }
+
+    fn index<T>(self, matrix: &Matrix<T>, row: usize, col: usize) -> usize {
+        match self {
+            Transpose::No => row * matrix.cols + col,
+            Transpose::Yes => col * matrix.cols + row,
+        }
+    }
+}
+
+/// `C <- alpha op(A) op(B) + beta C` in place, where `op` optionally
+/// transposes. Nothing is allocated, and `beta C` is always evaluated, so a
+/// NaN in `C` survives `beta = 0` unlike in reference BLAS.
+#[allow(clippy::too_many_arguments)]
+pub fn gemm<T>(
+    alpha: T,
+    a: &Matrix<T>,
+    transpose_a: Transpose,
+    b: &Matrix<T>,
+    transpose_b: Transpose,
+    beta: T,
+    c: &mut Matrix<T>,
+) -> Result<(), MatrixError>
+where
+    T: Scalar,
+{
+    let (m, k) = transpose_a.shape(a);
+    let (k_b, n) = transpose_b.shape(b);
+    if k != k_b {
+        return Err(product_mismatch((m, k), (k_b, n)));
+    }
+    if c.rows != m || c.cols != n {
+        return Err(MatrixError::DimensionMismatch {
+            op: Op::Output,
+            lhs: (c.rows, c.cols),
+            rhs: (m, n),
+        });
+    }
+
+    for i in 0..m {
+        for j in 0..n {
+            let mut sum = T::zero();
+            for l in 0..k {
+                sum += a.values[transpose_a.index(a, i, l)]
+                    .mul_ref(&b.values[transpose_b.index(b, l, j)]);
+            }
+            let entry = &mut c.values[i * n + j];
+            let scaled = beta.clone() * std::mem::take(entry);
+            *entry = alpha.clone() * sum + scaled;
+        }
+    }
+
+    #[cfg(feature = "profiling")]
+    crate::profile::record::<T>(
+        2 * m * n * k + 3 * m * n,
+        a.values.len() + b.values.len() + 2 * c.values.len(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gemm::{gemm, Transpose};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_gemm() {
+        let a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let b: Matrix<i32> = Matrix::new(3, 2, vec![7, 8, 9, 10, 11, 12]);
+        let mut c: Matrix<i32> = Matrix::new(2, 2, vec![1, 1, 1, 1]);
+
+        gemm(2, &a, Transpose::No, &b, Transpose::No, 3, &mut c).unwrap();
+        assert_eq!(c.values, vec![119, 131, 281, 311]);
+
+        let a_t = a.transpose();
+        let b_t = b.transpose();
+        let mut d: Matrix<i32> = Matrix::new(2, 2, vec![5, 5, 5, 5]);
+        gemm(1, &a_t, Transpose::Yes, &b_t, Transpose::Yes, 0, &mut d).unwrap();
+        assert_eq!(d.values, vec![58, 64, 139, 154]);
+
+        assert!(gemm(1, &a, Transpose::Yes, &b, Transpose::No, 0, &mut d).is_err());
+    }
+}
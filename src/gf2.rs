@@ -0,0 +1,311 @@
+use crate::error::{elementwise_mismatch, product_mismatch, MatrixError, Op};
+use crate::matrix::checked_len;
+
+/// A matrix over GF(2) with each row packed into 64-bit words.
+///
+/// Addition is XOR and multiplication is AND, so whole words of a row are
+/// processed at once. Bits past `cols` in the last word of a row are kept
+/// zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatrixGF2 {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl MatrixGF2 {
+    pub fn zeros(rows: usize, cols: usize) -> Result<MatrixGF2, MatrixError> {
+        let words_per_row = cols.div_ceil(64);
+        let len = checked_len(rows, words_per_row)?;
+
+        Ok(MatrixGF2 {
+            rows,
+            cols,
+            words_per_row,
+            bits: vec![0; len],
+        })
+    }
+
+    pub fn identity(order: usize) -> Result<MatrixGF2, MatrixError> {
+        let mut matrix = MatrixGF2::zeros(order, order)?;
+        for i in 0..order {
+            matrix.set_bit(i, i, true);
+        }
+        Ok(matrix)
+    }
+
+    /// Builds a matrix from row-major values, keeping only the lowest bit of
+    /// each one.
+    pub fn from_values(rows: usize, cols: usize, values: &[u8]) -> Result<MatrixGF2, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        if values.len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+
+        let mut matrix = MatrixGF2::zeros(rows, cols)?;
+        for (index, value) in values.iter().enumerate() {
+            matrix.set_bit(index / cols, index % cols, value & 1 == 1);
+        }
+        Ok(matrix)
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn to_values(&self) -> Vec<u8> {
+        (0..self.rows * self.cols)
+            .map(|index| self.bit(index / self.cols, index % self.cols) as u8)
+            .collect()
+    }
+
+    pub(crate) fn bit(&self, row: usize, col: usize) -> bool {
+        self.bits[row * self.words_per_row + col / 64] >> (col % 64) & 1 == 1
+    }
+
+    pub(crate) fn set_bit(&mut self, row: usize, col: usize, value: bool) {
+        let word = &mut self.bits[row * self.words_per_row + col / 64];
+        let mask = 1u64 << (col % 64);
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    fn check_index(&self, row: usize, col: usize) -> Result<(), MatrixError> {
+        if row < self.rows && col < self.cols {
+            Ok(())
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (self.rows, self.cols),
+            })
+        }
+    }
+
+    fn check_same_shape(&self, matrix_b: &MatrixGF2, op: Op) -> Result<(), MatrixError> {
+        if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
+            return Err(elementwise_mismatch(
+                op,
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<bool, MatrixError> {
+        self.check_index(row, col)?;
+        Ok(self.bit(row, col))
+    }
+
+    pub fn set_value(&mut self, row: usize, col: usize, value: bool) -> Result<(), MatrixError> {
+        self.check_index(row, col)?;
+        self.set_bit(row, col, value);
+        Ok(())
+    }
+
+    pub(crate) fn row(&self, row: usize) -> &[u64] {
+        &self.bits[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    fn xor_row_into(&mut self, source: usize, target: usize) {
+        for w in 0..self.words_per_row {
+            self.bits[target * self.words_per_row + w] ^=
+                self.bits[source * self.words_per_row + w];
+        }
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for w in 0..self.words_per_row {
+            self.bits
+                .swap(a * self.words_per_row + w, b * self.words_per_row + w);
+        }
+    }
+
+    pub fn add(&self, matrix_b: &MatrixGF2) -> Result<MatrixGF2, MatrixError> {
+        let mut result = self.clone();
+        result.add_mut(matrix_b)?;
+        Ok(result)
+    }
+
+    pub fn add_mut(&mut self, matrix_b: &MatrixGF2) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+
+        for (a, b) in self.bits.iter_mut().zip(&matrix_b.bits) {
+            *a ^= b;
+        }
+        Ok(self)
+    }
+
+    pub fn mult(&self, matrix_b: &MatrixGF2) -> Result<MatrixGF2, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let mut result = MatrixGF2::zeros(self.rows, matrix_b.cols)?;
+        let words = result.words_per_row;
+
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                if self.bit(i, k) {
+                    let target = &mut result.bits[i * words..(i + 1) * words];
+                    for (t, s) in target.iter_mut().zip(matrix_b.row(k)) {
+                        *t ^= s;
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn transpose(&self) -> MatrixGF2 {
+        let mut result = MatrixGF2 {
+            rows: self.cols,
+            cols: self.rows,
+            words_per_row: self.rows.div_ceil(64),
+            bits: vec![0; self.cols * self.rows.div_ceil(64)],
+        };
+
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if self.bit(i, j) {
+                    result.set_bit(j, i, true);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Reduces the matrix to reduced row echelon form in place and returns
+    /// the pivot column of each nonzero row.
+    pub fn rref_mut(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        let mut row = 0;
+
+        for col in 0..self.cols {
+            if row == self.rows {
+                break;
+            }
+            let Some(pivot) = (row..self.rows).find(|&r| self.bit(r, col)) else {
+                continue;
+            };
+            self.swap_rows(row, pivot);
+
+            for r in 0..self.rows {
+                if r != row && self.bit(r, col) {
+                    self.xor_row_into(row, r);
+                }
+            }
+
+            pivots.push(col);
+            row += 1;
+        }
+
+        pivots
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().rref_mut().len()
+    }
+
+    /// Solves `A x = b`, returning one solution (free variables set to zero),
+    /// or `None` if the system is inconsistent.
+    pub fn solve(&self, b: &[bool]) -> Result<Option<Vec<bool>>, MatrixError> {
+        if b.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (self.rows, self.cols),
+                rhs: (b.len(), 1),
+            });
+        }
+
+        let mut augmented = MatrixGF2::zeros(self.rows, self.cols + 1)?;
+        for (i, &rhs) in b.iter().enumerate() {
+            for j in 0..self.cols {
+                augmented.set_bit(i, j, self.bit(i, j));
+            }
+            augmented.set_bit(i, self.cols, rhs);
+        }
+
+        let pivots = augmented.rref_mut();
+        if pivots.last() == Some(&self.cols) {
+            return Ok(None);
+        }
+
+        let mut x = vec![false; self.cols];
+        for (row, &col) in pivots.iter().enumerate() {
+            x[col] = augmented.bit(row, self.cols);
+        }
+        Ok(Some(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gf2::MatrixGF2;
+
+    #[test]
+    fn check_gf2_arithmetic() {
+        let matrix_a = MatrixGF2::from_values(2, 2, &[1, 1, 0, 1]).unwrap();
+        let matrix_b = MatrixGF2::from_values(2, 2, &[1, 0, 1, 1]).unwrap();
+
+        assert_eq!(
+            matrix_a.add(&matrix_b).unwrap().to_values(),
+            vec![0, 1, 1, 0]
+        );
+        assert_eq!(
+            matrix_a.mult(&matrix_b).unwrap().to_values(),
+            vec![0, 1, 1, 1]
+        );
+        assert_eq!(matrix_a.transpose().to_values(), vec![1, 0, 1, 1]);
+        assert_eq!(
+            matrix_a.mult(&MatrixGF2::identity(2).unwrap()).unwrap(),
+            matrix_a
+        );
+        assert!(matrix_a.mult(&MatrixGF2::zeros(3, 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn check_gf2_wide_rows() {
+        let mut matrix = MatrixGF2::zeros(2, 130).unwrap();
+        matrix.set_value(0, 129, true).unwrap();
+        matrix.set_value(1, 64, true).unwrap();
+
+        let product = matrix.mult(&matrix.transpose()).unwrap();
+        assert_eq!(product.to_values(), vec![1, 0, 0, 1]);
+        assert!(matrix.value_at(0, 130).is_err());
+    }
+
+    #[test]
+    fn check_gf2_rank_and_solve() {
+        let matrix = MatrixGF2::from_values(3, 3, &[1, 1, 0, 0, 1, 1, 1, 0, 1]).unwrap();
+        assert_eq!(matrix.rank(), 2);
+        assert_eq!(
+            matrix.solve(&[true, false, true]).unwrap(),
+            Some(vec![true, false, false])
+        );
+        assert_eq!(matrix.solve(&[true, false, false]).unwrap(), None);
+
+        let invertible = MatrixGF2::from_values(2, 2, &[0, 1, 1, 1]).unwrap();
+        assert_eq!(invertible.rank(), 2);
+        assert_eq!(
+            invertible.solve(&[true, false]).unwrap(),
+            Some(vec![true, true])
+        );
+    }
+}
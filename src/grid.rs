@@ -0,0 +1,104 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+impl<T: Float> Matrix<T> {
+    /// `n` evenly spaced values from `start` to `end` inclusive, as a 1x`n`
+    /// row vector. The last value is exactly `end`.
+    pub fn linspace(start: T, end: T, n: usize) -> Matrix<T> {
+        let step = if n > 1 {
+            (end - start) / T::from_f64((n - 1) as f64)
+        } else {
+            T::zero()
+        };
+        let mut values: Vec<T> = (0..n)
+            .map(|i| start + step * T::from_f64(i as f64))
+            .collect();
+        if let Some(last) = values.last_mut().filter(|_| n > 1) {
+            *last = end;
+        }
+        Matrix::new(1, n, values)
+    }
+
+    /// `start, start + step, ...` up to but excluding `end`, as a row vector.
+    /// A step pointing away from `end` gives an empty vector.
+    pub fn arange(start: T, end: T, step: T) -> Result<Matrix<T>, MatrixError> {
+        let count = ((end - start) / step).to_f64().ceil();
+        if !count.is_finite() || step == T::zero() {
+            return Err(MatrixError::InvalidArgument(
+                "arange needs finite bounds and a finite, non-zero step".to_string(),
+            ));
+        }
+        // A buffer may not exceed isize::MAX bytes, so larger counts could
+        // never be allocated; `as usize` would saturate rather than fail.
+        let limit = isize::MAX as usize / std::mem::size_of::<T>().max(1);
+        if count > limit as f64 {
+            return Err(MatrixError::SizeOverflow(format!(
+                "arange would produce {:e} values, more than fit in memory",
+                count
+            )));
+        }
+        let n = count.max(0.0) as usize;
+        let values = (0..n)
+            .map(|i| start + step * T::from_f64(i as f64))
+            .collect();
+        Ok(Matrix::new(1, n, values))
+    }
+
+    /// Coordinate matrices for the grid spanned by the vectors `x` and `y`,
+    /// both `y.len()`x`x.len()`: the first repeats `x` in every row and the
+    /// second repeats `y` in every column.
+    pub fn meshgrid(x: &Matrix<T>, y: &Matrix<T>) -> Result<(Matrix<T>, Matrix<T>), MatrixError> {
+        let (nx, ny) = (x.values.len(), y.values.len());
+        let grid_x = Matrix::new(1, nx, x.values.clone()).repeat_rows(ny)?;
+        let grid_y = Matrix::new(ny, 1, y.values.clone()).repeat_cols(nx)?;
+        Ok((grid_x, grid_y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_ranges() {
+        assert_eq!(
+            Matrix::linspace(0.0, 1.0, 5).values,
+            vec![0.0, 0.25, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(Matrix::linspace(0.1, 0.7, 7).values[6], 0.7);
+        assert_eq!(Matrix::linspace(2.0, 3.0, 1).values, vec![2.0]);
+        assert!(Matrix::<f64>::linspace(2.0, 3.0, 0).is_empty());
+
+        let range = Matrix::arange(0.0, 1.0, 0.3).unwrap();
+        assert_eq!((range.get_rows(), range.get_cols()), (1, 4));
+        assert_eq!(
+            Matrix::arange(3.0, 0.0, -1.0).unwrap().values,
+            vec![3.0, 2.0, 1.0]
+        );
+        assert!(Matrix::arange(0.0, 1.0, -1.0).unwrap().is_empty());
+        assert!(matches!(
+            Matrix::arange(0.0, 1e30, 1.0),
+            Err(MatrixError::SizeOverflow(_))
+        ));
+        assert!(Matrix::arange(0.0, 1.0, 0.0).is_err());
+        assert!(Matrix::arange(0.0, f64::INFINITY, 1.0).is_err());
+    }
+
+    #[test]
+    fn check_meshgrid() {
+        let x = Matrix::linspace(0.0, 2.0, 3);
+        let y = Matrix::new(2, 1, vec![10.0, 20.0]);
+        let (grid_x, grid_y) = Matrix::meshgrid(&x, &y).unwrap();
+
+        assert_eq!(
+            grid_x,
+            Matrix::new(2, 3, vec![0.0, 1.0, 2.0, 0.0, 1.0, 2.0])
+        );
+        assert_eq!(
+            grid_y,
+            Matrix::new(2, 3, vec![10.0, 10.0, 10.0, 20.0, 20.0, 20.0])
+        );
+    }
+}
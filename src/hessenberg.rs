@@ -0,0 +1,118 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// An orthogonal similarity `A = Q H Q^T` with `H` upper Hessenberg: zero
+/// below the first subdiagonal. QR iterations such as [`Matrix::schur`]
+/// start from `H`, where each step costs `O(n^2)` instead of `O(n^3)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hessenberg<T> {
+    h: Matrix<T>,
+    q: Matrix<T>,
+}
+
+impl<T: Float> Hessenberg<T> {
+    pub fn h(&self) -> &Matrix<T> {
+        &self.h
+    }
+
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    pub fn into_parts(self) -> (Matrix<T>, Matrix<T>) {
+        (self.h, self.q)
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Reduces `self` to upper Hessenberg form by Householder reflections.
+    /// A symmetric input gives a tridiagonal `H`, up to rounding.
+    pub fn hessenberg(&self) -> Result<Hessenberg<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+        let n = self.rows;
+        let mut h = self.clone();
+        let mut q: Matrix<T> = Matrix::identity_within(n);
+
+        for j in 0..n.saturating_sub(2) {
+            let mut v: Vec<T> = (j + 1..n).map(|i| h.values[i * n + j]).collect();
+            let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            let alpha = if v[0] > T::zero() { -norm } else { norm };
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+            if v_norm == T::zero() {
+                continue;
+            }
+
+            for c in j..n {
+                let dot =
+                    (j + 1..n).fold(T::zero(), |acc, i| acc + v[i - j - 1] * h.values[i * n + c]);
+                let scale = (dot + dot) / v_norm;
+                for i in j + 1..n {
+                    h.values[i * n + c] = h.values[i * n + c] - scale * v[i - j - 1];
+                }
+            }
+            for matrix in [&mut h, &mut q] {
+                for r in 0..n {
+                    let dot = (j + 1..n).fold(T::zero(), |acc, c| {
+                        acc + matrix.values[r * n + c] * v[c - j - 1]
+                    });
+                    let scale = (dot + dot) / v_norm;
+                    for c in j + 1..n {
+                        matrix.values[r * n + c] = matrix.values[r * n + c] - scale * v[c - j - 1];
+                    }
+                }
+            }
+            for i in j + 2..n {
+                h.values[i * n + j] = T::zero();
+            }
+        }
+
+        Ok(Hessenberg { h, q })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        for (x, y) in a.values.iter().zip(&b.values) {
+            assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_hessenberg() {
+        let matrix: Matrix<f64> = Matrix::new(
+            4,
+            4,
+            vec![
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, 3.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+        );
+        let hessenberg = matrix.hessenberg().unwrap();
+        let (h, q) = (hessenberg.h(), hessenberg.q());
+
+        for i in 0..4 {
+            for j in 0..4 {
+                if i > j + 1 {
+                    assert_eq!(h.values[i * 4 + j], 0.0);
+                }
+            }
+        }
+        assert_close(
+            &q.mult_naive(h).unwrap().mult_naive(&q.transpose()).unwrap(),
+            &matrix,
+        );
+        assert_close(
+            &q.transpose().mult_naive(q).unwrap(),
+            &Matrix::identity(4).unwrap(),
+        );
+        assert!(Matrix::<f64>::new(1, 2, vec![0.0; 2]).hessenberg().is_err());
+    }
+}
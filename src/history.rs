@@ -0,0 +1,195 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+enum Undo<T> {
+    Swap(usize, usize),
+    Row(usize, Vec<T>),
+}
+
+/// A position in a [`History`] that can be rolled back to, for as long as
+/// the operations before it have not been undone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    len: usize,
+    generation: usize,
+}
+
+/// A matrix that journals row operations so they can be undone.
+///
+/// Only the rows an operation overwrites are saved (a swap saves nothing), so
+/// taking a checkpoint is free and rolling back costs as much as the edits
+/// made since, instead of a full copy of the matrix per step.
+///
+/// Each journal entry records the generation it was written in, and every
+/// rollback starts a new generation, so a checkpoint whose operations were
+/// undone and replaced by others is rejected rather than restored to a
+/// state it never described.
+pub struct History<T> {
+    matrix: Matrix<T>,
+    journal: Vec<(usize, Undo<T>)>,
+    generation: usize,
+}
+
+impl<T: Scalar> History<T> {
+    pub fn new(matrix: Matrix<T>) -> History<T> {
+        History {
+            matrix,
+            journal: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    pub fn into_matrix(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    fn save_row(&mut self, row: usize) {
+        let cols = self.matrix.cols;
+        let saved = self.matrix.values[row * cols..(row + 1) * cols].to_vec();
+        self.journal.push((self.generation, Undo::Row(row, saved)));
+    }
+
+    pub fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<&mut Self, MatrixError> {
+        self.matrix.swap_rows(row_a, row_b)?;
+        self.journal
+            .push((self.generation, Undo::Swap(row_a, row_b)));
+        Ok(self)
+    }
+
+    pub fn scale_row(&mut self, row: usize, factor: T) -> Result<&mut Self, MatrixError> {
+        self.matrix.check_row(row)?;
+        self.save_row(row);
+        self.matrix.scale_row(row, factor)?;
+        Ok(self)
+    }
+
+    pub fn add_scaled_row(
+        &mut self,
+        source: usize,
+        target: usize,
+        factor: T,
+    ) -> Result<&mut Self, MatrixError> {
+        self.matrix.check_row(source)?;
+        self.matrix.check_row(target)?;
+        self.save_row(target);
+        self.matrix.add_scaled_row(source, target, factor)?;
+        Ok(self)
+    }
+
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.journal.len(),
+            generation: self.generation,
+        }
+    }
+
+    pub fn restore(&mut self, checkpoint: Checkpoint) -> Result<&mut Self, MatrixError> {
+        if checkpoint.len > self.journal.len() {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Checkpoint {} is newer than the current history of {} operations",
+                checkpoint.len,
+                self.journal.len()
+            )));
+        }
+        // The last entry before the checkpoint was rewritten after it was
+        // taken, so the operations it covered are gone.
+        if checkpoint.len > 0 && self.journal[checkpoint.len - 1].0 > checkpoint.generation {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Checkpoint {} was invalidated by an earlier rollback",
+                checkpoint.len
+            )));
+        }
+
+        self.rewind(checkpoint.len)
+    }
+
+    fn rewind(&mut self, len: usize) -> Result<&mut Self, MatrixError> {
+        if self.journal.len() > len {
+            self.generation += 1;
+        }
+        let cols = self.matrix.cols;
+        while self.journal.len() > len {
+            match self.journal.pop().map(|(_, undo)| undo) {
+                Some(Undo::Swap(row_a, row_b)) => {
+                    self.matrix.swap_rows(row_a, row_b)?;
+                }
+                Some(Undo::Row(row, saved)) => {
+                    for (value, old) in self.matrix.values[row * cols..(row + 1) * cols]
+                        .iter_mut()
+                        .zip(saved)
+                    {
+                        *value = old;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        Ok(self)
+    }
+
+    pub fn undo(&mut self) -> Result<&mut Self, MatrixError> {
+        self.rewind(self.journal.len().saturating_sub(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::history::History;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_checkpoint_restore() {
+        let original: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        let mut history = History::new(original.clone());
+
+        let start = history.checkpoint();
+        history.swap_rows(0, 1).unwrap();
+        history.add_scaled_row(1, 0, -3).unwrap();
+        let middle = history.checkpoint();
+        history.scale_row(1, 5).unwrap();
+
+        assert_eq!(history.matrix(), &Matrix::new(2, 2, vec![0, -2, 5, 10]));
+
+        history.restore(middle).unwrap();
+        assert_eq!(history.matrix(), &Matrix::new(2, 2, vec![0, -2, 1, 2]));
+
+        history.undo().unwrap();
+        assert_eq!(history.matrix(), &Matrix::new(2, 2, vec![3, 4, 1, 2]));
+
+        history.restore(start).unwrap();
+        assert_eq!(history.matrix(), &original);
+        assert!(history.restore(middle).is_err());
+        assert!(history.scale_row(2, 1).is_err());
+        assert_eq!(history.checkpoint().len, start.len);
+    }
+
+    #[test]
+    fn check_stale_checkpoint() {
+        let mut history = History::new(Matrix::new(3, 1, vec![1, 2, 3]));
+        for _ in 0..2 {
+            history.scale_row(0, 2).unwrap();
+        }
+        let early = history.checkpoint();
+        for _ in 0..3 {
+            history.scale_row(1, 2).unwrap();
+        }
+        let late = history.checkpoint();
+
+        history.restore(early).unwrap();
+        for _ in 0..3 {
+            history.scale_row(2, 2).unwrap();
+        }
+        assert_eq!(history.matrix(), &Matrix::new(3, 1, vec![4, 2, 24]));
+        assert!(history.restore(late).is_err());
+        assert_eq!(history.matrix(), &Matrix::new(3, 1, vec![4, 2, 24]));
+
+        history.restore(early).unwrap();
+        assert_eq!(history.matrix(), &Matrix::new(3, 1, vec![4, 2, 3]));
+    }
+}
@@ -1,130 +1,24 @@
+/// Additive and multiplicative identities, the one piece of arithmetic the
+/// operator traits in `std::ops` do not provide.
 pub trait IdentityElement {
     fn zero() -> Self;
     fn one() -> Self;
 }
 
-impl IdentityElement for i8 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
+macro_rules! impl_identity_element {
+    ($zero:literal, $one:literal: $($t:ty),*) => {
+        $(
+            impl IdentityElement for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
 }
 
-impl IdentityElement for i16 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for i32 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for i64 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for i128 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for isize {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for u8 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for u16 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for u32 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for u64 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for u128 {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for usize {
-    fn zero() -> Self {
-        0
-    }
-    fn one() -> Self {
-        1
-    }
-}
-
-impl IdentityElement for f32 {
-    fn zero() -> Self {
-        0.0
-    }
-    fn one() -> Self {
-        1.0
-    }
-}
-
-impl IdentityElement for f64 {
-    fn zero() -> Self {
-        0.0
-    }
-    fn one() -> Self {
-        1.0
-    }
-}
+impl_identity_element!(0, 1: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_identity_element!(0.0, 1.0: f32, f64);
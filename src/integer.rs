@@ -0,0 +1,211 @@
+use crate::error::{MatrixError, Op};
+use crate::identity_element::IdentityElement;
+use crate::matrix::Matrix;
+use crate::semiring::Semiring;
+
+/// The wrapping and saturating operations of the primitive integer types.
+pub trait IntegerArithmetic: Sized {
+    fn wrapping_add(self, other: Self) -> Self;
+    fn wrapping_sub(self, other: Self) -> Self;
+    fn wrapping_mul(self, other: Self) -> Self;
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer_arithmetic {
+    ($($t:ty),*) => {
+        $(
+            impl IntegerArithmetic for $t {
+                fn wrapping_add(self, other: Self) -> Self {
+                    <$t>::wrapping_add(self, other)
+                }
+                fn wrapping_sub(self, other: Self) -> Self {
+                    <$t>::wrapping_sub(self, other)
+                }
+                fn wrapping_mul(self, other: Self) -> Self {
+                    <$t>::wrapping_mul(self, other)
+                }
+                fn saturating_add(self, other: Self) -> Self {
+                    <$t>::saturating_add(self, other)
+                }
+                fn saturating_sub(self, other: Self) -> Self {
+                    <$t>::saturating_sub(self, other)
+                }
+                fn saturating_mul(self, other: Self) -> Self {
+                    <$t>::saturating_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_arithmetic!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+struct WrappingOps;
+struct SaturatingOps;
+
+impl<T: IntegerArithmetic + IdentityElement + Clone> Semiring<T> for WrappingOps {
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::one()
+    }
+    fn add(a: T, b: T) -> T {
+        a.wrapping_add(b)
+    }
+    fn mul(a: &T, b: &T) -> T {
+        a.clone().wrapping_mul(b.clone())
+    }
+}
+
+impl<T: IntegerArithmetic + IdentityElement + Clone> Semiring<T> for SaturatingOps {
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::one()
+    }
+    fn add(a: T, b: T) -> T {
+        a.saturating_add(b)
+    }
+    fn mul(a: &T, b: &T) -> T {
+        a.clone().saturating_mul(b.clone())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: IntegerArithmetic + IdentityElement + Clone,
+{
+    fn zip_integer_mut(
+        &mut self,
+        matrix_b: &Matrix<T>,
+        op: Op,
+        f: impl Fn(T, T) -> T,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(matrix_b, op)?;
+        for (a, b) in self.values.iter_mut().zip(&matrix_b.values) {
+            *a = f(a.clone(), b.clone());
+        }
+        Ok(self)
+    }
+
+    pub fn wrapping_add(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let mut result = self.clone();
+        result.wrapping_add_mut(matrix_b)?;
+        Ok(result)
+    }
+
+    pub fn wrapping_add_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
+        self.zip_integer_mut(matrix_b, Op::Add, T::wrapping_add)
+    }
+
+    pub fn wrapping_sub(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let mut result = self.clone();
+        result.wrapping_sub_mut(matrix_b)?;
+        Ok(result)
+    }
+
+    pub fn wrapping_sub_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
+        self.zip_integer_mut(matrix_b, Op::Subtract, T::wrapping_sub)
+    }
+
+    pub fn wrapping_mul(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.mult_semiring::<WrappingOps>(matrix_b)
+    }
+
+    pub fn wrapping_mul_scalar(&mut self, num: T) -> &mut Self {
+        for value in &mut self.values {
+            *value = value.clone().wrapping_mul(num.clone());
+        }
+        self
+    }
+
+    pub fn saturating_add(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let mut result = self.clone();
+        result.saturating_add_mut(matrix_b)?;
+        Ok(result)
+    }
+
+    pub fn saturating_add_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
+        self.zip_integer_mut(matrix_b, Op::Add, T::saturating_add)
+    }
+
+    pub fn saturating_sub(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let mut result = self.clone();
+        result.saturating_sub_mut(matrix_b)?;
+        Ok(result)
+    }
+
+    pub fn saturating_sub_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
+        self.zip_integer_mut(matrix_b, Op::Subtract, T::saturating_sub)
+    }
+
+    /// Saturates after every product and every partial sum, in order of
+    /// increasing `k`. A dot product whose partial sums overshoot and come
+    /// back stays clamped, as it would in a saturating DSP accumulator.
+    pub fn saturating_mul(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.mult_semiring::<SaturatingOps>(matrix_b)
+    }
+
+    pub fn saturating_mul_scalar(&mut self, num: T) -> &mut Self {
+        for value in &mut self.values {
+            *value = value.clone().saturating_mul(num.clone());
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_wrapping() {
+        let matrix_a: Matrix<u8> = Matrix::new(1, 2, vec![250, 3]);
+        let matrix_b: Matrix<u8> = Matrix::new(1, 2, vec![10, 5]);
+
+        assert_eq!(
+            matrix_a.wrapping_add(&matrix_b).unwrap(),
+            Matrix::new(1, 2, vec![4, 8])
+        );
+        assert_eq!(
+            matrix_a.wrapping_sub(&matrix_b).unwrap(),
+            Matrix::new(1, 2, vec![240, 254])
+        );
+        assert_eq!(
+            matrix_a.wrapping_mul(&matrix_b.transpose()).unwrap(),
+            Matrix::new(1, 1, vec![211])
+        );
+
+        let mut scaled = matrix_a.clone();
+        scaled.wrapping_mul_scalar(2);
+        assert_eq!(scaled, Matrix::new(1, 2, vec![244, 6]));
+        assert!(matrix_a.wrapping_add(&matrix_b.transpose()).is_err());
+    }
+
+    #[test]
+    fn check_saturating() {
+        let matrix_a: Matrix<i8> = Matrix::new(1, 2, vec![120, -100]);
+        let matrix_b: Matrix<i8> = Matrix::new(1, 2, vec![10, 100]);
+
+        assert_eq!(
+            matrix_a.saturating_add(&matrix_b).unwrap(),
+            Matrix::new(1, 2, vec![127, 0])
+        );
+        assert_eq!(
+            matrix_a.saturating_sub(&matrix_b).unwrap(),
+            Matrix::new(1, 2, vec![110, -128])
+        );
+        assert_eq!(
+            matrix_a.saturating_mul(&matrix_b.transpose()).unwrap(),
+            Matrix::new(1, 1, vec![-1])
+        );
+
+        let mut scaled = matrix_a.clone();
+        scaled.saturating_mul_scalar(2);
+        assert_eq!(scaled, Matrix::new(1, 2, vec![127, -128]));
+    }
+}
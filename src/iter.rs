@@ -0,0 +1,111 @@
+use crate::error::{MatrixError, Op};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::borrow::Borrow;
+use std::iter::{Product, Sum};
+
+/// The element-wise sum of equally sized matrices, e.g.
+/// `let total: Result<Matrix<f64>, _> = gradients.iter().sum();`. An empty
+/// sequence has no shape to sum into, so it is an error like a mismatch.
+fn sum_matrices<T, M, I>(mut iter: I) -> Result<Matrix<T>, MatrixError>
+where
+    T: Scalar,
+    M: Borrow<Matrix<T>>,
+    I: Iterator<Item = M>,
+{
+    let mut total = match iter.next() {
+        Some(first) => first.borrow().clone(),
+        None => return Err(empty_sequence("sum")),
+    };
+    for matrix in iter {
+        let matrix = matrix.borrow();
+        total.check_same_shape(matrix, Op::Add)?;
+        for (sum, value) in total.values.iter_mut().zip(&matrix.values) {
+            *sum += value.clone();
+        }
+    }
+    Ok(total)
+}
+
+/// The matrix product of the sequence in order, `A_1 A_2 ... A_n`.
+fn product_of_matrices<T, M, I>(mut iter: I) -> Result<Matrix<T>, MatrixError>
+where
+    T: Scalar,
+    M: Borrow<Matrix<T>>,
+    I: Iterator<Item = M>,
+{
+    let mut total = match iter.next() {
+        Some(first) => first.borrow().clone(),
+        None => return Err(empty_sequence("multiply")),
+    };
+    for matrix in iter {
+        total = total.mult(matrix.borrow())?;
+    }
+    Ok(total)
+}
+
+fn empty_sequence(verb: &str) -> MatrixError {
+    MatrixError::InvalidArgument(format!("Cannot {} an empty sequence of matrices", verb))
+}
+
+impl<T: Scalar> Sum<Matrix<T>> for Result<Matrix<T>, MatrixError> {
+    fn sum<I: Iterator<Item = Matrix<T>>>(iter: I) -> Self {
+        sum_matrices(iter)
+    }
+}
+
+impl<'a, T: Scalar> Sum<&'a Matrix<T>> for Result<Matrix<T>, MatrixError> {
+    fn sum<I: Iterator<Item = &'a Matrix<T>>>(iter: I) -> Self {
+        sum_matrices(iter)
+    }
+}
+
+impl<T> Product<Matrix<T>> for Result<Matrix<T>, MatrixError>
+where
+    T: Scalar,
+{
+    fn product<I: Iterator<Item = Matrix<T>>>(iter: I) -> Self {
+        product_of_matrices(iter)
+    }
+}
+
+impl<'a, T> Product<&'a Matrix<T>> for Result<Matrix<T>, MatrixError>
+where
+    T: Scalar,
+{
+    fn product<I: Iterator<Item = &'a Matrix<T>>>(iter: I) -> Self {
+        product_of_matrices(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_sum_and_product() {
+        let matrices: Vec<Matrix<i32>> = (1..=3)
+            .map(|k| Matrix::new(2, 2, vec![k, 0, 0, k]))
+            .collect();
+
+        let total: Result<Matrix<i32>, MatrixError> = matrices.iter().sum();
+        assert_eq!(total.unwrap().values, vec![6, 0, 0, 6]);
+        let product: Result<Matrix<i32>, MatrixError> = matrices.into_iter().product();
+        assert_eq!(product.unwrap().values, vec![6, 0, 0, 6]);
+
+        let chain = [Matrix::new(1, 2, vec![1, 2]), Matrix::new(2, 1, vec![3, 4])];
+        let dot: Result<Matrix<i32>, MatrixError> = chain.iter().product();
+        assert_eq!(dot.unwrap().values, vec![11]);
+    }
+
+    #[test]
+    fn check_sum_errors() {
+        let mismatched = [Matrix::new(1, 2, vec![1, 2]), Matrix::new(2, 1, vec![3, 4])];
+        assert!(mismatched.iter().sum::<Result<Matrix<i32>, _>>().is_err());
+        assert!(Vec::<Matrix<f64>>::new()
+            .into_iter()
+            .sum::<Result<Matrix<f64>, _>>()
+            .is_err());
+    }
+}
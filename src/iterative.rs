@@ -0,0 +1,243 @@
+use crate::error::{MatrixError, Op};
+use crate::float::FloatEnv;
+use crate::matrix::Matrix;
+use std::io::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Preconditioner {
+    Identity,
+    Jacobi,
+}
+
+/// Diagnostics from an iterative solve. `residual_history[k]` is the
+/// Euclidean norm of `b - A x` after `k` iterations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveReport {
+    pub iterations: usize,
+    pub converged: bool,
+    pub residual_history: Vec<f64>,
+}
+
+impl SolveReport {
+    /// `Ok` if the solve converged, otherwise
+    /// [`MatrixError::NonConvergent`] with the final residual, for callers
+    /// that treat a stalled solve as a failure.
+    pub fn check(&self) -> Result<(), MatrixError> {
+        if self.converged {
+            return Ok(());
+        }
+        Err(MatrixError::NonConvergent {
+            iterations: self.iterations,
+            residual: self.residual_history.last().copied().unwrap_or(f64::NAN),
+        })
+    }
+
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+        writeln!(writer, "iteration,residual")?;
+        for (iteration, residual) in self.residual_history.iter().enumerate() {
+            writeln!(writer, "{},{:e}", iteration, residual)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a self-contained gnuplot script plotting the residual history
+    /// of every report on a log scale, one line per label.
+    pub fn write_gnuplot<W: Write>(
+        reports: &[(&str, &SolveReport)],
+        mut writer: W,
+    ) -> Result<(), MatrixError> {
+        writeln!(writer, "set logscale y")?;
+        writeln!(writer, "set xlabel \"iteration\"")?;
+        writeln!(writer, "set ylabel \"residual norm\"")?;
+
+        for (index, (_, report)) in reports.iter().enumerate() {
+            writeln!(writer, "$run{} << EOD", index)?;
+            for (iteration, residual) in report.residual_history.iter().enumerate() {
+                writeln!(writer, "{} {:e}", iteration, residual)?;
+            }
+            writeln!(writer, "EOD")?;
+        }
+
+        let plots: Vec<String> = reports
+            .iter()
+            .enumerate()
+            .map(|(index, (label, _))| {
+                format!(
+                    "$run{} using 1:2 with lines title \"{}\"",
+                    index,
+                    label.replace('"', "'")
+                )
+            })
+            .collect();
+        writeln!(writer, "plot {}", plots.join(", "))?;
+        Ok(())
+    }
+}
+
+impl Matrix<f64> {
+    /// Solves `A x = b` for symmetric positive-definite `A` with the
+    /// (preconditioned) conjugate gradient method, starting from `x = 0`.
+    /// Honors the `fused_multiply_add` and `flush_denormals` settings of the
+    /// current [`Config`](crate::config::Config).
+    pub fn solve_cg(
+        &self,
+        b: &Matrix<f64>,
+        preconditioner: Preconditioner,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<(Matrix<f64>, SolveReport), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+        if b.rows != self.rows || b.cols != 1 {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (self.rows, self.cols),
+                rhs: (b.rows, b.cols),
+            });
+        }
+
+        let env = FloatEnv::current();
+        let dot = |a: &[f64], b: &[f64]| env.dot(a, b);
+        let n = self.rows;
+        let inverse_diagonal: Vec<f64> = (0..n)
+            .map(|i| match preconditioner {
+                Preconditioner::Identity => 1.0,
+                Preconditioner::Jacobi => {
+                    let d = self.values[i * n + i];
+                    if d != 0.0 {
+                        1.0 / d
+                    } else {
+                        1.0
+                    }
+                }
+            })
+            .collect();
+        let apply_a = |v: &[f64]| -> Vec<f64> {
+            (0..n)
+                .map(|i| dot(&self.values[i * n..(i + 1) * n], v))
+                .collect()
+        };
+
+        let mut x = vec![0.0; n];
+        let mut r = b.values.clone();
+        let mut z: Vec<f64> = r
+            .iter()
+            .zip(&inverse_diagonal)
+            .map(|(r, m)| r * m)
+            .collect();
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+
+        let mut report = SolveReport {
+            iterations: 0,
+            converged: false,
+            residual_history: vec![dot(&r, &r).sqrt()],
+        };
+
+        while report.iterations < max_iterations {
+            if report.residual_history[report.iterations] <= tolerance {
+                report.converged = true;
+                break;
+            }
+
+            let ap = apply_a(&p);
+            let alpha = rz / dot(&p, &ap);
+            for i in 0..n {
+                x[i] = env.mul_add(alpha, p[i], x[i]);
+                r[i] = env.mul_add(-alpha, ap[i], r[i]);
+            }
+
+            z = r
+                .iter()
+                .zip(&inverse_diagonal)
+                .map(|(r, m)| env.flush(r * m))
+                .collect();
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            rz = rz_next;
+            for i in 0..n {
+                p[i] = env.mul_add(beta, p[i], z[i]);
+            }
+
+            report.iterations += 1;
+            report.residual_history.push(dot(&r, &r).sqrt());
+        }
+
+        report.converged |= report.residual_history[report.iterations] <= tolerance;
+        Ok((Matrix::new(n, 1, x), report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{self, Config};
+    use crate::error::MatrixError;
+    use crate::iterative::{Preconditioner, SolveReport};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_solve_cg() {
+        let a: Matrix<f64> = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let b: Matrix<f64> = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+
+        for preconditioner in [Preconditioner::Identity, Preconditioner::Jacobi] {
+            let (x, report) = a.solve_cg(&b, preconditioner, 1e-12, 10).unwrap();
+            let residual = a.mult_naive(&x).unwrap().subtract(&b).unwrap();
+
+            assert!(report.converged);
+            assert!(report.iterations <= 3);
+            assert_eq!(report.residual_history.len(), report.iterations + 1);
+            assert!(residual.get_values().iter().all(|r| r.abs() < 1e-10));
+        }
+
+        let tuned = Config {
+            fused_multiply_add: true,
+            flush_denormals: true,
+            ..Config::default()
+        };
+        let (x, report) =
+            config::with_config(tuned, || a.solve_cg(&b, Preconditioner::Jacobi, 1e-12, 10))
+                .unwrap()
+                .unwrap();
+        assert!(report.converged);
+        let residual = a.mult_naive(&x).unwrap().subtract(&b).unwrap();
+        assert!(residual.get_values().iter().all(|r| r.abs() < 1e-10));
+
+        let (_, report) = a.solve_cg(&b, Preconditioner::Identity, 1e-12, 1).unwrap();
+        assert!(!report.converged);
+        assert!(matches!(
+            report.check(),
+            Err(MatrixError::NonConvergent { iterations: 1, residual }) if residual > 1e-12
+        ));
+        assert!(a.solve_cg(&a, Preconditioner::Identity, 1e-12, 1).is_err());
+        assert!(matches!(
+            Matrix::new(1, 2, vec![1.0, 0.0]).solve_cg(&b, Preconditioner::Identity, 1e-12, 1),
+            Err(MatrixError::NotSquare { dims: (1, 2) })
+        ));
+    }
+
+    #[test]
+    fn check_report_export() {
+        let report = SolveReport {
+            iterations: 1,
+            converged: true,
+            residual_history: vec![1.0, 0.5],
+        };
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "iteration,residual\n0,1e0\n1,5e-1\n"
+        );
+
+        let mut script = Vec::new();
+        SolveReport::write_gnuplot(&[("jacobi", &report)], &mut script).unwrap();
+        let script = String::from_utf8(script).unwrap();
+        assert!(script.contains("$run0 << EOD\n0 1e0\n1 5e-1\nEOD\n"));
+        assert!(script.ends_with("plot $run0 using 1:2 with lines title \"jacobi\"\n"));
+    }
+}
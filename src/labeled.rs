@@ -0,0 +1,239 @@
+use crate::error::{Axis, MatrixError};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::collections::HashSet;
+
+/// A matrix whose rows and columns carry unique names.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabeledMatrix<T> {
+    matrix: Matrix<T>,
+    row_labels: Vec<String>,
+    col_labels: Vec<String>,
+}
+
+fn check_labels(labels: &[String], expected: usize, axis: &str) -> Result<(), MatrixError> {
+    if labels.len() != expected {
+        return Err(MatrixError::LengthMismatch {
+            expected,
+            actual: labels.len(),
+        });
+    }
+
+    let mut seen = HashSet::new();
+    for label in labels {
+        if !seen.insert(label.as_str()) {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Duplicate {} label \"{}\"",
+                axis, label
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn find(labels: &[String], label: &str, axis: Axis) -> Result<usize, MatrixError> {
+    labels
+        .iter()
+        .position(|l| l == label)
+        .ok_or_else(|| MatrixError::UnknownLabel {
+            axis,
+            label: label.to_string(),
+        })
+}
+
+impl<T: Scalar> LabeledMatrix<T> {
+    pub fn new(
+        matrix: Matrix<T>,
+        row_labels: Vec<String>,
+        col_labels: Vec<String>,
+    ) -> Result<LabeledMatrix<T>, MatrixError> {
+        check_labels(&row_labels, matrix.rows, "rows")?;
+        check_labels(&col_labels, matrix.cols, "columns")?;
+
+        Ok(LabeledMatrix {
+            matrix,
+            row_labels,
+            col_labels,
+        })
+    }
+
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    pub fn row_labels(&self) -> &[String] {
+        &self.row_labels
+    }
+
+    pub fn col_labels(&self) -> &[String] {
+        &self.col_labels
+    }
+
+    pub fn into_parts(self) -> (Matrix<T>, Vec<String>, Vec<String>) {
+        (self.matrix, self.row_labels, self.col_labels)
+    }
+
+    pub fn row_index(&self, label: &str) -> Result<usize, MatrixError> {
+        find(&self.row_labels, label, Axis::Rows)
+    }
+
+    pub fn col_index(&self, label: &str) -> Result<usize, MatrixError> {
+        find(&self.col_labels, label, Axis::Cols)
+    }
+
+    pub fn value_by_label(&self, row: &str, col: &str) -> Result<&T, MatrixError> {
+        self.matrix
+            .value_at(self.row_index(row)?, self.col_index(col)?)
+    }
+
+    pub fn transpose(&self) -> LabeledMatrix<T> {
+        LabeledMatrix {
+            matrix: self.matrix.transpose(),
+            row_labels: self.col_labels.clone(),
+            col_labels: self.row_labels.clone(),
+        }
+    }
+
+    pub fn select_rows(&self, labels: &[&str]) -> Result<LabeledMatrix<T>, MatrixError> {
+        let indices = labels
+            .iter()
+            .map(|label| self.row_index(label))
+            .collect::<Result<Vec<usize>, MatrixError>>()?;
+
+        LabeledMatrix::new(
+            self.matrix.select_rows(&indices)?,
+            labels.iter().map(|l| l.to_string()).collect(),
+            self.col_labels.clone(),
+        )
+    }
+
+    pub fn select_cols(&self, labels: &[&str]) -> Result<LabeledMatrix<T>, MatrixError> {
+        let indices = labels
+            .iter()
+            .map(|label| self.col_index(label))
+            .collect::<Result<Vec<usize>, MatrixError>>()?;
+
+        LabeledMatrix::new(
+            self.matrix.select_cols(&indices)?,
+            self.row_labels.clone(),
+            labels.iter().map(|l| l.to_string()).collect(),
+        )
+    }
+
+    /// Places `matrix_b`'s columns after this matrix's. Both must have the
+    /// same row labels in the same order, and the column labels must not
+    /// collide.
+    pub fn hstack(&self, matrix_b: &LabeledMatrix<T>) -> Result<LabeledMatrix<T>, MatrixError> {
+        if self.row_labels != matrix_b.row_labels {
+            return Err(MatrixError::InvalidArgument(
+                "Cannot horizontally stack labelled matrices with different row labels".to_string(),
+            ));
+        }
+
+        let mut col_labels = self.col_labels.clone();
+        col_labels.extend(matrix_b.col_labels.iter().cloned());
+        LabeledMatrix::new(
+            self.matrix.hstack(&matrix_b.matrix)?,
+            self.row_labels.clone(),
+            col_labels,
+        )
+    }
+
+    /// Places `matrix_b`'s rows after this matrix's. Both must have the same
+    /// column labels in the same order, and the row labels must not collide.
+    pub fn vstack(&self, matrix_b: &LabeledMatrix<T>) -> Result<LabeledMatrix<T>, MatrixError> {
+        if self.col_labels != matrix_b.col_labels {
+            return Err(MatrixError::InvalidArgument(
+                "Cannot vertically stack labelled matrices with different column labels"
+                    .to_string(),
+            ));
+        }
+
+        let mut row_labels = self.row_labels.clone();
+        row_labels.extend(matrix_b.row_labels.iter().cloned());
+        LabeledMatrix::new(
+            self.matrix.vstack(&matrix_b.matrix)?,
+            row_labels,
+            self.col_labels.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{Axis, MatrixError};
+    use crate::labeled::LabeledMatrix;
+    use crate::matrix::Matrix;
+
+    fn labels(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn check_labeled_lookup_and_transpose() {
+        let covariance = LabeledMatrix::new(
+            Matrix::new(2, 3, vec![1.0, 0.5, 0.1, 0.5, 2.0, 0.3]),
+            labels(&["height", "weight"]),
+            labels(&["height", "weight", "age"]),
+        )
+        .unwrap();
+
+        assert_eq!(covariance.value_by_label("weight", "age").unwrap(), &0.3);
+        assert!(matches!(
+            covariance.value_by_label("age", "age"),
+            Err(MatrixError::UnknownLabel {
+                axis: Axis::Rows,
+                ..
+            })
+        ));
+
+        let transposed = covariance.transpose();
+        assert_eq!(transposed.value_by_label("age", "weight").unwrap(), &0.3);
+        assert_eq!(
+            transposed.row_labels(),
+            &labels(&["height", "weight", "age"])[..]
+        );
+
+        assert!(LabeledMatrix::new(
+            Matrix::new(1, 2, vec![1, 2]),
+            labels(&["a"]),
+            labels(&["b", "b"]),
+        )
+        .is_err());
+        assert!(
+            LabeledMatrix::new(Matrix::new(1, 1, vec![1]), labels(&[]), labels(&["b"])).is_err()
+        );
+    }
+
+    #[test]
+    fn check_labeled_selection_and_stacking() {
+        let matrix = LabeledMatrix::new(
+            Matrix::new(2, 2, vec![1, 2, 3, 4]),
+            labels(&["r1", "r2"]),
+            labels(&["c1", "c2"]),
+        )
+        .unwrap();
+
+        let selected = matrix
+            .select_rows(&["r2"])
+            .unwrap()
+            .select_cols(&["c2", "c1"])
+            .unwrap();
+        assert_eq!(selected.matrix(), &Matrix::new(1, 2, vec![4, 3]));
+        assert_eq!(selected.col_labels(), &labels(&["c2", "c1"])[..]);
+
+        let extra = LabeledMatrix::new(
+            Matrix::new(2, 1, vec![5, 6]),
+            labels(&["r1", "r2"]),
+            labels(&["c3"]),
+        )
+        .unwrap();
+        let wide = matrix.hstack(&extra).unwrap();
+        assert_eq!(wide.value_by_label("r2", "c3").unwrap(), &6);
+        assert!(matrix.hstack(&matrix).is_err());
+        assert!(matrix.vstack(&extra.transpose()).is_err());
+        assert!(matrix
+            .vstack(&matrix.select_rows(&["r1"]).unwrap())
+            .is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use crate::error::{elementwise_mismatch, product_mismatch, MatrixError, Op};
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::Scalar;
+use crate::view::MatrixView;
+
+/// The order in which a flat buffer stores a matrix's elements. `Matrix`
+/// itself is always row-major; column-major is the Fortran and LAPACK
+/// convention, kept without copying by [`ColumnMajorMatrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    RowMajor,
+    ColumnMajor,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Views a borrowed buffer in either layout without copying. A
+    /// column-major buffer becomes a view with a column stride of `rows`.
+    pub fn from_slice_with_layout(
+        values: &'a [T],
+        rows: usize,
+        cols: usize,
+        layout: Layout,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        match layout {
+            Layout::RowMajor => MatrixView::from_slice(values, rows, cols),
+            Layout::ColumnMajor => Ok(MatrixView::from_slice(values, cols, rows)?.transpose()),
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Copies a buffer in either layout into a row-major matrix.
+    pub fn from_layout(
+        rows: usize,
+        cols: usize,
+        values: Vec<T>,
+        layout: Layout,
+    ) -> Result<Matrix<T>, MatrixError> {
+        match layout {
+            Layout::RowMajor => Matrix::try_new(rows, cols, values),
+            Layout::ColumnMajor => {
+                let view = MatrixView::from_slice_with_layout(&values, rows, cols, layout)?;
+                Ok(Matrix::new(rows, cols, view.iter().cloned().collect()))
+            }
+        }
+    }
+
+    /// The elements flattened in `layout` order, ready to hand to a library
+    /// expecting that convention.
+    pub fn to_layout(&self, layout: Layout) -> Vec<T> {
+        match layout {
+            Layout::RowMajor => self.values.clone(),
+            Layout::ColumnMajor => {
+                let mut new_values = Vec::with_capacity(self.values.len());
+                for j in 0..self.cols {
+                    for i in 0..self.rows {
+                        new_values.push(self.values[i * self.cols + j].clone());
+                    }
+                }
+                new_values
+            }
+        }
+    }
+
+    /// Overwrites `out`, which must hold exactly `rows * cols` elements,
+    /// with the elements in `layout` order, so repeated FFI calls can reuse
+    /// one buffer.
+    pub fn write_layout(&self, out: &mut [T], layout: Layout) -> Result<(), MatrixError> {
+        let len = checked_len(self.rows, self.cols)?;
+        if out.len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: out.len(),
+            });
+        }
+        match layout {
+            Layout::RowMajor => out.clone_from_slice(&self.values),
+            Layout::ColumnMajor => {
+                for i in 0..self.rows {
+                    for j in 0..self.cols {
+                        out[j * self.rows + i] = self.values[i * self.cols + j].clone();
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A matrix that owns a column-major buffer.
+///
+/// A column-major `rows`x`cols` buffer is, element for element, the
+/// row-major buffer of the `cols`x`rows` transpose, so the matrix is kept as
+/// that transpose and the row-major kernels run on the buffer directly:
+/// `A + B` is `(A^T + B^T)^T` and `A B` is `(B^T A^T)^T`. Nothing is copied
+/// on the way in from, or out to, a Fortran-convention library.
+///
+/// nalgebra's `DMatrix` uses the same layout. The crate has no `nalgebra`
+/// feature, but a matrix crosses over without a copy as
+/// `DMatrix::from_vec(rows, cols, matrix.into_vec())` and comes back as
+/// `ColumnMajorMatrix::new(rows, cols, dmatrix.data.into())`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnMajorMatrix<T> {
+    transposed: Matrix<T>,
+}
+
+impl<T> ColumnMajorMatrix<T> {
+    /// Takes `values` as the columns of a `rows`x`cols` matrix, one after
+    /// the other.
+    pub fn new(
+        rows: usize,
+        cols: usize,
+        values: Vec<T>,
+    ) -> Result<ColumnMajorMatrix<T>, MatrixError> {
+        Ok(ColumnMajorMatrix {
+            transposed: Matrix::try_new(cols, rows, values)?,
+        })
+    }
+
+    /// Reinterprets a row-major matrix as the column-major buffer of its
+    /// transpose.
+    pub fn from_transpose(matrix: Matrix<T>) -> ColumnMajorMatrix<T> {
+        ColumnMajorMatrix { transposed: matrix }
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.transposed.cols
+    }
+
+    pub fn get_cols(&self) -> usize {
+        self.transposed.rows
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        let rows = self.get_rows();
+        if row >= rows || col >= self.get_cols() {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (rows, self.get_cols()),
+            });
+        }
+        Ok(&self.transposed.values[col * rows + row])
+    }
+
+    /// The elements in column-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.transposed.values
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.transposed.values
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.transposed.values
+    }
+
+    /// The transpose as a row-major matrix, sharing the buffer.
+    pub fn into_transpose(self) -> Matrix<T> {
+        self.transposed
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        self.transposed
+            .row_block(0, self.transposed.rows)
+            .transpose()
+    }
+}
+
+impl<T: Scalar> ColumnMajorMatrix<T> {
+    /// Reports a mismatch in the caller's `rows`x`cols` terms rather than
+    /// those of the stored transposes.
+    fn check_same_shape(&self, matrix_b: &ColumnMajorMatrix<T>, op: Op) -> Result<(), MatrixError> {
+        let (lhs, rhs) = (
+            (self.get_rows(), self.get_cols()),
+            (matrix_b.get_rows(), matrix_b.get_cols()),
+        );
+        if lhs != rhs {
+            return Err(elementwise_mismatch(op, lhs, rhs));
+        }
+        Ok(())
+    }
+
+    pub fn add(
+        &self,
+        matrix_b: &ColumnMajorMatrix<T>,
+    ) -> Result<ColumnMajorMatrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+        Ok(ColumnMajorMatrix::from_transpose(
+            self.transposed.add(&matrix_b.transposed)?,
+        ))
+    }
+
+    pub fn subtract(
+        &self,
+        matrix_b: &ColumnMajorMatrix<T>,
+    ) -> Result<ColumnMajorMatrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Subtract)?;
+        Ok(ColumnMajorMatrix::from_transpose(
+            self.transposed.subtract(&matrix_b.transposed)?,
+        ))
+    }
+
+    /// The matrix in row-major order. This is the one conversion that
+    /// copies.
+    pub fn to_row_major(&self) -> Matrix<T> {
+        self.transposed.transpose()
+    }
+}
+
+impl<T: Scalar> ColumnMajorMatrix<T> {
+    pub fn mult(
+        &self,
+        matrix_b: &ColumnMajorMatrix<T>,
+    ) -> Result<ColumnMajorMatrix<T>, MatrixError> {
+        if self.get_cols() != matrix_b.get_rows() {
+            return Err(product_mismatch(
+                (self.get_rows(), self.get_cols()),
+                (matrix_b.get_rows(), matrix_b.get_cols()),
+            ));
+        }
+        Ok(ColumnMajorMatrix::from_transpose(
+            matrix_b.transposed.mult(&self.transposed)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{MatrixError, Op};
+    use crate::layout::{ColumnMajorMatrix, Layout};
+    use crate::matrix::Matrix;
+    use crate::view::MatrixView;
+
+    #[test]
+    fn check_column_major() {
+        let fortran = [1, 4, 2, 5, 3, 6];
+        let expected: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        let view = MatrixView::from_slice_with_layout(&fortran, 2, 3, Layout::ColumnMajor).unwrap();
+        assert_eq!(view.value_at(0, 2).unwrap(), &3);
+        assert_eq!(view.to_matrix(), expected);
+
+        let matrix = Matrix::from_layout(2, 3, fortran.to_vec(), Layout::ColumnMajor).unwrap();
+        assert_eq!(matrix, expected);
+        assert_eq!(matrix.to_layout(Layout::ColumnMajor), fortran);
+        assert_eq!(matrix.to_layout(Layout::RowMajor), expected.values);
+
+        let mut buffer = [0; 6];
+        matrix
+            .write_layout(&mut buffer, Layout::ColumnMajor)
+            .unwrap();
+        assert_eq!(buffer, fortran);
+        assert!(matrix
+            .write_layout(&mut buffer[..4], Layout::RowMajor)
+            .is_err());
+        assert!(Matrix::from_layout(4, 2, fortran.to_vec(), Layout::ColumnMajor).is_err());
+    }
+
+    #[test]
+    fn check_column_major_matrix() {
+        let a = ColumnMajorMatrix::new(2, 3, vec![1, 4, 2, 5, 3, 6]).unwrap();
+        let b = ColumnMajorMatrix::new(3, 2, vec![1, 0, 2, 0, 1, 1]).unwrap();
+        let (row_a, row_b) = (a.to_row_major(), b.to_row_major());
+        assert_eq!(row_a, Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!((a.get_rows(), a.get_cols()), (2, 3));
+        assert_eq!(a.value_at(1, 2).unwrap(), &6);
+        assert_eq!(a.as_view().to_matrix(), row_a);
+
+        let product = a.mult(&b).unwrap();
+        assert_eq!(product.to_row_major(), row_a.mult(&row_b).unwrap());
+        assert_eq!(product.as_slice(), &[7, 16, 5, 11]);
+
+        let sum = a.add(&a).unwrap().subtract(&a).unwrap();
+        assert_eq!(sum, a);
+        assert!(matches!(
+            a.add(&b),
+            Err(MatrixError::DimensionMismatch {
+                op: Op::Add,
+                lhs: (2, 3),
+                rhs: (3, 2),
+            })
+        ));
+        assert!(a.mult(&a).is_err());
+        assert!(matches!(
+            a.value_at(2, 0),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (2, 0),
+                dims: (2, 3),
+            })
+        ));
+        assert_eq!(a.into_transpose(), row_a.transpose());
+    }
+}
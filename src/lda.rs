@@ -0,0 +1,122 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::{checked_len, Matrix};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+impl<T: Float> Matrix<T> {
+    /// Fisher's linear discriminant analysis. Each row of `self` is a sample
+    /// with class `labels[row]`. Returns the `n`x`components` projection whose
+    /// columns solve `S_b v = lambda S_w v` for the largest `lambda`, where
+    /// `S_b` and `S_w` are the between- and within-class scatter matrices.
+    /// `S_w` must be positive definite, which needs more samples than
+    /// features and no feature constant within every class.
+    pub fn lda<L: Eq + Hash>(
+        &self,
+        labels: &[L],
+        components: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let (m, n) = (self.rows, self.cols);
+        if labels.len() != m {
+            return Err(MatrixError::LengthMismatch {
+                expected: m,
+                actual: labels.len(),
+            });
+        }
+
+        let mut classes: HashMap<&L, usize> = HashMap::new();
+        let mut class_of = Vec::with_capacity(m);
+        for label in labels {
+            let next = classes.len();
+            class_of.push(*classes.entry(label).or_insert(next));
+        }
+        let class_count = classes.len();
+        if components == 0 || components >= class_count || components > n {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Cannot extract {} discriminants from {} classes and {} features",
+                components, class_count, n
+            )));
+        }
+
+        let mut sizes = vec![0usize; class_count];
+        let mut means = vec![T::zero(); class_count * n];
+        let mut overall = vec![T::zero(); n];
+        for (row, &class) in self.values.chunks(n).zip(&class_of) {
+            sizes[class] += 1;
+            for (j, &value) in row.iter().enumerate() {
+                means[class * n + j] += value;
+                overall[j] += value;
+            }
+        }
+        for (class, &size) in sizes.iter().enumerate() {
+            let size = T::from_f64(size as f64);
+            for mean in &mut means[class * n..(class + 1) * n] {
+                *mean = *mean / size;
+            }
+        }
+        let total = T::from_f64(m as f64);
+        for mean in overall.iter_mut() {
+            *mean = *mean / total;
+        }
+
+        let mut within = Matrix::new(n, n, vec![T::zero(); checked_len(n, n)?]);
+        for (row, &class) in self.values.chunks(n).zip(&class_of) {
+            let mean = &means[class * n..(class + 1) * n];
+            for i in 0..n {
+                for j in 0..n {
+                    within.values[i * n + j] += (row[i] - mean[i]) * (row[j] - mean[j]);
+                }
+            }
+        }
+
+        let mut between = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for (class, &size) in sizes.iter().enumerate() {
+            let size = T::from_f64(size as f64);
+            let mean = &means[class * n..(class + 1) * n];
+            for i in 0..n {
+                for j in 0..n {
+                    between.values[i * n + j] +=
+                        size * (mean[i] - overall[i]) * (mean[j] - overall[j]);
+                }
+            }
+        }
+
+        let eigen = between.generalized_symmetric_eigen(&within)?;
+        let projection = (0..n)
+            .flat_map(|i| eigen.vectors.values[i * n..i * n + components].to_vec())
+            .collect();
+        Ok(Matrix::new(n, components, projection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_lda() {
+        let samples: Matrix<f64> = Matrix::new(
+            6,
+            2,
+            vec![
+                0.0, 0.0, 0.2, 3.0, -0.1, -3.0, 4.0, 0.5, 3.9, 3.5, 4.1, -2.5,
+            ],
+        );
+        let labels = ["a", "a", "a", "b", "b", "b"];
+        let projection = samples.lda(&labels, 1).unwrap();
+
+        assert_eq!((projection.get_rows(), projection.get_cols()), (2, 1));
+        assert!(projection.values[0].abs() > 10.0 * projection.values[1].abs());
+
+        let projected = samples.mult_naive(&projection).unwrap();
+        let (a, b) = projected.values.split_at(3);
+        let gap = (a.iter().sum::<f64>() - b.iter().sum::<f64>()).abs() / 3.0;
+        assert!(a
+            .iter()
+            .chain(b)
+            .all(|v| (v - a[0]).abs() < 0.5 * gap || (v - b[0]).abs() < 0.5 * gap));
+
+        assert!(samples.lda(&labels, 2).is_err());
+        assert!(samples.lda(&labels[..5], 1).is_err());
+    }
+}
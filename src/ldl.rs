@@ -0,0 +1,330 @@
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// A symmetric indefinite factorization `P A P^T = L D L^T` with
+/// Bunch-Kaufman pivoting.
+///
+/// `L` is unit lower triangular and `D` block diagonal with 1x1 and 2x2
+/// blocks, so saddle-point (KKT) systems that defeat Cholesky factor
+/// stably. `permutation[i]` is the row and column of `A` that ended up in
+/// position `i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ldl<T> {
+    l: Matrix<T>,
+    diagonal: Vec<T>,
+    /// `D[k + 1][k]`, non-zero exactly where a 2x2 block starts at `k`.
+    off_diagonal: Vec<T>,
+    permutation: Vec<usize>,
+}
+
+/// Swaps index `a` with `b` in the symmetric working matrix and in the
+/// finished columns `0..k` of `l`.
+fn symmetric_swap<T: Float>(work: &mut Matrix<T>, l: &mut Matrix<T>, k: usize, a: usize, b: usize) {
+    if a == b {
+        return;
+    }
+    let n = work.rows;
+    for j in 0..n {
+        work.values.swap(a * n + j, b * n + j);
+    }
+    for i in 0..n {
+        work.values.swap(i * n + a, i * n + b);
+    }
+    for j in 0..k {
+        l.values.swap(a * n + j, b * n + j);
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Factors a symmetric matrix, reading only its lower triangle.
+    pub fn ldl(&self) -> Result<Ldl<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+
+        let n = self.rows;
+        let mut work = Matrix::from_fn(n, n, |i, j| self.values[i.max(j) * n + i.min(j)]);
+        let mut l: Matrix<T> = Matrix::identity_within(n);
+        let mut diagonal = vec![T::zero(); n];
+        let mut off_diagonal = vec![T::zero(); n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let alpha = T::from_f64((1.0 + 17.0f64.sqrt()) / 8.0);
+
+        let mut k = 0;
+        while k < n {
+            let at = |work: &Matrix<T>, i: usize, j: usize| work.values[i * n + j];
+            let absakk = at(&work, k, k).abs();
+            let (r, colmax) = (k + 1..n).fold((k, T::zero()), |(r, max), i| {
+                let value = at(&work, i, k).abs();
+                if value > max {
+                    (i, value)
+                } else {
+                    (r, max)
+                }
+            });
+            if !(absakk.to_f64().is_finite() && colmax.to_f64().is_finite()) {
+                return Err(MatrixError::InvalidArgument(format!(
+                    "Non-finite pivot candidate in column {}",
+                    k
+                )));
+            }
+            if absakk == T::zero() && colmax == T::zero() {
+                return Err(MatrixError::Singular { column: k });
+            }
+
+            // The last column has nothing to pair with.
+            let two_by_two = if k + 1 == n || absakk >= alpha * colmax {
+                false
+            } else {
+                let rowmax = (k..n)
+                    .filter(|&j| j != r)
+                    .map(|j| at(&work, r, j).abs())
+                    .fold(
+                        T::zero(),
+                        |max, value| if value > max { value } else { max },
+                    );
+                if absakk * rowmax >= alpha * colmax * colmax {
+                    false
+                } else if at(&work, r, r).abs() >= alpha * rowmax {
+                    symmetric_swap(&mut work, &mut l, k, k, r);
+                    permutation.swap(k, r);
+                    false
+                } else {
+                    symmetric_swap(&mut work, &mut l, k, k + 1, r);
+                    permutation.swap(k + 1, r);
+                    true
+                }
+            };
+
+            if two_by_two {
+                let (a, b, c) = (
+                    at(&work, k, k),
+                    at(&work, k + 1, k),
+                    at(&work, k + 1, k + 1),
+                );
+                let det = a * c - b * b;
+                diagonal[k] = a;
+                diagonal[k + 1] = c;
+                off_diagonal[k] = b;
+                for i in k + 2..n {
+                    let (x, y) = (at(&work, i, k), at(&work, i, k + 1));
+                    l.values[i * n + k] = (c * x - b * y) / det;
+                    l.values[i * n + k + 1] = (a * y - b * x) / det;
+                }
+                for i in k + 2..n {
+                    for j in k + 2..n {
+                        let update = l.values[i * n + k] * at(&work, j, k)
+                            + l.values[i * n + k + 1] * at(&work, j, k + 1);
+                        work.values[i * n + j] = work.values[i * n + j] - update;
+                    }
+                }
+                k += 2;
+            } else {
+                let d = at(&work, k, k);
+                diagonal[k] = d;
+                for i in k + 1..n {
+                    l.values[i * n + k] = at(&work, i, k) / d;
+                }
+                for i in k + 1..n {
+                    for j in k + 1..n {
+                        let update = l.values[i * n + k] * at(&work, j, k);
+                        work.values[i * n + j] = work.values[i * n + j] - update;
+                    }
+                }
+                k += 1;
+            }
+        }
+
+        Ok(Ldl {
+            l,
+            diagonal,
+            off_diagonal,
+            permutation,
+        })
+    }
+}
+
+impl<T: Float> Ldl<T> {
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// The block diagonal `D` as a full matrix.
+    pub fn d(&self) -> Matrix<T> {
+        let n = self.diagonal.len();
+        Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                self.diagonal[i]
+            } else if i == j + 1 {
+                self.off_diagonal[j]
+            } else if j == i + 1 {
+                self.off_diagonal[i]
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// How many eigenvalues of `A` are positive, negative and zero, which
+    /// by Sylvester's law of inertia are those of `D`. A KKT matrix with `n`
+    /// primal and `m` constraint rows is well posed when this is `(n, m, 0)`.
+    pub fn inertia(&self) -> (usize, usize, usize) {
+        let mut counts = (0, 0, 0);
+        let mut count = |value: T| {
+            if value > T::zero() {
+                counts.0 += 1;
+            } else if value < T::zero() {
+                counts.1 += 1;
+            } else {
+                counts.2 += 1;
+            }
+        };
+
+        let mut k = 0;
+        while k < self.diagonal.len() {
+            if self.off_diagonal[k] != T::zero() {
+                let (a, b, c) = (self.diagonal[k], self.off_diagonal[k], self.diagonal[k + 1]);
+                let det = a * c - b * b;
+                if det < T::zero() {
+                    count(T::one());
+                    count(-T::one());
+                } else {
+                    count(a + c);
+                    count(if det > T::zero() { a + c } else { T::zero() });
+                }
+                k += 2;
+            } else {
+                count(self.diagonal[k]);
+                k += 1;
+            }
+        }
+        counts
+    }
+
+    /// Solves `A X = B` for every column of `b`.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let n = self.diagonal.len();
+        if b.rows != n {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (n, n),
+                rhs: (b.rows, b.cols),
+            });
+        }
+
+        let cols = b.cols;
+        let mut x = b.select_rows(&self.permutation)?;
+        for j in 0..cols {
+            let at = |i: usize| i * cols + j;
+            for i in 0..n {
+                let mut sum = x.values[at(i)];
+                for k in 0..i {
+                    sum = sum - self.l.values[i * n + k] * x.values[at(k)];
+                }
+                x.values[at(i)] = sum;
+            }
+
+            let mut k = 0;
+            while k < n {
+                if self.off_diagonal[k] != T::zero() {
+                    let (a, b, c) = (self.diagonal[k], self.off_diagonal[k], self.diagonal[k + 1]);
+                    let (y0, y1) = (x.values[at(k)], x.values[at(k + 1)]);
+                    let det = a * c - b * b;
+                    x.values[at(k)] = (c * y0 - b * y1) / det;
+                    x.values[at(k + 1)] = (a * y1 - b * y0) / det;
+                    k += 2;
+                } else {
+                    x.values[at(k)] = x.values[at(k)] / self.diagonal[k];
+                    k += 1;
+                }
+            }
+
+            for i in (0..n).rev() {
+                let mut sum = x.values[at(i)];
+                for k in i + 1..n {
+                    sum = sum - self.l.values[k * n + i] * x.values[at(k)];
+                }
+                x.values[at(i)] = sum;
+            }
+        }
+
+        let mut result = x.clone();
+        for (i, &target) in self.permutation.iter().enumerate() {
+            for j in 0..cols {
+                result.values[target * cols + j] = x.values[i * cols + j];
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_ldl_kkt() {
+        // [H A^T; A 0] with H = diag(2, 1) and the constraint x0 + x1 = 1.
+        let kkt: Matrix<f64> = Matrix::new(3, 3, vec![2.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 0.0]);
+        assert!(kkt.cholesky().is_err());
+
+        let ldl = kkt.ldl().unwrap();
+        assert_eq!(ldl.inertia(), (2, 1, 0));
+
+        let permuted = kkt
+            .select_rows(ldl.permutation())
+            .unwrap()
+            .select_cols(ldl.permutation())
+            .unwrap();
+        let product = ldl
+            .l()
+            .mult(&ldl.d())
+            .unwrap()
+            .mult(&ldl.l().transpose())
+            .unwrap();
+        for (a, b) in product.values.iter().zip(&permuted.values) {
+            assert!((a - b).abs() < 1e-12);
+        }
+
+        let rhs = Matrix::new(3, 1, vec![0.0, 0.0, 1.0]);
+        let solution = ldl.solve(&rhs).unwrap();
+        let residual = kkt.mult(&solution).unwrap().subtract(&rhs).unwrap();
+        assert!(residual.max_abs() < 1e-12);
+        for (x, expected) in solution.values.iter().zip([1.0 / 3.0, 2.0 / 3.0]) {
+            assert!((x - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn check_ldl_two_by_two_pivots() {
+        // A zero diagonal forces 2x2 pivots.
+        let matrix: Matrix<f64> = Matrix::new(
+            4,
+            4,
+            vec![
+                0.0, 1.0, 2.0, 0.0, 1.0, 0.0, 0.0, 3.0, 2.0, 0.0, 0.0, 1.0, 0.0, 3.0, 1.0, 0.0,
+            ],
+        );
+        let ldl = matrix.ldl().unwrap();
+        assert!(ldl.d().values.iter().skip(1).step_by(5).any(|&v| v != 0.0));
+
+        let rhs = Matrix::new(4, 2, vec![1.0, 0.0, 2.0, 1.0, 3.0, 0.0, 4.0, 1.0]);
+        let solution = ldl.solve(&rhs).unwrap();
+        let residual = matrix.mult(&solution).unwrap().subtract(&rhs).unwrap();
+        assert!(residual.max_abs() < 1e-12);
+
+        assert!(Matrix::<f64>::new(2, 2, vec![0.0; 4]).ldl().is_err());
+        for values in [[f64::NAN, 1.0, 1.0, 1.0], [1.0, 0.0, 0.0, f64::NAN]] {
+            assert!(Matrix::new(2, 2, values.to_vec()).ldl().is_err());
+        }
+        assert!(Matrix::<f64>::new(2, 3, vec![0.0; 6]).ldl().is_err());
+        assert!(ldl.solve(&Matrix::new(3, 1, vec![0.0; 3])).is_err());
+    }
+}
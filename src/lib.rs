@@ -1,3 +1,75 @@
+pub mod apply;
+pub mod binary;
+pub mod broadcast;
+pub mod codes;
+pub mod collect;
+pub mod complex;
+pub mod config;
+pub mod constant_time;
+pub mod csv;
+pub mod cur;
+pub mod diagonal;
+pub mod dtype;
+pub mod eigen;
+pub mod elimination;
 pub mod error;
+pub mod expr;
+pub mod fixed;
+pub mod float;
+pub mod format;
+pub mod gallery;
+pub mod gemm;
+pub mod gf2;
+pub mod grid;
+pub mod hessenberg;
+pub mod history;
 pub mod identity_element;
+pub mod integer;
+pub mod iter;
+pub mod iterative;
+pub mod labeled;
+pub mod layout;
+pub mod lda;
+pub mod ldl;
+pub mod lu;
+pub mod mask;
+pub mod matfun;
+pub mod math;
 pub mod matrix;
+#[cfg(all(feature = "mmap", unix, target_pointer_width = "64"))]
+pub mod mmap;
+pub mod normalize;
+pub mod npy;
+pub mod orthogonal;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod pca;
+pub mod pgm;
+pub mod pivot;
+pub mod pod;
+pub mod polar;
+pub mod polynomial;
+pub mod precision;
+#[cfg(feature = "profiling")]
+pub mod profile;
+pub mod qr;
+pub mod random;
+pub mod rank;
+pub mod rearrange;
+pub mod reduce;
+pub mod regression;
+pub mod rounding;
+pub mod scalar;
+pub mod schur;
+pub mod select;
+pub mod semiring;
+pub mod simulate;
+pub mod smatrix;
+pub mod sort;
+pub mod storage;
+pub mod svd;
+pub mod testing;
+pub mod units;
+pub mod view;
+#[cfg(feature = "visualize")]
+pub mod visualize;
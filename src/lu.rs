@@ -0,0 +1,192 @@
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// An LU factorization with partial pivoting, `P A = L U`.
+///
+/// `L` (unit lower triangular) and `U` share one compact matrix, and
+/// `permutation[i]` is the row of `A` that ended up in row `i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu<T> {
+    factors: Matrix<T>,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn lu(&self) -> Result<Lu<T>, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+
+        let n = self.rows;
+        let mut factors = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| {
+                    let a = factors.values[a * n + k].abs();
+                    let b = factors.values[b * n + k].abs();
+                    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+
+            let pivot = factors.values[pivot_row * n + k];
+            if pivot == T::zero() {
+                return Err(MatrixError::Singular { column: k });
+            }
+
+            if pivot_row != k {
+                factors.swap_rows(pivot_row, k)?;
+                permutation.swap(pivot_row, k);
+                swaps += 1;
+            }
+
+            for i in k + 1..n {
+                let factor = factors.values[i * n + k] / pivot;
+                factors.values[i * n + k] = factor;
+                for j in k + 1..n {
+                    let update = factor * factors.values[k * n + j];
+                    factors.values[i * n + j] = factors.values[i * n + j] - update;
+                }
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(
+            (0..n).map(|k| (n - k - 1) * (2 * (n - k) - 1)).sum(),
+            2 * n * n,
+        );
+
+        Ok(Lu {
+            factors,
+            permutation,
+            swaps,
+        })
+    }
+}
+
+impl<T: Float> Lu<T> {
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.factors.rows;
+        let mut result = Matrix::identity_within(n);
+        for i in 0..n {
+            for j in 0..i {
+                result.values[i * n + j] = self.factors.values[i * n + j];
+            }
+        }
+        result
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.factors.rows;
+        let mut result = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for i in 0..n {
+            for j in i..n {
+                result.values[i * n + j] = self.factors.values[i * n + j];
+            }
+        }
+        result
+    }
+
+    pub fn determinant(&self) -> T {
+        let n = self.factors.rows;
+        let product = (0..n).fold(T::one(), |acc, i| acc * self.factors.values[i * n + i]);
+        if self.swaps.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+
+    /// Solves `A X = B` for every column of `b`.
+    pub fn solve(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let n = self.factors.rows;
+        if b.rows != n {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (n, n),
+                rhs: (b.rows, b.cols),
+            });
+        }
+
+        let cols = b.cols;
+        let mut x = Matrix::new(n, cols, vec![T::zero(); n * cols]);
+        for (i, &source) in self.permutation.iter().enumerate() {
+            for j in 0..cols {
+                x.values[i * cols + j] = b.values[source * cols + j];
+            }
+        }
+
+        for j in 0..cols {
+            for i in 0..n {
+                let mut sum = x.values[i * cols + j];
+                for k in 0..i {
+                    sum = sum - self.factors.values[i * n + k] * x.values[k * cols + j];
+                }
+                x.values[i * cols + j] = sum;
+            }
+            for i in (0..n).rev() {
+                let mut sum = x.values[i * cols + j];
+                for k in i + 1..n {
+                    sum = sum - self.factors.values[i * n + k] * x.values[k * cols + j];
+                }
+                x.values[i * cols + j] = sum / self.factors.values[i * n + i];
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(2 * n * n * cols, n * n + 2 * n * cols);
+
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_lu() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0]);
+        let lu = matrix.lu().unwrap();
+
+        let mut permuted = Matrix::new(3, 3, vec![0.0; 9]);
+        for (i, &source) in lu.permutation().iter().enumerate() {
+            for j in 0..3 {
+                permuted.values[i * 3 + j] = matrix.values[source * 3 + j];
+            }
+        }
+        let product = lu.l().mult_naive(&lu.u()).unwrap();
+        for (a, b) in product.values.iter().zip(&permuted.values) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert!((lu.determinant() + 3.0).abs() < 1e-12);
+
+        let b = Matrix::new(3, 1, vec![6.0, 15.0, 25.0]);
+        let x = lu.solve(&b).unwrap();
+        for value in &x.values {
+            assert!((value - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn check_lu_singular() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(matches!(
+            matrix.lu(),
+            Err(MatrixError::Singular { column: 1 })
+        ));
+        assert!(Matrix::<f64>::new(2, 3, vec![0.0; 6]).lu().is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use crate::error::{elementwise_mismatch, MatrixError, Op};
+use crate::matrix::Matrix;
+
+fn check_mask<T>(matrix: &Matrix<T>, mask: &Matrix<bool>) -> Result<(), MatrixError> {
+    if matrix.rows != mask.rows || matrix.cols != mask.cols {
+        return Err(MatrixError::DimensionMismatch {
+            op: Op::Mask,
+            lhs: (matrix.rows, matrix.cols),
+            rhs: (mask.rows, mask.cols),
+        });
+    }
+    Ok(())
+}
+
+impl<T> Matrix<T> {
+    fn compare<F>(&self, matrix_b: &Matrix<T>, f: F) -> Result<Matrix<bool>, MatrixError>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
+            return Err(elementwise_mismatch(
+                Op::Compare,
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+        let new_values = self
+            .values
+            .iter()
+            .zip(&matrix_b.values)
+            .map(|(a, b)| f(a, b))
+            .collect();
+        Ok(Matrix::new(self.rows, self.cols, new_values))
+    }
+
+    fn compare_scalar<F: Fn(&T) -> bool>(&self, f: F) -> Matrix<bool> {
+        Matrix::new(self.rows, self.cols, self.values.iter().map(f).collect())
+    }
+}
+
+impl<T: PartialEq> Matrix<T> {
+    pub fn eq_elementwise(&self, matrix_b: &Matrix<T>) -> Result<Matrix<bool>, MatrixError> {
+        self.compare(matrix_b, |a, b| a == b)
+    }
+}
+
+impl<T: PartialOrd> Matrix<T> {
+    pub fn gt(&self, matrix_b: &Matrix<T>) -> Result<Matrix<bool>, MatrixError> {
+        self.compare(matrix_b, |a, b| a > b)
+    }
+
+    pub fn lt(&self, matrix_b: &Matrix<T>) -> Result<Matrix<bool>, MatrixError> {
+        self.compare(matrix_b, |a, b| a < b)
+    }
+
+    /// Where each element exceeds `threshold`. NaN compares false.
+    pub fn gt_scalar(&self, threshold: &T) -> Matrix<bool> {
+        self.compare_scalar(|a| a > threshold)
+    }
+
+    pub fn lt_scalar(&self, threshold: &T) -> Matrix<bool> {
+        self.compare_scalar(|a| a < threshold)
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// This matrix's element where `mask` is true and `other`'s elsewhere.
+    pub fn select(&self, mask: &Matrix<bool>, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        check_mask(self, mask)?;
+        check_mask(other, mask)?;
+        let new_values = self
+            .values
+            .iter()
+            .zip(&other.values)
+            .zip(&mask.values)
+            .map(|((a, b), &keep)| if keep { a.clone() } else { b.clone() })
+            .collect();
+        Ok(Matrix::new(self.rows, self.cols, new_values))
+    }
+
+    /// Overwrites every element where `mask` is true with `value`.
+    pub fn masked_fill(&mut self, mask: &Matrix<bool>, value: T) -> Result<&mut Self, MatrixError> {
+        check_mask(self, mask)?;
+        for (entry, &fill) in self.values.iter_mut().zip(&mask.values) {
+            if fill {
+                *entry = value.clone();
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl Matrix<bool> {
+    /// How many elements are true.
+    pub fn count_true(&self) -> usize {
+        self.values.iter().filter(|&&v| v).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::{MatrixError, Op};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_mask_ops() {
+        let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, -2.0, f64::NAN, 4.0]);
+        let b: Matrix<f64> = Matrix::new(2, 2, vec![0.0, 0.0, 0.0, 4.0]);
+
+        assert_eq!(a.gt(&b).unwrap().values, vec![true, false, false, false]);
+        assert_eq!(a.lt(&b).unwrap().values, vec![false, true, false, false]);
+        assert_eq!(a.eq_elementwise(&b).unwrap().count_true(), 1);
+
+        let positive = a.gt_scalar(&0.0);
+        assert_eq!(a.select(&positive, &b).unwrap().values[..2], [1.0, 0.0]);
+
+        let mut clipped = a.clone();
+        clipped.masked_fill(&a.lt_scalar(&0.0), 0.0).unwrap();
+        assert_eq!(clipped.values[..2], [1.0, 0.0]);
+    }
+
+    #[test]
+    fn check_mask_shapes() {
+        let mut a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let mask = Matrix::new(3, 2, vec![true; 6]);
+
+        assert!(matches!(
+            a.masked_fill(&mask, 0),
+            Err(MatrixError::DimensionMismatch { op: Op::Mask, .. })
+        ));
+        assert!(a.select(&mask, &a.clone()).is_err());
+        assert!(a.gt(&a.transpose()).unwrap_err().transpose_hint().is_some());
+    }
+}
@@ -0,0 +1,295 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// `logm` takes square roots until `|A - I|_F` is below this, where a short
+/// series for the logarithm is accurate.
+const LOG_SERIES_RADIUS: f64 = 0.25;
+
+impl<T: Float> Matrix<T> {
+    /// `f(A) = V f(D) V^-1` for a diagonalizable `A = V D V^-1` with real
+    /// eigenvalues, applying `f` to each eigenvalue. Fails if `A` has
+    /// complex eigenvalues, or if its eigenvectors are so close to
+    /// dependent (condition number above `1 / sqrt(epsilon)`) that `A` is
+    /// numerically defective.
+    pub fn funm<F: Fn(T) -> T>(&self, f: F) -> Result<Matrix<T>, MatrixError> {
+        let schur = self.schur()?;
+        let n = self.rows;
+        let (q, t) = (schur.q(), schur.t());
+        if (1..n).any(|i| t.values[i * n + i - 1] != T::zero()) {
+            return Err(MatrixError::InvalidArgument(
+                "Matrix has complex eigenvalues".to_string(),
+            ));
+        }
+
+        // Eigenvectors of the triangular `T` by back substitution, with
+        // a tiny floor on the divisors so repeated eigenvalues stay finite.
+        let small = T::epsilon() * t.max_abs();
+        let mut y = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for k in 0..n {
+            let lambda = t.values[k * n + k];
+            y.values[k * n + k] = T::one();
+            for i in (0..k).rev() {
+                let sum = (i + 1..=k).fold(T::zero(), |acc, j| {
+                    acc + t.values[i * n + j] * y.values[j * n + k]
+                });
+                let mut divisor = t.values[i * n + i] - lambda;
+                if divisor.abs() < small {
+                    divisor = if small > T::zero() { small } else { T::one() };
+                }
+                y.values[i * n + k] = -sum / divisor;
+            }
+        }
+        let mut vectors = q.mult_naive(&y)?;
+        for k in 0..n {
+            let norm = (0..n)
+                .fold(T::zero(), |acc, i| {
+                    acc + vectors.values[i * n + k] * vectors.values[i * n + k]
+                })
+                .sqrt();
+            for i in 0..n {
+                vectors.values[i * n + k] = vectors.values[i * n + k] / norm;
+            }
+        }
+
+        let singular_values = vectors.svd()?.singular_values().to_vec();
+        let largest = singular_values.first().copied().unwrap_or(T::one());
+        let smallest = singular_values.last().copied().unwrap_or(T::one());
+        if smallest / T::epsilon().sqrt() < largest {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Eigenvector matrix has condition number {:e}; the matrix is defective",
+                (largest / smallest).to_f64()
+            )));
+        }
+
+        let mut scaled = vectors.clone();
+        for k in 0..n {
+            let value = f(t.values[k * n + k]);
+            for i in 0..n {
+                scaled.values[i * n + k] = scaled.values[i * n + k] * value;
+            }
+        }
+        scaled.mult_naive(&vectors.inverse()?)
+    }
+
+    /// Principal functions such as `sqrtm` and `logm` are defined for real
+    /// matrices without eigenvalues on the closed negative real axis.
+    fn check_principal_spectrum(&self) -> Result<(), MatrixError> {
+        for value in self.schur()?.eigenvalues() {
+            if value.im == T::zero() && value.re <= T::zero() {
+                return Err(MatrixError::InvalidArgument(format!(
+                    "Eigenvalue {} has no real principal branch",
+                    value.re.to_f64()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.lu()?.solve(&Matrix::identity_within(self.rows))
+    }
+
+    /// The principal square root, the unique `X` with `X X = A` whose
+    /// eigenvalues have positive real parts, by the Denman-Beavers
+    /// iteration. Fails if `A` has an eigenvalue on the closed negative
+    /// real axis.
+    pub fn sqrtm(&self) -> Result<Matrix<T>, MatrixError> {
+        self.check_principal_spectrum()?;
+        self.denman_beavers()
+    }
+
+    fn denman_beavers(&self) -> Result<Matrix<T>, MatrixError> {
+        let n = self.rows;
+        let half = T::from_f64(0.5);
+        let tolerance = T::from_f64(n as f64) * T::epsilon();
+        let mut y = self.clone();
+        let mut z: Matrix<T> = Matrix::identity_within(n);
+        let mut previous_change: Option<T> = None;
+
+        for _ in 0..MAX_ITERATIONS {
+            let y_inverse = y.inverse()?;
+            let z_inverse = z.inverse()?;
+            let mut change = T::zero();
+            for (index, value) in y.values.iter_mut().enumerate() {
+                let next = half * (*value + z_inverse.values[index]);
+                change += (next - *value) * (next - *value);
+                *value = next;
+            }
+            for (value, &inverse) in z.values.iter_mut().zip(&y_inverse.values) {
+                *value = half * (*value + inverse);
+            }
+
+            // Rounding eventually stops the change from shrinking; once it
+            // is already small, that is as converged as `y` will get.
+            let change = change.sqrt();
+            let norm = y.frobenius_norm();
+            if change <= tolerance * norm
+                || (change <= T::epsilon().sqrt() * norm
+                    && previous_change.is_some_and(|p| change >= p))
+            {
+                return Ok(y);
+            }
+            previous_change = Some(change);
+        }
+
+        Err(MatrixError::NonConvergent {
+            iterations: MAX_ITERATIONS,
+            residual: previous_change.map_or(f64::NAN, |change| change.to_f64()),
+        })
+    }
+
+    /// The principal logarithm, whose eigenvalues have imaginary parts in
+    /// `(-pi, pi)`, by inverse scaling and squaring: square roots bring `A`
+    /// close to `I`, where `log A = 2 atanh((A - I)(A + I)^-1)` is summed as
+    /// a series. Fails if `A` has an eigenvalue on the closed negative real
+    /// axis.
+    pub fn logm(&self) -> Result<Matrix<T>, MatrixError> {
+        self.check_principal_spectrum()?;
+        let n = self.rows;
+        let identity: Matrix<T> = Matrix::identity_within(n);
+        let distance = |matrix: &Matrix<T>| {
+            matrix
+                .values
+                .iter()
+                .zip(&identity.values)
+                .fold(T::zero(), |acc, (&a, &b)| acc + (a - b) * (a - b))
+                .sqrt()
+        };
+
+        let mut root = self.clone();
+        let mut scale = T::one();
+        while distance(&root) > T::from_f64(LOG_SERIES_RADIUS) {
+            root = root.denman_beavers()?;
+            scale = scale + scale;
+        }
+
+        let mut numerator = root.clone();
+        let mut denominator = root;
+        for i in 0..n {
+            numerator.values[i * n + i] = numerator.values[i * n + i] - T::one();
+            denominator.values[i * n + i] += T::one();
+        }
+        // `(A - I)` and `(A + I)^-1` commute, so either order gives `y`.
+        let y = numerator.mult_naive(&denominator.inverse()?)?;
+        let y_squared = y.mult_naive(&y)?;
+
+        let mut sum = y.clone();
+        let mut power = y;
+        for k in 1..MAX_ITERATIONS {
+            power = power.mult_naive(&y_squared)?;
+            let factor = T::one() / T::from_f64((2 * k + 1) as f64);
+            let mut term_norm = T::zero();
+            for (value, &p) in sum.values.iter_mut().zip(&power.values) {
+                *value += factor * p;
+                term_norm += factor * p * factor * p;
+            }
+            if term_norm.sqrt() <= T::epsilon() * sum.frobenius_norm() {
+                break;
+            }
+        }
+
+        let factor = scale + scale;
+        for value in sum.values.iter_mut() {
+            *value = *value * factor;
+        }
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        for (x, y) in a.values.iter().zip(&b.values) {
+            assert!((x - y).abs() < 1e-10, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_funm() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        assert_close(
+            &matrix.funm(|x| x.sqrt()).unwrap(),
+            &matrix.sqrtm().unwrap(),
+        );
+        assert_close(
+            &matrix.funm(|x| x * x).unwrap(),
+            &matrix.mult_naive(&matrix).unwrap(),
+        );
+
+        // Not symmetric, with eigenvalues 1 and 2.
+        let skewed: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 5.0, 0.0, 2.0]);
+        let e = std::f64::consts::E;
+        assert_close(
+            &skewed.funm(f64::exp).unwrap(),
+            &Matrix::new(2, 2, vec![e, 5.0 * (e * e - e), 0.0, e * e]),
+        );
+
+        let jordan: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 1.0, 0.0, 1.0]);
+        assert!(jordan.funm(f64::exp).is_err());
+        let rotation: Matrix<f64> = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+        assert!(rotation.funm(f64::exp).is_err());
+    }
+
+    #[test]
+    fn check_sqrtm() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let root = matrix.sqrtm().unwrap();
+        assert_close(&root.mult_naive(&root).unwrap(), &matrix);
+
+        // A rotation by 90 degrees has eigenvalues +-i; its principal root
+        // rotates by 45.
+        let rotation: Matrix<f64> = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+        let half = std::f64::consts::FRAC_1_SQRT_2;
+        assert_close(
+            &rotation.sqrtm().unwrap(),
+            &Matrix::new(2, 2, vec![half, -half, half, half]),
+        );
+
+        assert!(Matrix::<f64>::new(2, 2, vec![-1.0, 0.0, 0.0, 4.0])
+            .sqrtm()
+            .is_err());
+        assert!(Matrix::<f64>::new(2, 2, vec![1.0, 1.0, 1.0, 1.0])
+            .sqrtm()
+            .is_err());
+    }
+
+    #[test]
+    fn check_logm() {
+        let e = std::f64::consts::E;
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![e, 0.0, 0.0, e * e * e]);
+        assert_close(
+            &matrix.logm().unwrap(),
+            &Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 3.0]),
+        );
+
+        let theta: f64 = 2.5;
+        let rotation = Matrix::new(
+            2,
+            2,
+            vec![theta.cos(), -theta.sin(), theta.sin(), theta.cos()],
+        );
+        assert_close(
+            &rotation.logm().unwrap(),
+            &Matrix::new(2, 2, vec![0.0, -theta, theta, 0.0]),
+        );
+
+        // log(exp(N)) = N for a nilpotent N with exp(N) = I + N + N^2 / 2.
+        let exp_n: Matrix<f64> =
+            Matrix::new(3, 3, vec![1.0, 1.0, 1.5, 0.0, 1.0, 3.0, 0.0, 0.0, 1.0]);
+        assert_close(
+            &exp_n.logm().unwrap(),
+            &Matrix::new(3, 3, vec![0.0, 1.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0]),
+        );
+        assert!(Matrix::<f64>::new(2, 2, vec![-2.0, 0.0, 0.0, 1.0])
+            .logm()
+            .is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+use std::cmp::Ordering;
+use std::ops::Neg;
+
+/// For each unary [`Float`] function, a method returning a new matrix and a
+/// `_mut` method applying it in place.
+macro_rules! impl_elementwise {
+    ($($name:ident, $name_mut:ident);* $(;)?) => {
+        impl<T: Float> Matrix<T> {
+            $(
+                pub fn $name(&self) -> Matrix<T> {
+                    self.map(|&v| v.$name())
+                }
+
+                pub fn $name_mut(&mut self) -> &mut Self {
+                    self.values.iter_mut().for_each(|v| *v = v.$name());
+                    self
+                }
+            )*
+        }
+    };
+}
+
+impl_elementwise!(
+    exp, exp_mut;
+    ln, ln_mut;
+    sqrt, sqrt_mut;
+    abs, abs_mut;
+    sin, sin_mut;
+    cos, cos_mut;
+    tanh, tanh_mut;
+    round, round_mut;
+    floor, floor_mut;
+    ceil, ceil_mut;
+);
+
+impl<T: Float> Matrix<T> {
+    pub fn powi(&self, n: i32) -> Matrix<T> {
+        self.map(|&v| v.powi(n))
+    }
+
+    pub fn powi_mut(&mut self, n: i32) -> &mut Self {
+        self.values.iter_mut().for_each(|v| *v = v.powi(n));
+        self
+    }
+
+    pub fn powf(&self, n: T) -> Matrix<T> {
+        self.map(|&v| v.powf(n))
+    }
+
+    pub fn powf_mut(&mut self, n: T) -> &mut Self {
+        self.values.iter_mut().for_each(|v| *v = v.powf(n));
+        self
+    }
+}
+
+impl<T: PartialOrd + Clone> Matrix<T> {
+    /// Every element limited to `[min, max]`. Works for integer and float
+    /// matrices alike; NaN elements stay NaN.
+    pub fn clamp(&self, min: T, max: T) -> Result<Matrix<T>, MatrixError> {
+        let mut result = self.clone();
+        result.clamp_mut(min, max)?;
+        Ok(result)
+    }
+
+    pub fn clamp_mut(&mut self, min: T, max: T) -> Result<&mut Self, MatrixError> {
+        if !matches!(
+            min.partial_cmp(&max),
+            Some(Ordering::Less | Ordering::Equal)
+        ) {
+            return Err(MatrixError::InvalidArgument(
+                "Clamp bounds must satisfy min <= max".to_string(),
+            ));
+        }
+        for v in self.values.iter_mut() {
+            if *v < min {
+                *v = min.clone();
+            } else if *v > max {
+                *v = max.clone();
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl<T: Neg<Output = T> + Clone> Matrix<T> {
+    pub fn negate_mut(&mut self) -> &mut Self {
+        for value in self.values.iter_mut() {
+            *value = -value.clone();
+        }
+        self
+    }
+}
+
+impl<T: Neg<Output = T> + Clone> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        self.negate_mut();
+        self
+    }
+}
+
+impl<T: Neg<Output = T> + Clone> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        self.map(|value| -value.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_elementwise_math() {
+        let matrix: Matrix<f64> = Matrix::new(1, 3, vec![0.0, 1.0, 4.0]);
+
+        assert_eq!(matrix.exp().ln(), matrix);
+        assert_eq!(matrix.sqrt().values, vec![0.0, 1.0, 2.0]);
+        assert_eq!(matrix.powi(2), matrix.powf(2.0));
+        assert_eq!(matrix.sin().values[0], 0.0);
+        assert_eq!(matrix.cos().values[0], 1.0);
+        assert!(matrix
+            .tanh()
+            .values
+            .iter()
+            .all(|&v| (0.0..1.0).contains(&v)));
+
+        let mut negated: Matrix<f32> = Matrix::new(1, 2, vec![-1.5, 2.0]);
+        negated.abs_mut().powi_mut(2).sqrt_mut();
+        assert_eq!(negated.values, vec![1.5, 2.0]);
+        assert!(Matrix::new(1, 1, vec![-1.0f64]).ln().values[0].is_nan());
+    }
+
+    #[test]
+    fn check_clamp_and_rounding() {
+        let matrix: Matrix<f64> = Matrix::new(1, 4, vec![-2.5, 0.4, 1.5, f64::NAN]);
+
+        assert_eq!(matrix.round().values[..3], [-3.0, 0.0, 2.0]);
+        assert_eq!(matrix.floor().values[..3], [-3.0, 0.0, 1.0]);
+        assert_eq!(matrix.ceil().values[..3], [-2.0, 1.0, 2.0]);
+
+        let clamped = matrix.clamp(-1.0, 1.0).unwrap();
+        assert_eq!(clamped.values[..3], [-1.0, 0.4, 1.0]);
+        assert!(clamped.values[3].is_nan());
+        assert!(matrix.clamp(1.0, -1.0).is_err());
+        assert!(matrix.clamp(f64::NAN, 1.0).is_err());
+
+        let mut pixels: Matrix<i32> = Matrix::new(1, 3, vec![-20, 128, 300]);
+        pixels.clamp_mut(0, 255).unwrap();
+        assert_eq!(pixels.values, vec![0, 128, 255]);
+    }
+
+    #[test]
+    fn check_negation() {
+        let matrix: Matrix<i32> = Matrix::new(1, 3, vec![1, -2, 0]);
+
+        assert_eq!((-&matrix).values, vec![-1, 2, 0]);
+        assert_eq!(-(-matrix.clone()), matrix);
+
+        let mut floats: Matrix<f64> = Matrix::new(1, 2, vec![0.5, -1.5]);
+        floats.negate_mut();
+        assert_eq!(floats.values, vec![-0.5, 1.5]);
+    }
+}
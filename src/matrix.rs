@@ -1,205 +1,533 @@
-use crate::error::MatrixError;
-use crate::identity_element::IdentityElement;
-use std::ops::{Add, AddAssign, Mul, Sub};
-
-#[derive(Debug, PartialEq)]
-pub struct Matrix<T> {
-    rows: usize,
-    cols: usize,
-    values: Vec<T>,
+use crate::complex::Conjugate;
+use crate::error::{elementwise_mismatch, product_mismatch, ElementError, MatrixError, Op};
+use crate::scalar::Scalar;
+use crate::semiring::Standard;
+use crate::storage::{Storage, StorageMut};
+use std::marker::PhantomData;
+
+/// A dense row-major matrix.
+///
+/// Either dimension may be zero. Such empty matrices keep their shape
+/// through every operation: sums and transposes of an `m`x`0` matrix are
+/// `m`x`0` and `0`x`m`, and the product of `m`x`0` and `0`x`n` matrices is
+/// the `m`x`n` zero matrix, since each entry is an empty sum. Reductions
+/// such as [`Matrix::sum`] and [`Matrix::product`] return their identity,
+/// and a `0`x`0` matrix has determinant one.
+///
+/// The elements live in `S`, an owned `Vec` unless another
+/// [`Storage`](crate::storage::Storage) is named. Most algorithms are
+/// implemented for the owned form; see [`crate::storage`] for what every
+/// storage supports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<T, S = Vec<T>> {
+    pub(crate) rows: usize,
+    pub(crate) cols: usize,
+    pub(crate) values: S,
+    pub(crate) marker: PhantomData<T>,
+}
+
+pub(crate) fn checked_len(rows: usize, cols: usize) -> Result<usize, MatrixError> {
+    rows.checked_mul(cols).ok_or_else(|| {
+        MatrixError::SizeOverflow(format!(
+            "A {}x{} matrix has more elements than fit in usize",
+            rows, cols
+        ))
+    })
 }
 
-impl<T> Matrix<T>
-where
-    T: Add<Output = T>
-        + Sub<Output = T>
-        + Mul<Output = T>
-        + Copy
-        + Default
-        + AddAssign
-        + IdentityElement,
-{
+impl<T> Matrix<T> {
+    /// Trusts that `values` holds exactly `rows * cols` elements. Every
+    /// other constructor and method keeps that invariant and reports bad
+    /// input as a [`MatrixError`], so this is the one way to build a matrix
+    /// that later methods may panic on; use [`Matrix::try_new`] for
+    /// untrusted input.
     pub fn new(rows: usize, cols: usize, values: Vec<T>) -> Matrix<T> {
+        debug_assert_eq!(rows.checked_mul(cols), Some(values.len()));
         Matrix {
-            rows: rows,
-            cols: cols,
-            values: values,
+            rows,
+            cols,
+            values,
+            marker: PhantomData,
         }
     }
 
-    pub fn get_rows(&self) -> usize {
-        self.rows
+    pub fn try_new(rows: usize, cols: usize, values: Vec<T>) -> Result<Matrix<T>, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        if values.len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+
+        Ok(Matrix::new(rows, cols, values))
     }
 
-    pub fn get_cols(&self) -> usize {
-        self.cols
+    /// Builds a matrix by calling `f(row, col)` for every cell in row-major
+    /// order, pushing each result instead of overwriting a default-filled
+    /// buffer.
+    pub(crate) fn from_fn<F: FnMut(usize, usize) -> T>(
+        rows: usize,
+        cols: usize,
+        mut f: F,
+    ) -> Matrix<T> {
+        let mut new_values = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                new_values.push(f(i, j));
+            }
+        }
+        Matrix::new(rows, cols, new_values)
     }
 
     pub fn get_values(&self) -> &Vec<T> {
         &self.values
     }
 
-    pub fn set_rows(&mut self, new_rows: usize) -> &mut Self {
-        self.rows = new_rows;
-        self
+    /// The row-major buffer, without copying. With [`Matrix::try_new`] this
+    /// is the conversion to and from other row-major containers; the crate
+    /// has no `ndarray` feature, but an `Array2` moves across losslessly as
+    /// `Array2::from_shape_vec((rows, cols), matrix.into_vec())` and, for a
+    /// standard-layout array, back from its raw vector.
+    pub fn into_vec(self) -> Vec<T> {
+        self.values
     }
 
-    pub fn set_cols(&mut self, new_cols: usize) -> &mut Self {
+    /// Reinterprets the row-major values with a new shape of the same size.
+    pub fn reshape(&mut self, new_rows: usize, new_cols: usize) -> Result<&mut Self, MatrixError> {
+        let len = checked_len(new_rows, new_cols)?;
+        if len != self.values.len() {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: self.values.len(),
+            });
+        }
+        self.rows = new_rows;
         self.cols = new_cols;
-        self
+        Ok(self)
+    }
+
+    /// Changes the row count, keeping the element count: a `2`x`6` matrix
+    /// may become `3`x`4`. Fails if `new_rows` does not divide it.
+    pub fn set_rows(&mut self, new_rows: usize) -> Result<&mut Self, MatrixError> {
+        let new_cols = match new_rows {
+            0 => 0,
+            _ => self.values.len() / new_rows,
+        };
+        self.reshape(new_rows, new_cols)
+    }
+
+    /// Changes the column count, keeping the element count.
+    pub fn set_cols(&mut self, new_cols: usize) -> Result<&mut Self, MatrixError> {
+        let new_rows = match new_cols {
+            0 => 0,
+            _ => self.values.len() / new_cols,
+        };
+        self.reshape(new_rows, new_cols)
     }
 
     pub fn set_values(&mut self, new_values: Vec<T>) -> Result<(), MatrixError> {
-        if new_values.len() == self.rows * self.cols {
+        let len = checked_len(self.rows, self.cols)?;
+        if new_values.len() == len {
             self.values = new_values;
             Ok(())
         } else {
-            Err(MatrixError::DimensionMismatch(format!(
-                "Matrix has capacity of {}, gave it {} values",
-                self.rows * self.cols,
-                new_values.len()
-            )))
+            Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: new_values.len(),
+            })
         }
     }
 
-    pub fn value_at(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
-        if row < self.rows && col < self.cols {
-            let index = (row * self.cols) + col;
-            Ok(&self.values[index])
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix::new(self.rows, self.cols, self.values.iter().map(f).collect())
+    }
+
+    /// Maps every element, stopping at the first failure in row-major order.
+    pub fn try_map<U, E, F>(&self, mut f: F) -> Result<Matrix<U>, ElementError<E>>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        let cols = self.cols.max(1);
+        let new_values = self
+            .values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                f(value).map_err(|error| ElementError {
+                    row: index / cols,
+                    col: index % cols,
+                    error,
+                })
+            })
+            .collect::<Result<Vec<U>, _>>()?;
+        Ok(Matrix::new(self.rows, self.cols, new_values))
+    }
+
+    /// Like [`Matrix::try_map`], but visits every element and reports all
+    /// failures in row-major order.
+    pub fn try_map_all<U, E, F>(&self, mut f: F) -> Result<Matrix<U>, Vec<ElementError<E>>>
+    where
+        F: FnMut(&T) -> Result<U, E>,
+    {
+        let cols = self.cols.max(1);
+        let mut new_values = Vec::with_capacity(self.values.len());
+        let mut errors = Vec::new();
+        for (index, value) in self.values.iter().enumerate() {
+            match f(value) {
+                Ok(mapped) => new_values.push(mapped),
+                Err(error) => errors.push(ElementError {
+                    row: index / cols,
+                    col: index % cols,
+                    error,
+                }),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(Matrix::new(self.rows, self.cols, new_values))
         } else {
-            Err(MatrixError::InvalidIndex(format!(
-                "Index ({}, {}) is out of bounds for matrix of size {}x{}",
-                row, col, self.rows, self.cols
-            )))
+            Err(errors)
         }
     }
+}
 
-    pub fn add(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+impl<T, S> Matrix<T, S> {
+    pub(crate) fn check_same_shape<R>(
+        &self,
+        matrix_b: &Matrix<T, R>,
+        op: Op,
+    ) -> Result<(), MatrixError> {
         if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
-            return Err(MatrixError::DimensionMismatch(format!(
-                "Cannot add matrices of dimensions {}x{} and {}x{}",
-                self.rows, self.cols, matrix_b.rows, matrix_b.cols
-            )));
+            return Err(elementwise_mismatch(
+                op,
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
         }
+        Ok(())
+    }
 
-        let new_values: Vec<T> = self
-            .values
-            .iter()
-            .zip(&matrix_b.values)
-            .map(|(a, b)| *a + *b)
-            .collect();
+    /// Reports a bad row as an index into the first column.
+    pub(crate) fn check_row(&self, row: usize) -> Result<(), MatrixError> {
+        if row < self.rows {
+            Ok(())
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, 0),
+                dims: (self.rows, self.cols),
+            })
+        }
+    }
 
-        Ok(Matrix::new(self.rows, self.cols, new_values))
+    /// Reports a bad column as an index into the first row.
+    pub(crate) fn check_col(&self, col: usize) -> Result<(), MatrixError> {
+        if col < self.cols {
+            Ok(())
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (0, col),
+                dims: (self.rows, self.cols),
+            })
+        }
     }
+}
 
-    pub fn add_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
-        if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
-            return Err(MatrixError::DimensionMismatch(format!(
-                "Cannot add matricies of dimensions {}x{} and {}x{}",
-                self.rows, self.cols, matrix_b.rows, matrix_b.cols
-            )));
+/// The in-place operations, which write through [`StorageMut`] so that a
+/// [`SharedMatrix`](crate::storage::SharedMatrix) or `Cow` matrix copies its
+/// elements on the first write instead of refusing the operation.
+impl<T, S: StorageMut<T>> Matrix<T, S> {
+    pub fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<&mut Self, MatrixError> {
+        self.check_row(row_a)?;
+        self.check_row(row_b)?;
+
+        if row_a != row_b {
+            let cols = self.cols;
+            let values = self.values.as_mut_slice();
+            for j in 0..cols {
+                values.swap(row_a * cols + j, row_b * cols + j);
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+impl<T: Scalar, S: StorageMut<T>> Matrix<T, S> {
+    pub fn add_mut<R: Storage<T>>(
+        &mut self,
+        matrix_b: &Matrix<T, R>,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+
+        let values = self.values.as_mut_slice();
+        for (a, b) in values.iter_mut().zip(matrix_b.as_slice()) {
+            *a += b.clone();
         }
 
-        for i in 0..self.values.len() {
-            self.values[i] = self.values[i] + matrix_b.values[i];
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(values.len(), 3 * values.len());
+
+        Ok(self)
+    }
+
+    pub fn subtract_mut<R: Storage<T>>(
+        &mut self,
+        matrix_b: &Matrix<T, R>,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Subtract)?;
+
+        let values = self.values.as_mut_slice();
+        for (a, b) in values.iter_mut().zip(matrix_b.as_slice()) {
+            *a = std::mem::take(a) - b.clone();
         }
 
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(values.len(), 3 * values.len());
+
         Ok(self)
     }
 
-    pub fn subtract(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
-        if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
-            return Err(MatrixError::DimensionMismatch(format!(
-                "Cannot subtract matricies of dimensions {}x{} and {}x{}",
-                self.rows, self.cols, matrix_b.rows, matrix_b.cols
-            )));
+    pub fn mult_scalar(&mut self, num: T) -> &mut Self {
+        let values = self.values.as_mut_slice();
+        for value in values.iter_mut() {
+            let a = std::mem::take(value);
+            *value = a * num.clone();
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(values.len(), 2 * values.len());
+
+        self
+    }
+
+    pub fn scale_row(&mut self, row: usize, factor: T) -> Result<&mut Self, MatrixError> {
+        self.check_row(row)?;
+
+        let cols = self.cols;
+        for value in &mut self.values.as_mut_slice()[row * cols..(row + 1) * cols] {
+            let a = std::mem::take(value);
+            *value = a * factor.clone();
         }
 
+        Ok(self)
+    }
+
+    pub fn add_scaled_row(
+        &mut self,
+        source: usize,
+        target: usize,
+        factor: T,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_row(source)?;
+        self.check_row(target)?;
+
+        let cols = self.cols;
+        let values = self.values.as_mut_slice();
+        for j in 0..cols {
+            let scaled = values[source * cols + j].clone() * factor.clone();
+            values[target * cols + j] += scaled;
+        }
+
+        Ok(self)
+    }
+}
+
+const BLOCKED_MIN_DIM: usize = 128;
+const DEFAULT_BLOCK_SIZE: usize = 64;
+const STRASSEN_MIN_DIM: usize = 64;
+
+impl<T: Scalar> Matrix<T> {
+    pub fn add(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+
         let new_values: Vec<T> = self
             .values
             .iter()
             .zip(&matrix_b.values)
-            .map(|(a, b)| *a - *b)
+            .map(|(a, b)| a.clone() + b.clone())
             .collect();
 
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(self.values.len(), 3 * self.values.len());
+
         Ok(Matrix::new(self.rows, self.cols, new_values))
     }
 
-    pub fn subtract_mut(&mut self, matrix_b: &Matrix<T>) -> Result<&mut Self, MatrixError> {
-        if self.rows != matrix_b.rows || self.cols != matrix_b.cols {
-            return Err(MatrixError::DimensionMismatch(format!(
-                "Cannot subtract matricies of dimensions {}x{} and {}x{}",
-                self.rows, self.cols, matrix_b.rows, matrix_b.cols
-            )));
+    pub fn subtract(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Subtract)?;
+
+        let new_values: Vec<T> = self
+            .values
+            .iter()
+            .zip(&matrix_b.values)
+            .map(|(a, b)| a.clone() - b.clone())
+            .collect();
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(self.values.len(), 3 * self.values.len());
+
+        Ok(Matrix::new(self.rows, self.cols, new_values))
+    }
+
+    fn check_output(&self, out: &Matrix<T>, rows: usize, cols: usize) -> Result<(), MatrixError> {
+        if out.rows != rows || out.cols != cols {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Output,
+                lhs: (out.rows, out.cols),
+                rhs: (rows, cols),
+            });
         }
+        Ok(())
+    }
 
-        for i in 0..self.values.len() {
-            self.values[i] = self.values[i] - matrix_b.values[i];
+    /// [`Matrix::add`] into an existing `out` of the same shape, without
+    /// allocating.
+    pub fn add_into(&self, matrix_b: &Matrix<T>, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+        self.check_output(out, self.rows, self.cols)?;
+        for ((c, a), b) in out
+            .values
+            .iter_mut()
+            .zip(&self.values)
+            .zip(&matrix_b.values)
+        {
+            *c = a.clone() + b.clone();
         }
 
-        Ok(self)
-    }
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(self.values.len(), 3 * self.values.len());
 
-    pub fn transpose(&self) -> Matrix<T> {
-        let mut new_values: Vec<T> = vec![T::default(); self.rows * self.cols];
+        Ok(())
+    }
 
-        for i in 0..self.cols {
-            for j in 0..self.rows {
-                new_values[i * self.rows + j] = self.values[j * self.cols + i];
-            }
+    pub fn subtract_into(
+        &self,
+        matrix_b: &Matrix<T>,
+        out: &mut Matrix<T>,
+    ) -> Result<(), MatrixError> {
+        self.check_same_shape(matrix_b, Op::Subtract)?;
+        self.check_output(out, self.rows, self.cols)?;
+        for ((c, a), b) in out
+            .values
+            .iter_mut()
+            .zip(&self.values)
+            .zip(&matrix_b.values)
+        {
+            *c = a.clone() - b.clone();
         }
 
-        Matrix::new(self.cols, self.rows, new_values)
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(self.values.len(), 3 * self.values.len());
+
+        Ok(())
     }
 
-    pub fn transpose_mut(&mut self) -> &mut Self {
-        let mut new_values = vec![T::default(); self.rows * self.cols];
-        for i in 0..self.cols {
-            for j in 0..self.rows {
-                new_values[i * self.rows + j] = self.values[j * self.cols + i];
+    pub fn transpose_into(&self, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        self.check_output(out, self.cols, self.rows)?;
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                out.values[j * self.rows + i] = self.values[i * self.cols + j].clone();
             }
         }
-        std::mem::swap(&mut self.values, &mut new_values);
-        std::mem::swap(&mut self.rows, &mut self.cols);
+        Ok(())
+    }
 
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.cols, self.rows, |i, j| {
+            self.values[j * self.cols + i].clone()
+        })
+    }
+
+    pub fn transpose_mut(&mut self) -> &mut Self {
+        *self = self.transpose();
         self
     }
 
     pub fn mult_naive(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
-        if self.cols != matrix_b.rows {
-            return Err(MatrixError::DimensionMismatch(format!(
-                "Cannot multiply  matricies of dimensions {}x{} and {}x{}",
-                self.rows, self.cols, matrix_b.rows, matrix_b.cols
-            )));
-        }
-
-        let bt = matrix_b.transpose();
+        self.mult_semiring::<Standard>(matrix_b)
+    }
 
-        let mut new_values: Vec<T> = vec![T::default(); self.rows * matrix_b.cols];
+    /// Places `matrix_b` to the right of `self`. A `0`x`0` operand is
+    /// neutral, so stacking onto an empty accumulator needs no special case.
+    pub fn hstack(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows == 0 && self.cols == 0 {
+            return Ok(matrix_b.clone());
+        }
+        if matrix_b.rows == 0 && matrix_b.cols == 0 {
+            return Ok(self.clone());
+        }
+        if self.rows != matrix_b.rows {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::HorizontalStack,
+                lhs: (self.rows, self.cols),
+                rhs: (matrix_b.rows, matrix_b.cols),
+            });
+        }
 
+        let cols = self.cols.checked_add(matrix_b.cols).ok_or_else(|| {
+            MatrixError::SizeOverflow("Stacked column count overflows usize".to_string())
+        })?;
+        let mut new_values: Vec<T> = Vec::with_capacity(checked_len(self.rows, cols)?);
         for i in 0..self.rows {
-            for j in 0..bt.rows {
-                let mut sum: T = Default::default();
-                for k in 0..self.cols {
-                    sum += self.values[i * self.cols + k] * bt.values[j * bt.cols + k];
-                }
+            new_values.extend_from_slice(&self.values[i * self.cols..(i + 1) * self.cols]);
+            new_values
+                .extend_from_slice(&matrix_b.values[i * matrix_b.cols..(i + 1) * matrix_b.cols]);
+        }
 
-                new_values[i * matrix_b.cols + j] = sum;
-            }
+        Ok(Matrix::new(self.rows, cols, new_values))
+    }
+
+    /// Places `matrix_b` below `self`. A `0`x`0` operand is neutral.
+    pub fn vstack(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows == 0 && self.cols == 0 {
+            return Ok(matrix_b.clone());
+        }
+        if matrix_b.rows == 0 && matrix_b.cols == 0 {
+            return Ok(self.clone());
         }
+        if self.cols != matrix_b.cols {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::VerticalStack,
+                lhs: (self.rows, self.cols),
+                rhs: (matrix_b.rows, matrix_b.cols),
+            });
+        }
+
+        let rows = self.rows.checked_add(matrix_b.rows).ok_or_else(|| {
+            MatrixError::SizeOverflow("Stacked row count overflows usize".to_string())
+        })?;
+        let mut new_values: Vec<T> = Vec::with_capacity(checked_len(rows, self.cols)?);
+        new_values.extend_from_slice(&self.values);
+        new_values.extend_from_slice(&matrix_b.values);
 
-        Ok(Matrix::new(self.rows, matrix_b.cols, new_values))
+        Ok(Matrix::new(rows, self.cols, new_values))
     }
 
-    pub fn mult_scalar(&mut self, num: T) -> &mut Self {
-        for value in &mut self.values {
-            *value = *value * num;
+    pub fn from_scalar(value: T) -> Matrix<T> {
+        Matrix::new(1, 1, vec![value])
+    }
+
+    /// The single element of a `1`x`1` matrix.
+    pub fn to_scalar(&self) -> Result<T, MatrixError> {
+        if self.rows != 1 || self.cols != 1 {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Convert,
+                lhs: (self.rows, self.cols),
+                rhs: (1, 1),
+            });
         }
+        Ok(self.values[0].clone())
+    }
 
-        self
+    pub fn identity(order: usize) -> Result<Matrix<T>, MatrixError> {
+        checked_len(order, order)?;
+        Ok(Matrix::identity_within(order))
     }
 
-    pub fn identity(order: usize) -> Matrix<T> {
+    /// The identity for an `order` already known to be small enough, because
+    /// the caller holds a matrix with at least `order * order` elements.
+    pub(crate) fn identity_within(order: usize) -> Matrix<T> {
         let mut values: Vec<T> = vec![T::zero(); order * order];
 
         for i in 0..order {
@@ -210,9 +538,240 @@ where
     }
 }
 
+/// The products, which multiply elements by reference so their inner loops
+/// clone neither operand.
+impl<T: Scalar> Matrix<T> {
+    /// The product `self * matrix_b` written into an existing
+    /// `self.rows`x`matrix_b.cols` matrix, without allocating.
+    pub fn mult_into(&self, matrix_b: &Matrix<T>, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+        self.check_output(out, self.rows, matrix_b.cols)?;
+
+        let p = matrix_b.cols;
+        for i in 0..self.rows {
+            let row = &mut out.values[i * p..(i + 1) * p];
+            for c in row.iter_mut() {
+                *c = T::zero();
+            }
+            for k in 0..self.cols {
+                let a = &self.values[i * self.cols + k];
+                for (c, b) in row.iter_mut().zip(&matrix_b.values[k * p..(k + 1) * p]) {
+                    *c += a.mul_ref(b);
+                }
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(
+            2 * self.rows * self.cols * p,
+            self.values.len() + matrix_b.values.len() + out.values.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Multiplies tile by tile so that a `block_size` square of each operand
+    /// stays in cache. Within a tile each `self` element is held while the
+    /// matching row of `matrix_b` is streamed into the output row, which
+    /// compilers vectorize well.
+    pub fn mult_blocked(
+        &self,
+        matrix_b: &Matrix<T>,
+        block_size: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+        if block_size == 0 {
+            return Err(MatrixError::InvalidArgument(
+                "Block size must be positive".to_string(),
+            ));
+        }
+
+        let (m, n, p) = (self.rows, self.cols, matrix_b.cols);
+        let mut new_values: Vec<T> = vec![T::zero(); checked_len(m, p)?];
+
+        for i0 in (0..m).step_by(block_size) {
+            for k0 in (0..n).step_by(block_size) {
+                for j0 in (0..p).step_by(block_size) {
+                    let j1 = (j0 + block_size).min(p);
+                    for i in i0..(i0 + block_size).min(m) {
+                        let out = &mut new_values[i * p + j0..i * p + j1];
+                        for k in k0..(k0 + block_size).min(n) {
+                            let a = &self.values[i * n + k];
+                            let b_row = &matrix_b.values[k * p + j0..k * p + j1];
+                            for (c, b) in out.iter_mut().zip(b_row) {
+                                *c += a.mul_ref(b);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(
+            2 * m * n * p,
+            self.values.len() + matrix_b.values.len() + new_values.len(),
+        );
+
+        Ok(Matrix::new(m, p, new_values))
+    }
+
+    /// Multiplies with [`Matrix::mult_blocked`] once every dimension reaches
+    /// `BLOCKED_MIN_DIM`, and with [`Matrix::mult_naive`] otherwise, where
+    /// tiling costs more than it saves.
+    pub fn mult(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows.min(self.cols).min(matrix_b.cols) >= BLOCKED_MIN_DIM {
+            self.mult_blocked(matrix_b, DEFAULT_BLOCK_SIZE)
+        } else {
+            self.mult_naive(matrix_b)
+        }
+    }
+
+    /// Strassen's algorithm: each level splits both operands into quadrants
+    /// and forms the product from seven quadrant products instead of eight.
+    /// An odd dimension is padded with a zero row or column, and the
+    /// recursion hands over to [`Matrix::mult_blocked`] once a dimension
+    /// falls to `STRASSEN_MIN_DIM`. The extra additions cost some accuracy
+    /// on floats; on exact elements the result equals [`Matrix::mult_naive`].
+    pub fn mult_strassen(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+        if self.rows.min(self.cols).min(matrix_b.cols) <= STRASSEN_MIN_DIM {
+            return self.mult_blocked(matrix_b, DEFAULT_BLOCK_SIZE);
+        }
+
+        let (m, p) = (self.rows, matrix_b.cols);
+        let (half_m, half_n, half_p) = (m.div_ceil(2), self.cols.div_ceil(2), p.div_ceil(2));
+        let [a11, a12, a21, a22] = self.quadrants(half_m, half_n);
+        let [b11, b12, b21, b22] = matrix_b.quadrants(half_n, half_p);
+
+        let m1 = a11.add(&a22)?.mult_strassen(&b11.add(&b22)?)?;
+        let m2 = a21.add(&a22)?.mult_strassen(&b11)?;
+        let m3 = a11.mult_strassen(&b12.subtract(&b22)?)?;
+        let m4 = a22.mult_strassen(&b21.subtract(&b11)?)?;
+        let m5 = a11.add(&a12)?.mult_strassen(&b22)?;
+        let m6 = a21.subtract(&a11)?.mult_strassen(&b11.add(&b12)?)?;
+        let m7 = a12.subtract(&a22)?.mult_strassen(&b21.add(&b22)?)?;
+
+        let c11 = m1.add(&m4)?.subtract(&m5)?.add(&m7)?;
+        let c12 = m3.add(&m5)?;
+        let c21 = m2.add(&m4)?;
+        let c22 = m1.subtract(&m2)?.add(&m3)?.add(&m6)?;
+
+        let mut new_values = Vec::with_capacity(checked_len(m, p)?);
+        for i in 0..m {
+            let (left, right, row) = if i < half_m {
+                (&c11, &c12, i)
+            } else {
+                (&c21, &c22, i - half_m)
+            };
+            new_values.extend_from_slice(&left.values[row * half_p..(row + 1) * half_p]);
+            new_values.extend_from_slice(&right.values[row * half_p..row * half_p + p - half_p]);
+        }
+        Ok(Matrix::new(m, p, new_values))
+    }
+
+    /// The four `rows`x`cols` quadrants of `self`, in row-major order,
+    /// padded with zeros where they run past its edge.
+    fn quadrants(&self, rows: usize, cols: usize) -> [Matrix<T>; 4] {
+        [(0, 0), (0, cols), (rows, 0), (rows, cols)].map(|(row0, col0)| {
+            Matrix::from_fn(rows, cols, |i, j| {
+                let (i, j) = (row0 + i, col0 + j);
+                if i < self.rows && j < self.cols {
+                    self.values[i * self.cols + j].clone()
+                } else {
+                    T::zero()
+                }
+            })
+        })
+    }
+
+    pub fn kronecker(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let overflow = || {
+            MatrixError::SizeOverflow(format!(
+                "Kronecker product of {}x{} and {}x{} matrices is too large",
+                self.rows, self.cols, matrix_b.rows, matrix_b.cols
+            ))
+        };
+        let rows = self.rows.checked_mul(matrix_b.rows).ok_or_else(overflow)?;
+        let cols = self.cols.checked_mul(matrix_b.cols).ok_or_else(overflow)?;
+        let len = checked_len(rows, cols)?;
+
+        let mut new_values: Vec<T> = Vec::with_capacity(len);
+
+        for i in 0..self.rows {
+            for k in 0..matrix_b.rows {
+                for j in 0..self.cols {
+                    let a = &self.values[i * self.cols + j];
+                    for l in 0..matrix_b.cols {
+                        new_values.push(a.mul_ref(&matrix_b.values[k * matrix_b.cols + l]));
+                    }
+                }
+            }
+        }
+
+        Ok(Matrix::new(rows, cols, new_values))
+    }
+}
+
+impl<T: Scalar + Conjugate> Matrix<T> {
+    pub fn conjugate(&self) -> Matrix<T> {
+        let new_values: Vec<T> = self.values.iter().map(|a| a.conjugate()).collect();
+
+        Matrix::new(self.rows, self.cols, new_values)
+    }
+
+    pub fn hermitian_transpose(&self) -> Matrix<T> {
+        Matrix::from_fn(self.cols, self.rows, |i, j| {
+            self.values[j * self.cols + i].conjugate()
+        })
+    }
+
+    pub fn h(&self) -> Matrix<T> {
+        self.hermitian_transpose()
+    }
+
+    pub fn is_hermitian(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        if self.rows != self.cols {
+            return false;
+        }
+
+        for i in 0..self.rows {
+            for j in i..self.cols {
+                if self.values[i * self.cols + j] != self.values[j * self.cols + i].conjugate() {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::complex::Complex;
+    use crate::error::MatrixError;
+    use crate::identity_element::IdentityElement;
     use crate::matrix::Matrix;
+    use std::ops::{Add, AddAssign, Mul, Sub};
 
     #[test]
     fn check_indexing() {
@@ -269,18 +828,136 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn check_into_variants() {
+        let matrix_a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+        let matrix_b: Matrix<i32> = Matrix::new(3, 2, vec![7, 8, 9, 10, 11, 12]);
+        let mut out: Matrix<i32> = Matrix::new(2, 2, vec![-1; 4]);
+
+        matrix_a.mult_into(&matrix_b, &mut out).unwrap();
+        assert_eq!(out.values, vec![58, 64, 139, 154]);
+
+        let mut sum = Matrix::new(2, 3, vec![0; 6]);
+        matrix_a.add_into(&matrix_a, &mut sum).unwrap();
+        assert_eq!(sum.values, vec![2, 4, 6, 8, 10, 12]);
+        matrix_a.subtract_into(&matrix_a, &mut sum).unwrap();
+        assert_eq!(sum.values, vec![0; 6]);
+
+        let mut transposed = Matrix::new(3, 2, vec![0; 6]);
+        matrix_a.transpose_into(&mut transposed).unwrap();
+        assert_eq!(transposed, matrix_a.transpose());
+        assert!(matrix_a.transpose_into(&mut sum).is_err());
+        assert!(matrix_a.mult_into(&matrix_b, &mut sum).is_err());
+    }
+
     #[test]
     fn check_naive() {
         let matrix_a: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
-        let mut matrix_b: Matrix<i32> = Matrix::new(3, 2, vec![7, 8, 9, 10, 11, 12]);
+        let matrix_b: Matrix<i32> = Matrix::new(3, 2, vec![7, 8, 9, 10, 11, 12]);
         let expected_result: Vec<i32> = vec![58, 64, 139, 154];
 
-        match matrix_a.mult_naive(&mut matrix_b) {
+        match matrix_a.mult_naive(&matrix_b) {
             Ok(matrix_c) => assert_eq!(matrix_c.values, expected_result),
             Err(e) => panic!("{}", e),
         }
     }
 
+    #[test]
+    fn check_blocked() {
+        let matrix_a: Matrix<i64> = Matrix::new(130, 150, (0..19500).map(|v| v % 7 - 3).collect());
+        let matrix_b: Matrix<i64> = Matrix::new(150, 140, (0..21000).map(|v| v % 5 - 2).collect());
+        let expected = matrix_a.mult_naive(&matrix_b).unwrap();
+
+        for block_size in [1, 16, 64, 1000] {
+            assert_eq!(
+                matrix_a.mult_blocked(&matrix_b, block_size).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(matrix_a.mult(&matrix_b).unwrap(), expected);
+        assert!(matrix_a.mult_blocked(&matrix_b, 0).is_err());
+        assert!(matrix_a.mult_blocked(&matrix_a, 16).is_err());
+    }
+
+    #[test]
+    fn check_strassen() {
+        // Odd sizes above the cutoff, so the padding is exercised.
+        let matrix_a: Matrix<i64> =
+            Matrix::from_fn(151, 140, |i, j| (i * 7 + j * 3) as i64 % 11 - 5);
+        let matrix_b: Matrix<i64> = Matrix::from_fn(140, 133, |i, j| (i * 5 + j) as i64 % 13 - 6);
+        assert_eq!(
+            matrix_a.mult_strassen(&matrix_b).unwrap(),
+            matrix_a.mult_naive(&matrix_b).unwrap()
+        );
+
+        let small: Matrix<i64> = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        assert_eq!(
+            small.mult_strassen(&small).unwrap(),
+            Matrix::new(2, 2, vec![7, 10, 15, 22])
+        );
+        assert!(matrix_a.mult_strassen(&matrix_a).is_err());
+    }
+
+    #[test]
+    fn check_empty_shapes() {
+        let tall: Matrix<i32> = Matrix::new(3, 0, vec![]);
+        let wide: Matrix<i32> = Matrix::new(0, 2, vec![]);
+
+        assert!(tall.is_empty() && wide.is_empty());
+        assert!(!Matrix::new(1, 1, vec![0]).is_empty());
+        assert_eq!(tall.add(&tall).unwrap(), tall);
+        assert_eq!(tall.transpose(), Matrix::new(0, 3, vec![]));
+        assert_eq!(
+            tall.mult_naive(&wide).unwrap(),
+            Matrix::new(3, 2, vec![0; 6])
+        );
+        assert_eq!(
+            tall.mult_blocked(&wide, 4).unwrap(),
+            Matrix::new(3, 2, vec![0; 6])
+        );
+        assert_eq!(
+            wide.mult_naive(&wide.transpose()).unwrap(),
+            Matrix::new(0, 0, vec![])
+        );
+        assert_eq!(tall.kronecker(&wide).unwrap(), Matrix::new(0, 0, vec![]));
+        assert!(tall.add(&wide).is_err());
+    }
+
+    #[test]
+    fn check_reshape() {
+        let mut matrix: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        matrix.set_rows(3).unwrap();
+        assert_eq!((matrix.get_rows(), matrix.get_cols()), (3, 2));
+        matrix.set_cols(6).unwrap();
+        assert_eq!((matrix.get_rows(), matrix.get_cols()), (1, 6));
+        assert!(matrix.set_rows(4).is_err());
+        assert!(matrix.reshape(2, 2).is_err());
+        assert!(matrix.reshape(usize::MAX, 2).is_err());
+        assert_eq!(matrix.reshape(6, 1).unwrap().get_values().len(), 6);
+
+        let huge: Matrix<i32> = Matrix::new(0, usize::MAX, vec![]);
+        assert!(matches!(
+            huge.hstack(&Matrix::new(0, 1, vec![])),
+            Err(MatrixError::SizeOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn check_scalar_matrices() {
+        let row: Matrix<i32> = Matrix::new(1, 3, vec![1, 2, 3]);
+        let dot = row.mult_naive(&row.transpose()).unwrap();
+
+        assert_eq!(dot.to_scalar().unwrap(), 14);
+        assert_eq!(Matrix::from_scalar(7).to_scalar().unwrap(), 7);
+        assert!(row.to_scalar().is_err());
+
+        let empty: Matrix<i32> = Matrix::new(0, 0, vec![]);
+        assert_eq!(empty.vstack(&row).unwrap(), row);
+        assert_eq!(row.hstack(&empty).unwrap(), row);
+        assert!(Matrix::new(0, 2, vec![]).vstack(&row).is_err());
+    }
+
     #[test]
     fn check_scalar_multiplication() {
         let mut matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, -2, 3, 4]);
@@ -294,10 +971,10 @@ mod tests {
 
     #[test]
     fn check_identity() {
-        let identity_matrix_2x2: Matrix<f64> = Matrix::identity(2);
+        let identity_matrix_2x2: Matrix<f64> = Matrix::identity(2).unwrap();
         let expected_result_2x2: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1.0]);
 
-        let identity_matrix_10x10: Matrix<u16> = Matrix::identity(10);
+        let identity_matrix_10x10: Matrix<u16> = Matrix::identity(10).unwrap();
         let values: Vec<u16> = vec![
             1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0,
@@ -307,6 +984,182 @@ mod tests {
         let expected_result_10x10: Matrix<u16> = Matrix::new(10, 10, values);
 
         assert_eq!(identity_matrix_2x2, expected_result_2x2);
-        assert_eq!(identity_matrix_10x10, expected_result_10x10)
+        assert_eq!(identity_matrix_10x10, expected_result_10x10);
+        assert!(Matrix::<f64>::identity(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn check_hermitian_transpose() {
+        let matrix: Matrix<Complex<f64>> = Matrix::new(
+            2,
+            2,
+            vec![
+                Complex::new(1.0, 0.0),
+                Complex::new(2.0, 3.0),
+                Complex::new(2.0, -3.0),
+                Complex::new(4.0, 0.0),
+            ],
+        );
+        let rectangular: Matrix<Complex<f64>> =
+            Matrix::new(1, 2, vec![Complex::new(1.0, 1.0), Complex::new(0.0, -2.0)]);
+        let expected_result: Matrix<Complex<f64>> =
+            Matrix::new(2, 1, vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.0)]);
+
+        assert!(matrix.is_hermitian());
+        assert_eq!(matrix.h(), matrix);
+        assert!(!rectangular.is_hermitian());
+        assert_eq!(rectangular.hermitian_transpose(), expected_result);
+        assert_eq!(
+            rectangular.conjugate().get_values(),
+            &vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn check_size_overflow() {
+        let huge = usize::MAX / 2;
+
+        assert!(matches!(
+            Matrix::<i32>::try_new(huge, 3, vec![]),
+            Err(MatrixError::SizeOverflow(_))
+        ));
+        assert!(Matrix::try_new(2, 2, vec![1, 2, 3]).is_err());
+
+        let tall: Matrix<i32> = Matrix::new(huge, 0, vec![]);
+        let wide: Matrix<i32> = Matrix::new(0, huge, vec![]);
+        assert!(matches!(
+            tall.mult_naive(&wide),
+            Err(MatrixError::SizeOverflow(_))
+        ));
+        assert!(matches!(
+            tall.kronecker(&tall),
+            Err(MatrixError::SizeOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn check_kronecker() {
+        let matrix_a: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        let matrix_b: Matrix<i32> = Matrix::new(1, 2, vec![0, 5]);
+        let expected_result: Matrix<i32> = Matrix::new(2, 4, vec![0, 5, 0, 10, 0, 15, 0, 20]);
+
+        assert_eq!(matrix_a.kronecker(&matrix_b).unwrap(), expected_result);
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Exact(Box<i64>);
+
+    impl Add for Exact {
+        type Output = Exact;
+        fn add(self, other: Exact) -> Exact {
+            Exact(Box::new(*self.0 + *other.0))
+        }
+    }
+
+    impl Sub for Exact {
+        type Output = Exact;
+        fn sub(self, other: Exact) -> Exact {
+            Exact(Box::new(*self.0 - *other.0))
+        }
+    }
+
+    impl Mul for Exact {
+        type Output = Exact;
+        fn mul(self, other: Exact) -> Exact {
+            Exact(Box::new(*self.0 * *other.0))
+        }
+    }
+
+    impl Mul<&Exact> for &Exact {
+        type Output = Exact;
+        fn mul(self, other: &Exact) -> Exact {
+            Exact(Box::new(*self.0 * *other.0))
+        }
+    }
+
+    impl AddAssign for Exact {
+        fn add_assign(&mut self, other: Exact) {
+            *self.0 += *other.0;
+        }
+    }
+
+    impl IdentityElement for Exact {
+        fn zero() -> Self {
+            Exact(Box::new(0))
+        }
+        fn one() -> Self {
+            Exact(Box::new(1))
+        }
+    }
+
+    #[test]
+    fn check_clone_only_elements() {
+        let exact = |values: &[i64]| values.iter().map(|v| Exact(Box::new(*v))).collect();
+        let mut matrix_a: Matrix<Exact> = Matrix::new(2, 2, exact(&[1, 2, 3, 4]));
+        let matrix_b: Matrix<Exact> = Matrix::new(2, 2, exact(&[5, 6, 7, 8]));
+
+        let product = matrix_a.mult_naive(&matrix_b).unwrap();
+        assert_eq!(product, Matrix::new(2, 2, exact(&[19, 22, 43, 50])));
+        assert_eq!(matrix_a.mult_blocked(&matrix_b, 1).unwrap(), product);
+
+        matrix_a.subtract_mut(&matrix_b).unwrap();
+        matrix_a.mult_scalar(Exact(Box::new(2)));
+        assert_eq!(matrix_a, Matrix::new(2, 2, exact(&[-8, -8, -8, -8])));
+    }
+
+    #[test]
+    fn check_try_map() {
+        let input: Matrix<&str> = Matrix::new(2, 2, vec!["1", "x", "3", "-"]);
+
+        let error = input.try_map(|s| s.parse::<i32>()).unwrap_err();
+        assert_eq!((error.row, error.col), (0, 1));
+        let errors = input.try_map_all(|s| s.parse::<i32>()).unwrap_err();
+        let positions: Vec<(usize, usize)> = errors.iter().map(|e| (e.row, e.col)).collect();
+        assert_eq!(positions, vec![(0, 1), (1, 1)]);
+
+        let valid: Matrix<&str> = Matrix::new(1, 2, vec!["4", "5"]);
+        assert_eq!(
+            valid.try_map(|s| s.parse::<i32>()).unwrap(),
+            Matrix::new(1, 2, vec![4, 5])
+        );
+        assert_eq!(valid.map(|s| s.len()), Matrix::new(1, 2, vec![1, 1]));
+    }
+
+    #[test]
+    fn check_row_operations() {
+        let mut matrix: Matrix<i32> = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        matrix.swap_rows(0, 2).unwrap();
+        matrix.scale_row(1, -1).unwrap();
+        matrix.add_scaled_row(0, 2, 2).unwrap();
+
+        assert_eq!(matrix, Matrix::new(3, 2, vec![5, 6, -3, -4, 11, 14]));
+        assert!(matches!(
+            matrix.swap_rows(0, 3),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (3, 0),
+                dims: (3, 2)
+            })
+        ));
+        assert!(matrix.add_scaled_row(3, 0, 1).is_err());
+    }
+
+    #[test]
+    fn check_stacking() {
+        let matrix_a: Matrix<i32> = Matrix::new(2, 1, vec![1, 2]);
+        let matrix_b: Matrix<i32> = Matrix::new(2, 2, vec![3, 4, 5, 6]);
+
+        assert_eq!(
+            matrix_a.hstack(&matrix_b).unwrap(),
+            Matrix::new(2, 3, vec![1, 3, 4, 2, 5, 6])
+        );
+        assert_eq!(
+            matrix_b.vstack(&matrix_b.transpose()).unwrap(),
+            Matrix::new(4, 2, vec![3, 4, 5, 6, 3, 5, 4, 6])
+        );
+        assert!(matrix_a.vstack(&matrix_b).is_err());
+        assert!(matrix_a
+            .hstack(&matrix_b.transpose().vstack(&matrix_b).unwrap())
+            .is_err());
     }
 }
@@ -0,0 +1,166 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use crate::pod::Pod;
+use crate::storage::Storage;
+use std::fs::File;
+use std::marker::PhantomData;
+use std::os::raw::{c_int, c_void};
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+
+extern "C" {
+    fn mmap(
+        addr: *mut c_void,
+        len: usize,
+        prot: c_int,
+        flags: c_int,
+        fd: c_int,
+        offset: i64,
+    ) -> *mut c_void;
+    fn munmap(addr: *mut c_void, len: usize) -> c_int;
+}
+
+const PROT_READ: c_int = 1;
+const MAP_PRIVATE: c_int = 2;
+
+/// A read-only memory-mapped file used as [`Storage`], so a matrix larger
+/// than RAM is paged in on demand by the operating system.
+///
+/// Opening one is `unsafe`: see [`Matrix::open_mmap`].
+#[derive(Debug)]
+pub struct Mmap<T> {
+    ptr: *const T,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+// SAFETY: the mapping is read-only and owned by this value, so sharing or
+// moving it between threads is as safe as for a `Box<[T]>`.
+unsafe impl<T: Sync> Send for Mmap<T> {}
+unsafe impl<T: Sync> Sync for Mmap<T> {}
+
+impl<T: Pod> Mmap<T> {
+    /// # Safety
+    ///
+    /// As for [`Matrix::open_mmap`].
+    unsafe fn open(path: &Path, len: usize) -> Result<Mmap<T>, MatrixError> {
+        let file = File::open(path).map_err(MatrixError::Io)?;
+        let bytes = len.checked_mul(std::mem::size_of::<T>()).ok_or_else(|| {
+            MatrixError::SizeOverflow(format!("{} elements overflow a byte count", len))
+        })?;
+        let file_len = file.metadata().map_err(MatrixError::Io)?.len();
+        if file_len != bytes as u64 {
+            return Err(MatrixError::LengthMismatch {
+                expected: bytes,
+                actual: file_len as usize,
+            });
+        }
+        if bytes == 0 {
+            return Ok(Mmap {
+                ptr: std::ptr::NonNull::dangling().as_ptr(),
+                len: 0,
+                marker: PhantomData,
+            });
+        }
+
+        // SAFETY: a fresh private, read-only mapping of an open file; the
+        // result is checked against MAP_FAILED before use.
+        let ptr = unsafe {
+            mmap(
+                std::ptr::null_mut(),
+                bytes,
+                PROT_READ,
+                MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr as isize == -1 {
+            return Err(MatrixError::Io(std::io::Error::last_os_error()));
+        }
+        Ok(Mmap {
+            ptr: ptr as *const T,
+            len,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<T> Drop for Mmap<T> {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            // SAFETY: `ptr` came from a successful `mmap` of exactly this many
+            // bytes and is unmapped only here.
+            unsafe {
+                munmap(self.ptr as *mut c_void, self.len * std::mem::size_of::<T>());
+            }
+        }
+    }
+}
+
+impl<T> Storage<T> for Mmap<T> {
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: the mapping is page aligned, holds `len` elements of a
+        // `Pod` type (any bit pattern is valid) and lives as long as `self`.
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<T: Pod> Matrix<T, Mmap<T>> {
+    /// Maps a file of exactly `rows * cols` raw row-major elements in native
+    /// byte order, as written by [`Matrix::as_bytes`]. Views and the
+    /// [`MatrixExpr`](crate::expr::MatrixExpr) products work directly on the
+    /// mapping, e.g. `mapped.view(0..n, 0..m)?.mult(&in_memory).eval()`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated, by this or any other
+    /// process, while the mapping lives. A write would change memory that
+    /// Rust treats as immutable, and reading pages past a truncated end
+    /// raises `SIGBUS`; both are undefined behavior.
+    pub unsafe fn open_mmap<P: AsRef<Path>>(
+        path: P,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T, Mmap<T>>, MatrixError> {
+        // SAFETY: the caller upholds the same contract for the file.
+        let storage = unsafe { Mmap::open(path.as_ref(), checked_len(rows, cols)?)? };
+        Matrix::from_storage(rows, cols, storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expr::MatrixExpr;
+    use crate::matrix::Matrix;
+    use crate::mmap::Mmap;
+
+    #[test]
+    fn check_open_mmap() {
+        let path = std::env::temp_dir().join(format!("linrust-mmap-{}.bin", std::process::id()));
+        let stored: Matrix<f64> = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        std::fs::write(&path, stored.as_bytes()).unwrap();
+
+        // SAFETY: the file is private to this test and outlives the maps.
+        let mapped: Matrix<f64, Mmap<f64>> = unsafe { Matrix::open_mmap(&path, 3, 2) }.unwrap();
+        assert_eq!(mapped.value_at(2, 1).unwrap(), &6.0);
+        assert_eq!(mapped.to_owned_matrix(), stored);
+
+        let weights: Matrix<f64> = Matrix::new(2, 1, vec![1.0, -1.0]);
+        let product = mapped
+            .view(1..3, 0..2)
+            .unwrap()
+            .mult(&weights)
+            .eval()
+            .unwrap();
+        assert_eq!(product.get_values(), &vec![-1.0, -1.0]);
+
+        unsafe {
+            assert!(Matrix::<f64, Mmap<f64>>::open_mmap(&path, 2, 2).is_err());
+            assert!(Matrix::<f32, Mmap<f32>>::open_mmap(&path, 3, 4).is_ok());
+        }
+        drop(mapped);
+        std::fs::remove_file(&path).unwrap();
+        assert!(unsafe { Matrix::<f64, Mmap<f64>>::open_mmap(&path, 3, 2) }.is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// The vector norm [`Matrix::normalize_rows`] scales each row to one in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    /// Sum of absolute values.
+    L1,
+    /// Euclidean length.
+    L2,
+    /// Largest absolute value.
+    Max,
+}
+
+impl Norm {
+    fn of<T: Float>(self, values: &[T]) -> T {
+        match self {
+            Norm::L1 => values.iter().fold(T::zero(), |acc, &v| acc + v.abs()),
+            Norm::L2 => values.iter().fold(T::zero(), |acc, &v| acc + v * v).sqrt(),
+            Norm::Max => values.iter().fold(
+                T::zero(),
+                |acc, &v| {
+                    if v.abs() > acc {
+                        v.abs()
+                    } else {
+                        acc
+                    }
+                },
+            ),
+        }
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Each row mapped to probabilities. The row maximum is subtracted
+    /// before exponentiating, so large inputs do not overflow.
+    pub fn softmax_rows(&self) -> Matrix<T> {
+        let mut result = self.clone();
+        result.apply_rows(|row| {
+            let Some(&first) = row.first() else {
+                return;
+            };
+            let max = row
+                .iter()
+                .fold(first, |acc, &v| if v > acc { v } else { acc });
+            let mut total = T::zero();
+            for v in row.iter_mut() {
+                *v = (*v - max).exp();
+                total += *v;
+            }
+            row.iter_mut().for_each(|v| *v = *v / total);
+        });
+        result
+    }
+
+    /// Each row divided by its `norm`. All-zero rows are left as they are.
+    pub fn normalize_rows(&self, norm: Norm) -> Matrix<T> {
+        let mut result = self.clone();
+        result.apply_rows(|row| {
+            let length = norm.of(row);
+            if length > T::zero() {
+                row.iter_mut().for_each(|v| *v = *v / length);
+            }
+        });
+        result
+    }
+
+    /// Each column shifted to zero mean and scaled to unit population
+    /// variance. Constant columns become zero rather than NaN.
+    pub fn standardize_cols(&self) -> Matrix<T> {
+        let mut result = self.clone();
+        let count = T::from_f64(self.rows as f64);
+        result.apply_cols(|col| {
+            let mean = col.iter().fold(T::zero(), |acc, &v| acc + v) / count;
+            let variance = col
+                .iter()
+                .fold(T::zero(), |acc, &v| acc + (v - mean) * (v - mean))
+                / count;
+            let deviation = variance.sqrt();
+            for v in col.iter_mut() {
+                *v = if deviation > T::zero() {
+                    (*v - mean) / deviation
+                } else {
+                    T::zero()
+                };
+            }
+        });
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::normalize::Norm;
+
+    #[test]
+    fn check_softmax_rows() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1000.0, 1000.0, 0.0, 2.0_f64.ln()]);
+        let softmax = matrix.softmax_rows();
+
+        assert_eq!(softmax.values[..2], [0.5, 0.5]);
+        assert!((softmax.values[2] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((softmax.values[3] - 2.0 / 3.0).abs() < 1e-12);
+
+        let empty: Matrix<f64> = Matrix::new(3, 0, vec![]);
+        assert_eq!(empty.softmax_rows(), empty);
+    }
+
+    #[test]
+    fn check_normalize_and_standardize() {
+        let matrix: Matrix<f64> = Matrix::new(3, 2, vec![3.0, 4.0, 0.0, 0.0, -1.0, 1.0]);
+
+        let unit = matrix.normalize_rows(Norm::L2);
+        assert_eq!(unit.values[..4], [0.6, 0.8, 0.0, 0.0]);
+        assert_eq!(matrix.normalize_rows(Norm::L1).values[4..], [-0.5, 0.5]);
+        assert_eq!(matrix.normalize_rows(Norm::Max).values[..2], [0.75, 1.0]);
+
+        let standard = matrix.standardize_cols();
+        for col in standard.map_cols(|col| col.to_vec()) {
+            let mean: f64 = col.iter().sum::<f64>() / 3.0;
+            let variance: f64 = col.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 3.0;
+            assert!(mean.abs() < 1e-12 && (variance - 1.0).abs() < 1e-12);
+        }
+        let constant: Matrix<f64> = Matrix::new(2, 1, vec![5.0, 5.0]);
+        assert_eq!(constant.standardize_cols().values, vec![0.0, 0.0]);
+    }
+}
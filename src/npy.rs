@@ -0,0 +1,230 @@
+use crate::dtype::DType;
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use std::io::{Read, Write};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+fn descr<T: DType>() -> String {
+    let byte_order = if T::SIZE == 1 { '|' } else { '<' };
+    format!("{}{}{}", byte_order, T::KIND, T::SIZE)
+}
+
+/// Returns the text following `'key':` in a NumPy header dictionary.
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, MatrixError> {
+    let pattern = format!("'{}':", key);
+    header
+        .find(&pattern)
+        .map(|start| header[start + pattern.len()..].trim_start())
+        .ok_or_else(|| MatrixError::ParseError(format!("npy header has no '{}' entry", key)))
+}
+
+fn parse_shape(header: &str) -> Result<(usize, usize), MatrixError> {
+    let value = header_value(header, "shape")?;
+    let not_a_tuple = || MatrixError::ParseError("npy shape is not a tuple".to_string());
+    if !value.starts_with('(') {
+        return Err(not_a_tuple());
+    }
+    let end = value.find(')').ok_or_else(not_a_tuple)?;
+
+    let dims = value
+        .get(1..end)
+        .ok_or_else(not_a_tuple)?
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| {
+            d.parse::<usize>()
+                .map_err(|_| MatrixError::ParseError(format!("Invalid npy dimension '{}'", d)))
+        })
+        .collect::<Result<Vec<usize>, MatrixError>>()?;
+
+    match dims[..] {
+        [cols] => Ok((1, cols)),
+        [rows, cols] => Ok((rows, cols)),
+        _ => Err(MatrixError::ParseError(format!(
+            "Cannot read a {}-dimensional npy array into a matrix",
+            dims.len()
+        ))),
+    }
+}
+
+/// Reads exactly `len` bytes, growing the buffer only as data arrives, so a
+/// corrupt length cannot force a huge allocation up front.
+fn read_bounded<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, MatrixError> {
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != len {
+        return Err(MatrixError::ParseError(format!(
+            "npy file ended after {} of {} expected bytes",
+            bytes.len(),
+            len
+        )));
+    }
+    Ok(bytes)
+}
+
+impl<T: DType> Matrix<T> {
+    /// Reads a NumPy `.npy` array of one or two dimensions whose dtype is
+    /// exactly `T`. A 1-D array becomes a single row, and Fortran-ordered
+    /// data is reordered to row-major.
+    pub fn from_npy<R: Read>(mut reader: R) -> Result<Matrix<T>, MatrixError> {
+        let mut preamble = [0u8; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err(MatrixError::ParseError(
+                "Missing npy magic string".to_string(),
+            ));
+        }
+
+        let header_len = match preamble[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            version => {
+                return Err(MatrixError::ParseError(format!(
+                    "Unsupported npy version {}.{}",
+                    version, preamble[7]
+                )))
+            }
+        };
+
+        let header = read_bounded(&mut reader, header_len)?;
+        let header = String::from_utf8_lossy(&header);
+
+        let found = header_value(&header, "descr")?
+            .trim_start_matches('\'')
+            .split('\'')
+            .next()
+            .unwrap_or("");
+        let expected = descr::<T>();
+        if found != expected && found.replace('=', "<") != expected {
+            return Err(MatrixError::ParseError(format!(
+                "npy dtype '{}' does not match the requested '{}'",
+                found, expected
+            )));
+        }
+
+        let fortran_order = header_value(&header, "fortran_order")?.starts_with("True");
+        let (rows, cols) = parse_shape(&header)?;
+
+        let len = checked_len(rows, cols)?;
+        let size = len.checked_mul(T::SIZE).ok_or_else(|| {
+            MatrixError::SizeOverflow(format!(
+                "A {}x{} npy array has more bytes than fit in usize",
+                rows, cols
+            ))
+        })?;
+        let data = read_bounded(&mut reader, size)?;
+        let elements: Vec<T> = data.chunks_exact(T::SIZE).map(T::read_le).collect();
+
+        let values = if fortran_order {
+            (0..len)
+                .map(|index| elements[(index % cols) * rows + index / cols])
+                .collect()
+        } else {
+            elements
+        };
+
+        Ok(Matrix::new(rows, cols, values))
+    }
+
+    /// Writes a version 1.0 `.npy` file in C order.
+    pub fn to_npy<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            descr::<T>(),
+            self.rows,
+            self.cols
+        );
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        header.push('\n');
+
+        let header_len = u16::try_from(header.len()).map_err(|_| {
+            MatrixError::SizeOverflow("npy header does not fit a version 1.0 file".to_string())
+        })?;
+
+        let mut bytes =
+            Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.values.len() * T::SIZE);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&header_len.to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for &value in &self.values {
+            value.write_le(&mut bytes);
+        }
+
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_npy_round_trip() {
+        let matrix: Matrix<f64> = Matrix::new(2, 3, vec![1.0, -2.5, 3.0, 4.0, 5.0, 1e-300]);
+        let mut buffer = Vec::new();
+        matrix.to_npy(&mut buffer).unwrap();
+
+        assert_eq!(&buffer[..8], b"\x93NUMPY\x01\x00");
+        let data_start = buffer.len() - 6 * 8;
+        assert_eq!(data_start % 64, 0);
+        let header = String::from_utf8_lossy(&buffer[10..data_start]);
+        assert!(header.ends_with(" \n"));
+        assert!(header.starts_with("{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }"));
+
+        assert_eq!(Matrix::<f64>::from_npy(buffer.as_slice()).unwrap(), matrix);
+        assert!(Matrix::<f32>::from_npy(buffer.as_slice()).is_err());
+        assert!(Matrix::<f64>::from_npy(&buffer[..data_start + 8]).is_err());
+    }
+
+    #[test]
+    fn check_npy_fortran_order() {
+        let header = "{'descr': '<i4', 'fortran_order': True, 'shape': (2, 3), }";
+        let mut buffer = b"\x93NUMPY\x01\x00".to_vec();
+        buffer.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buffer.extend_from_slice(header.as_bytes());
+        for value in [1i32, 4, 2, 5, 3, 6] {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+
+        assert_eq!(
+            Matrix::<i32>::from_npy(buffer.as_slice()).unwrap(),
+            Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[test]
+    fn check_npy_malformed_headers() {
+        let file = |header: &str| {
+            let mut buffer = b"\x93NUMPY\x01\x00".to_vec();
+            buffer.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            buffer.extend_from_slice(header.as_bytes());
+            buffer
+        };
+        for header in [
+            "{'descr': '<f8', 'fortran_order': False, 'shape': ), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': \u{e9}), }",
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (1, 2305843009213693952), }",
+        ] {
+            assert!(Matrix::<f64>::from_npy(file(header).as_slice()).is_err());
+        }
+
+        // A version 2.0 header claiming 4 GiB in a file of a few bytes.
+        let mut buffer = b"\x93NUMPY\x02\x00".to_vec();
+        buffer.extend_from_slice(&u32::MAX.to_le_bytes());
+        buffer.extend_from_slice(b"{'descr'");
+        assert!(Matrix::<f64>::from_npy(buffer.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::error::{Axis, MatrixError};
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// A plane rotation `G = [c s; -s c]` with `G [a; b] = [r; 0]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Givens<T> {
+    pub c: T,
+    pub s: T,
+    pub r: T,
+}
+
+/// A reflector `H = I - beta v v^T` with `H x = alpha e_1`. `vector[0]` is
+/// always one, so the rest of `vector` can be stored in the entries of `x`
+/// it zeroes, as packed QR factorizations do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Householder<T> {
+    pub vector: Vec<T>,
+    pub beta: T,
+    pub alpha: T,
+}
+
+/// The rotation zeroing `b` against `a`. Computed without overflow for
+/// large inputs; when `b` is already zero it is the identity.
+pub fn givens<T: Float>(a: T, b: T) -> Givens<T> {
+    let zero = T::zero();
+    if b == zero {
+        return Givens {
+            c: T::one(),
+            s: zero,
+            r: a,
+        };
+    }
+    if a.abs() >= b.abs() {
+        let t = b / a;
+        let u = (T::one() + t * t).sqrt();
+        let u = if a < zero { -u } else { u };
+        let c = T::one() / u;
+        Givens {
+            c,
+            s: c * t,
+            r: a * u,
+        }
+    } else {
+        let t = a / b;
+        let u = (T::one() + t * t).sqrt();
+        let u = if b < zero { -u } else { u };
+        let s = T::one() / u;
+        Givens {
+            c: s * t,
+            s,
+            r: b * u,
+        }
+    }
+}
+
+/// The reflector mapping `x` onto a multiple of the first axis, choosing
+/// the sign of `alpha` opposite to `x[0]` to avoid cancellation. A zero
+/// `x`, or one already on that axis, gives `beta = 0`, so `H = I`.
+pub fn householder<T: Float>(x: &[T]) -> Householder<T> {
+    let zero = T::zero();
+    let mut vector = vec![zero; x.len()];
+    if x.is_empty() {
+        return Householder {
+            vector,
+            beta: zero,
+            alpha: zero,
+        };
+    }
+    vector[0] = T::one();
+
+    let tail = x[1..].iter().fold(zero, |acc, &value| acc + value * value);
+    if tail == zero {
+        return Householder {
+            vector,
+            beta: zero,
+            alpha: x[0],
+        };
+    }
+    let norm = (x[0] * x[0] + tail).sqrt();
+    let alpha = if x[0] > zero { -norm } else { norm };
+    let head = x[0] - alpha;
+    for (value, &source) in vector[1..].iter_mut().zip(&x[1..]) {
+        *value = source / head;
+    }
+    let v_norm = vector.iter().fold(zero, |acc, &value| acc + value * value);
+    Householder {
+        vector,
+        beta: T::from_f64(2.0) / v_norm,
+        alpha,
+    }
+}
+
+impl<T: Float> Givens<T> {
+    /// `A <- G A` on rows `i` and `k`: row `i` becomes `c a_i + s a_k` and
+    /// row `k` becomes `c a_k - s a_i`.
+    pub fn apply_rows(
+        &self,
+        matrix: &mut Matrix<T>,
+        i: usize,
+        k: usize,
+    ) -> Result<(), MatrixError> {
+        matrix.check_row(i)?;
+        matrix.check_row(k)?;
+        if i == k {
+            return Err(MatrixError::InvalidArgument(format!(
+                "A rotation needs two distinct rows, got {} twice",
+                i
+            )));
+        }
+        let cols = matrix.cols;
+        for j in 0..cols {
+            let a = matrix.values[i * cols + j];
+            let b = matrix.values[k * cols + j];
+            matrix.values[i * cols + j] = self.c * a + self.s * b;
+            matrix.values[k * cols + j] = self.c * b - self.s * a;
+        }
+        Ok(())
+    }
+
+    /// `A <- A G^T` on columns `i` and `k`, so following
+    /// [`Givens::apply_rows`] with it is a similarity transform.
+    pub fn apply_cols(
+        &self,
+        matrix: &mut Matrix<T>,
+        i: usize,
+        k: usize,
+    ) -> Result<(), MatrixError> {
+        matrix.check_col(i)?;
+        matrix.check_col(k)?;
+        if i == k {
+            return Err(MatrixError::InvalidArgument(format!(
+                "A rotation needs two distinct columns, got {} twice",
+                i
+            )));
+        }
+        let cols = matrix.cols;
+        for row in 0..matrix.rows {
+            let a = matrix.values[row * cols + i];
+            let b = matrix.values[row * cols + k];
+            matrix.values[row * cols + i] = self.c * a + self.s * b;
+            matrix.values[row * cols + k] = self.c * b - self.s * a;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Float> Householder<T> {
+    fn check_span(
+        &self,
+        start: usize,
+        dims: (usize, usize),
+        axis: Axis,
+    ) -> Result<(), MatrixError> {
+        let len = match axis {
+            Axis::Rows => dims.0,
+            Axis::Cols => dims.1,
+        };
+        let end = start.checked_add(self.vector.len());
+        if end.is_none_or(|end| end > len) {
+            let end = end.unwrap_or(usize::MAX);
+            let (rows, cols) = match axis {
+                Axis::Rows => (start..end, 0..dims.1),
+                Axis::Cols => (0..dims.0, start..end),
+            };
+            return Err(MatrixError::RangeOutOfBounds { rows, cols, dims });
+        }
+        Ok(())
+    }
+
+    /// `x <- H x`.
+    pub fn apply_vector(&self, x: &mut [T]) -> Result<(), MatrixError> {
+        if x.len() != self.vector.len() {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.vector.len(),
+                actual: x.len(),
+            });
+        }
+        let dot = self
+            .vector
+            .iter()
+            .zip(x.iter())
+            .fold(T::zero(), |acc, (&v, &value)| acc + v * value);
+        let scale = self.beta * dot;
+        for (value, &v) in x.iter_mut().zip(&self.vector) {
+            *value = *value - scale * v;
+        }
+        Ok(())
+    }
+
+    /// `A <- H A`, with `H` acting on the rows from `start` on.
+    pub fn apply_rows(&self, matrix: &mut Matrix<T>, start: usize) -> Result<(), MatrixError> {
+        self.check_span(start, (matrix.rows, matrix.cols), Axis::Rows)?;
+        self.reflect_rows(matrix, start);
+        Ok(())
+    }
+
+    fn reflect_rows(&self, matrix: &mut Matrix<T>, start: usize) {
+        let cols = matrix.cols;
+        for j in 0..cols {
+            let dot = self
+                .vector
+                .iter()
+                .enumerate()
+                .fold(T::zero(), |acc, (i, &v)| {
+                    acc + v * matrix.values[(start + i) * cols + j]
+                });
+            let scale = self.beta * dot;
+            for (i, &v) in self.vector.iter().enumerate() {
+                let index = (start + i) * cols + j;
+                matrix.values[index] = matrix.values[index] - scale * v;
+            }
+        }
+    }
+
+    /// `A <- A H`, with `H` acting on the columns from `start` on.
+    pub fn apply_cols(&self, matrix: &mut Matrix<T>, start: usize) -> Result<(), MatrixError> {
+        self.check_span(start, (matrix.rows, matrix.cols), Axis::Cols)?;
+        let cols = matrix.cols;
+        for row in matrix.values.chunks_mut(cols.max(1)) {
+            self.apply_vector(&mut row[start..start + self.vector.len()])?;
+        }
+        Ok(())
+    }
+
+    /// The reflector as a dense matrix.
+    pub fn to_matrix(&self) -> Result<Matrix<T>, MatrixError> {
+        let mut h = Matrix::identity(self.vector.len())?;
+        self.reflect_rows(&mut h, 0);
+        Ok(h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+    use crate::orthogonal::{givens, householder};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-12, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_givens() {
+        let mut matrix: Matrix<f64> = Matrix::new(3, 2, vec![3.0, 1.0, 0.0, 2.0, -4.0, 5.0]);
+        let rotation = givens(3.0_f64, -4.0);
+        assert!((rotation.r.abs() - 5.0).abs() < 1e-12);
+
+        rotation.apply_rows(&mut matrix, 0, 2).unwrap();
+        assert_close(&[matrix.values[0].abs(), matrix.values[4]], &[5.0, 0.0]);
+        assert_close(&matrix.values[2..4], &[0.0, 2.0]);
+
+        let transposed = matrix.transpose();
+        let mut again = matrix.transpose();
+        givens(1.0, 0.0).apply_cols(&mut again, 0, 1).unwrap();
+        assert_eq!(again, transposed);
+        assert!(rotation.apply_rows(&mut matrix, 1, 1).is_err());
+        assert!(rotation.apply_cols(&mut matrix, 0, 2).is_err());
+        assert!((givens(1e300_f64, 1e300).c - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn check_householder() {
+        let x = [2.0, 1.0, -2.0];
+        let reflector = householder(&x);
+        assert_eq!(reflector.vector[0], 1.0);
+
+        let mut y = x;
+        reflector.apply_vector(&mut y).unwrap();
+        assert_close(&y, &[reflector.alpha, 0.0, 0.0]);
+        assert!((reflector.alpha + 3.0).abs() < 1e-12);
+
+        let h = reflector.to_matrix().unwrap();
+        assert_close(
+            &h.mult_naive(&h).unwrap().values,
+            &Matrix::<f64>::identity(3).unwrap().values,
+        );
+
+        // Zeroes the first column of a block below its top row.
+        let mut matrix: Matrix<f64> =
+            Matrix::new(4, 2, vec![7.0, 0.0, 2.0, 1.0, 1.0, 1.0, -2.0, 1.0]);
+        reflector.apply_rows(&mut matrix, 1).unwrap();
+        assert_close(&[matrix.values[4], matrix.values[6]], &[0.0, 0.0]);
+        // Applying the reflector again from the right undoes it.
+        let mut wide = matrix.transpose();
+        reflector.apply_cols(&mut wide, 1).unwrap();
+        assert_close(&wide.values, &[7.0, 2.0, 1.0, -2.0, 0.0, 1.0, 1.0, 1.0]);
+        assert!(reflector.apply_rows(&mut matrix, 2).is_err());
+        assert!(matches!(
+            reflector.apply_cols(&mut matrix, usize::MAX),
+            Err(MatrixError::RangeOutOfBounds { .. })
+        ));
+        assert_eq!(householder(&[0.0, 0.0]).beta, 0.0);
+    }
+}
@@ -0,0 +1,399 @@
+use crate::config;
+use crate::error::{product_mismatch, MatrixError, Op};
+use crate::float::Float;
+use crate::identity_element::IdentityElement;
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::MulRef;
+use crate::semiring::{Semiring, Standard};
+use crate::view::MatrixView;
+use std::ops::{Add, Range, Sub};
+
+/// Runs `work` over contiguous index ranges covering `0..len`, one per
+/// thread, and returns the results in range order. Below the configured
+/// `parallel_threshold` elements the whole range runs on the calling thread.
+fn split_work<R, F>(len: usize, work: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(Range<usize>) -> R + Sync,
+{
+    split_items(len, 1, work)
+}
+
+/// [`split_work`] over `len` items of `item_len` elements each, such as
+/// the rows of a matrix.
+fn split_items<R, F>(len: usize, item_len: usize, work: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(Range<usize>) -> R + Sync,
+{
+    let settings = config::current();
+    let threads = settings.thread_count().clamp(1, len.max(1));
+    if threads == 1 || len.saturating_mul(item_len) < settings.parallel_threshold {
+        return vec![work(0..len)];
+    }
+
+    let per_thread = len.div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..len)
+            .step_by(per_thread)
+            .map(|start| {
+                let work = &work;
+                scope.spawn(move || work(start..(start + per_thread).min(len)))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| match handle.join() {
+                Ok(result) => result,
+                Err(panic) => std::panic::resume_unwind(panic),
+            })
+            .collect()
+    })
+}
+
+impl<T: Sync> Matrix<T> {
+    /// Folds the rows in contiguous blocks on scoped threads, one block per
+    /// thread of the current [`config`](crate::config). Each block starts
+    /// from `init()` and folds its rows in order with `fold(acc, row, values)`.
+    /// The block results are then merged left to right with `combine`, so
+    /// the result is deterministic for a given thread count. Matrices below
+    /// the configured `parallel_threshold` elements fold as a single block.
+    pub fn par_fold_rows<A, I, F, C>(&self, init: I, fold: F, combine: C) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Sync,
+        F: Fn(A, usize, &[T]) -> A + Sync,
+        C: Fn(A, A) -> A,
+    {
+        let cols = self.cols;
+        split_items(self.rows, cols, |rows| {
+            rows.fold(init(), |acc, row| {
+                fold(acc, row, &self.values[row * cols..(row + 1) * cols])
+            })
+        })
+        .into_iter()
+        .reduce(combine)
+        .unwrap_or_else(init)
+    }
+}
+
+impl<T: Sync> Matrix<T> {
+    /// Calls `f(index, block)` for each block of [`Matrix::row_chunks`] on
+    /// scoped threads and returns the results in block order. Small
+    /// matrices, below the configured `parallel_threshold` elements, run on
+    /// the calling thread.
+    pub fn par_row_chunks<R, F>(&self, chunk_size: usize, f: F) -> Result<Vec<R>, MatrixError>
+    where
+        R: Send,
+        F: Fn(usize, MatrixView<'_, T>) -> R + Sync,
+    {
+        let blocks: Vec<MatrixView<'_, T>> = self.row_chunks(chunk_size)?.collect();
+        let settings = config::current();
+        let threads = settings.thread_count().clamp(1, blocks.len().max(1));
+        if threads == 1 || self.values.len() < settings.parallel_threshold {
+            return Ok(blocks
+                .into_iter()
+                .enumerate()
+                .map(|(i, b)| f(i, b))
+                .collect());
+        }
+
+        let per_thread = blocks.len().div_ceil(threads);
+        let results = std::thread::scope(|scope| {
+            let handles: Vec<_> = blocks
+                .chunks(per_thread)
+                .enumerate()
+                .map(|(group, group_blocks)| {
+                    let f = &f;
+                    scope.spawn(move || {
+                        group_blocks
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &block)| f(group * per_thread + i, block))
+                            .collect::<Vec<R>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(result) => result,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect()
+        });
+        Ok(results)
+    }
+
+    pub fn par_map<U: Send, F: Fn(&T) -> U + Sync>(&self, f: F) -> Matrix<U> {
+        let new_values = split_work(self.values.len(), |range| {
+            self.values[range].iter().map(&f).collect::<Vec<U>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+        Matrix::new(self.rows, self.cols, new_values)
+    }
+
+    fn par_zip_with<F: Fn(&T, &T) -> T + Sync>(&self, matrix_b: &Matrix<T>, f: F) -> Matrix<T>
+    where
+        T: Send,
+    {
+        let new_values = split_work(self.values.len(), |range| {
+            self.values[range.clone()]
+                .iter()
+                .zip(&matrix_b.values[range])
+                .map(|(a, b)| f(a, b))
+                .collect::<Vec<T>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect();
+        Matrix::new(self.rows, self.cols, new_values)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Clone + Send + Sync + IdentityElement,
+{
+    pub fn par_add(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Add)?;
+        Ok(self.par_zip_with(matrix_b, |a, b| a.clone() + b.clone()))
+    }
+
+    pub fn par_subtract(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.check_same_shape(matrix_b, Op::Subtract)?;
+        Ok(self.par_zip_with(matrix_b, |a, b| a.clone() - b.clone()))
+    }
+
+    /// The sum of all elements. Partial sums are combined in block order, so
+    /// floating-point results depend only on the thread count.
+    pub fn par_sum(&self) -> T {
+        split_work(self.values.len(), |range| {
+            self.values[range]
+                .iter()
+                .fold(T::zero(), |sum, value| sum + value.clone())
+        })
+        .into_iter()
+        .fold(T::zero(), |sum, partial| sum + partial)
+    }
+}
+
+impl<T: Float + Send + Sync> Matrix<T> {
+    pub fn par_frobenius_norm(&self) -> T {
+        split_work(self.values.len(), |range| {
+            self.values[range]
+                .iter()
+                .fold(T::zero(), |sum, &value| sum + value * value)
+        })
+        .into_iter()
+        .fold(T::zero(), |sum, partial| sum + partial)
+        .sqrt()
+    }
+
+    /// The largest absolute value of any element.
+    pub fn par_max_abs(&self) -> T {
+        split_work(self.values.len(), |range| {
+            self.values[range].iter().fold(T::zero(), |max, &value| {
+                if value.abs() > max {
+                    value.abs()
+                } else {
+                    max
+                }
+            })
+        })
+        .into_iter()
+        .fold(
+            T::zero(),
+            |max, partial| if partial > max { partial } else { max },
+        )
+    }
+}
+
+impl<T: Clone + Send + Sync> Matrix<T> {
+    /// [`Matrix::mult_semiring`] with the output rows split into contiguous
+    /// blocks, one per thread of the current [`config`](crate::config).
+    /// Every entry is summed in the same order as the sequential version,
+    /// so results are identical. Products with fewer than the configured
+    /// `parallel_threshold` entries run on the calling thread.
+    pub fn par_mult_semiring<S: Semiring<T>>(
+        &self,
+        matrix_b: &Matrix<T>,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let len = checked_len(self.rows, matrix_b.cols)?;
+        let settings = config::current();
+        let threads = settings.thread_count().clamp(1, self.rows.max(1));
+        if threads == 1 || len == 0 || len < settings.parallel_threshold {
+            return self.mult_semiring::<S>(matrix_b);
+        }
+
+        let bt: Vec<T> = (0..matrix_b.cols)
+            .flat_map(|j| {
+                (0..matrix_b.rows).map(move |k| matrix_b.values[k * matrix_b.cols + j].clone())
+            })
+            .collect();
+        let rows_per_block = self.rows.div_ceil(threads);
+        let mut new_values: Vec<T> = vec![S::zero(); len];
+
+        std::thread::scope(|scope| {
+            for (index, block) in new_values
+                .chunks_mut(rows_per_block * matrix_b.cols)
+                .enumerate()
+            {
+                let bt = &bt;
+                scope.spawn(move || {
+                    for (offset, row) in block.chunks_mut(matrix_b.cols).enumerate() {
+                        let i = index * rows_per_block + offset;
+                        let a_row = &self.values[i * self.cols..(i + 1) * self.cols];
+                        for (j, entry) in row.iter_mut().enumerate() {
+                            let b_col = &bt[j * matrix_b.rows..(j + 1) * matrix_b.rows];
+                            *entry = a_row
+                                .iter()
+                                .zip(b_col)
+                                .fold(S::zero(), |sum, (a, b)| S::add(sum, S::mul(a, b)));
+                        }
+                    }
+                });
+            }
+        });
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(
+            2 * self.rows * self.cols * matrix_b.cols,
+            self.values.len() + matrix_b.values.len() + new_values.len(),
+        );
+
+        Ok(Matrix::new(self.rows, matrix_b.cols, new_values))
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + MulRef + Clone + Send + Sync + IdentityElement,
+{
+    pub fn par_mult_naive(&self, matrix_b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.par_mult_semiring::<Standard>(matrix_b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{self, Config};
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_par_fold_rows() {
+        let matrix: Matrix<f64> = Matrix::new(1000, 3, (0..3000).map(|v| v as f64).collect());
+        let sequential: f64 = matrix.values.iter().sum();
+
+        for (threads, parallel_threshold) in [(1, 0), (3, 0), (8, 0), (8, usize::MAX)] {
+            let settings = Config {
+                threads,
+                parallel_threshold,
+                ..Config::default()
+            };
+            let (sum, max_row) = config::with_config(settings, || {
+                matrix.par_fold_rows(
+                    || (0.0, 0),
+                    |(sum, max_row), row, values| {
+                        (sum + values.iter().sum::<f64>(), max_row.max(row))
+                    },
+                    |a, b| (a.0 + b.0, a.1.max(b.1)),
+                )
+            })
+            .unwrap();
+
+            assert_eq!(sum, sequential);
+            assert_eq!(max_row, 999);
+        }
+
+        let empty: Matrix<f64> = Matrix::new(0, 3, vec![]);
+        assert_eq!(empty.par_fold_rows(|| 0, |n, _, _| n + 1, |a, b| a + b), 0);
+        let no_cols: Matrix<f64> = Matrix::new(4, 0, vec![]);
+        assert_eq!(
+            no_cols.par_fold_rows(|| 0, |n, _, _| n + 1, |a, b| a + b),
+            4
+        );
+    }
+
+    #[test]
+    fn check_par_mult_naive() {
+        let a: Matrix<i64> = Matrix::new(7, 5, (0..35).collect());
+        let b: Matrix<i64> = Matrix::new(5, 4, (0..20).map(|v| v - 10).collect());
+        let expected = a.mult_naive(&b).unwrap();
+
+        for (threads, parallel_threshold) in [(1, 0), (3, 0), (16, 0), (3, usize::MAX)] {
+            let settings = Config {
+                threads,
+                parallel_threshold,
+                ..Config::default()
+            };
+            let product = config::with_config(settings, || a.par_mult_naive(&b))
+                .unwrap()
+                .unwrap();
+            assert_eq!(product, expected);
+        }
+        assert!(a.par_mult_naive(&a).is_err());
+    }
+
+    #[test]
+    fn check_par_elementwise() {
+        let a: Matrix<f64> = Matrix::new(40, 25, (0..1000).map(|v| v as f64 - 500.0).collect());
+        let b = a.par_map(|v| v * 2.0);
+
+        for parallel_threshold in [0, usize::MAX] {
+            let settings = Config {
+                threads: 4,
+                parallel_threshold,
+                ..Config::default()
+            };
+            config::with_config(settings, || {
+                assert_eq!(a.par_map(|v| v * 2.0), b);
+                assert_eq!(a.par_add(&b).unwrap(), a.add(&b).unwrap());
+                assert_eq!(b.par_subtract(&a).unwrap(), a);
+                assert_eq!(a.par_sum(), -500.0);
+                assert_eq!(a.par_max_abs(), 500.0);
+                let norm: f64 = a.values.iter().map(|v| v * v).sum::<f64>().sqrt();
+                assert!((a.par_frobenius_norm() - norm).abs() < 1e-9);
+            })
+            .unwrap();
+        }
+        assert!(a.par_add(&a.transpose()).is_err());
+    }
+
+    #[test]
+    fn check_par_row_chunks() {
+        let a: Matrix<i64> = Matrix::new(50, 4, (0..200).collect());
+        let expected: Vec<(usize, i64)> = a
+            .row_chunks(7)
+            .unwrap()
+            .enumerate()
+            .map(|(i, block)| (i, block.iter().sum()))
+            .collect();
+
+        for parallel_threshold in [0, usize::MAX] {
+            let settings = Config {
+                threads: 3,
+                parallel_threshold,
+                ..Config::default()
+            };
+            let sums = config::with_config(settings, || {
+                a.par_row_chunks(7, |i, block| (i, block.iter().sum::<i64>()))
+            })
+            .unwrap()
+            .unwrap();
+            assert_eq!(sums, expected);
+        }
+        assert!(a.par_row_chunks(0, |_, _| ()).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// Principal component analysis fitted one row block at a time, so the
+/// dataset never has to be held in memory at once.
+///
+/// Each call to [`IncrementalPca::partial_fit`] takes the SVD of the current
+/// components (scaled by their singular values) stacked on the centered
+/// batch and a mean-correction row, which is exact whenever the data has
+/// rank at most `n_components`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalPca<T> {
+    n_components: usize,
+    samples_seen: usize,
+    mean: Vec<T>,
+    components: Option<Matrix<T>>,
+    singular_values: Vec<T>,
+}
+
+impl<T: Float> IncrementalPca<T> {
+    pub fn new(n_components: usize) -> Result<Self, MatrixError> {
+        if n_components == 0 {
+            return Err(MatrixError::InvalidArgument(
+                "Incremental PCA needs at least one component".to_string(),
+            ));
+        }
+        Ok(IncrementalPca {
+            n_components,
+            samples_seen: 0,
+            mean: Vec::new(),
+            components: None,
+            singular_values: Vec::new(),
+        })
+    }
+
+    pub fn partial_fit(&mut self, batch: &Matrix<T>) -> Result<(), MatrixError> {
+        let (m, n) = (batch.rows, batch.cols);
+        if m == 0 {
+            return Ok(());
+        }
+        if self.samples_seen == 0 {
+            if self.n_components > n {
+                return Err(MatrixError::InvalidArgument(format!(
+                    "Cannot keep {} components of {} features",
+                    self.n_components, n
+                )));
+            }
+        } else if n != self.mean.len() {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.mean.len(),
+                actual: n,
+            });
+        }
+
+        let batch_count = T::from_f64(m as f64);
+        let mut batch_mean = vec![T::zero(); n];
+        for row in batch.values.chunks(n) {
+            for (total, &value) in batch_mean.iter_mut().zip(row) {
+                *total += value;
+            }
+        }
+        for value in batch_mean.iter_mut() {
+            *value = *value / batch_count;
+        }
+
+        let mut stacked: Vec<T> = Vec::new();
+        if let Some(components) = &self.components {
+            for (row, &s) in components.values.chunks(n).zip(&self.singular_values) {
+                stacked.extend(row.iter().map(|&v| v * s));
+            }
+        }
+        for row in batch.values.chunks(n) {
+            stacked.extend(row.iter().zip(&batch_mean).map(|(&v, &mu)| v - mu));
+        }
+        if self.samples_seen > 0 {
+            let seen = self.samples_seen as f64;
+            let scale = T::from_f64((seen * m as f64 / (seen + m as f64)).sqrt());
+            stacked.extend(
+                self.mean
+                    .iter()
+                    .zip(&batch_mean)
+                    .map(|(&old, &new)| (old - new) * scale),
+            );
+        }
+
+        let stacked_rows = stacked.len() / n;
+        let svd = Matrix::new(stacked_rows, n, stacked).svd()?;
+        let kept = self.n_components.min(svd.singular_values().len());
+        self.components = Some(Matrix::new(kept, n, svd.v_t().values[..kept * n].to_vec()));
+        self.singular_values = svd.singular_values()[..kept].to_vec();
+
+        let total = self.samples_seen + m;
+        self.mean = if self.samples_seen == 0 {
+            batch_mean
+        } else {
+            let old_weight = T::from_f64(self.samples_seen as f64 / total as f64);
+            let new_weight = T::from_f64(m as f64 / total as f64);
+            self.mean
+                .iter()
+                .zip(&batch_mean)
+                .map(|(&old, &new)| old * old_weight + new * new_weight)
+                .collect()
+        };
+        self.samples_seen = total;
+        Ok(())
+    }
+
+    /// The principal axes as rows, strongest first, or `None` before the
+    /// first batch.
+    pub fn components(&self) -> Option<&Matrix<T>> {
+        self.components.as_ref()
+    }
+
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    pub fn mean(&self) -> &[T] {
+        &self.mean
+    }
+
+    pub fn samples_seen(&self) -> usize {
+        self.samples_seen
+    }
+
+    /// The sample variance along each component.
+    pub fn explained_variance(&self) -> Vec<T> {
+        let dof = T::from_f64(self.samples_seen.saturating_sub(1).max(1) as f64);
+        self.singular_values.iter().map(|&s| s * s / dof).collect()
+    }
+
+    /// Projects the rows of `batch` onto the components.
+    pub fn transform(&self, batch: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let components = self.components.as_ref().ok_or_else(|| {
+            MatrixError::InvalidArgument("Incremental PCA has not been fitted".to_string())
+        })?;
+        if batch.cols != self.mean.len() {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.mean.len(),
+                actual: batch.cols,
+            });
+        }
+
+        let centered: Vec<T> = batch
+            .values
+            .chunks(batch.cols)
+            .flat_map(|row| row.iter().zip(&self.mean).map(|(&v, &mu)| v - mu))
+            .collect();
+        Matrix::new(batch.rows, batch.cols, centered).mult_naive(&components.transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::pca::IncrementalPca;
+
+    #[test]
+    fn check_incremental_pca_matches_batch() {
+        let data = vec![
+            2.0, 0.5, 1.0, 1.0, 1.5, 0.0, 4.0, 2.0, 3.5, 3.0, 0.0, 1.0, 0.5, 1.0, 2.0, 5.0, 3.0,
+            2.5,
+        ];
+        let mut pca = IncrementalPca::new(3).unwrap();
+        pca.partial_fit(&Matrix::new(2, 3, data[..6].to_vec()))
+            .unwrap();
+        pca.partial_fit(&Matrix::new(4, 3, data[6..].to_vec()))
+            .unwrap();
+
+        let mut mean = [0.0f64; 3];
+        for row in data.chunks(3) {
+            for (m, v) in mean.iter_mut().zip(row) {
+                *m += v / 6.0;
+            }
+        }
+        let centered: Vec<f64> = data
+            .chunks(3)
+            .flat_map(|row| row.iter().zip(&mean).map(|(v, m)| v - m))
+            .collect();
+        let full = Matrix::new(6, 3, centered).svd().unwrap();
+
+        assert_eq!(pca.samples_seen(), 6);
+        for (a, b) in pca.mean().iter().zip(&mean) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        for (a, b) in pca.singular_values().iter().zip(full.singular_values()) {
+            assert!((a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn check_incremental_pca_errors() {
+        assert!(IncrementalPca::<f64>::new(0).is_err());
+
+        let mut pca = IncrementalPca::<f64>::new(1).unwrap();
+        assert!(pca.transform(&Matrix::new(1, 2, vec![1.0, 2.0])).is_err());
+        pca.partial_fit(&Matrix::new(2, 2, vec![1.0, 1.0, 3.0, 3.0]))
+            .unwrap();
+        assert!(pca.partial_fit(&Matrix::new(1, 3, vec![0.0; 3])).is_err());
+
+        let projected = pca.transform(&Matrix::new(1, 2, vec![3.0, 3.0])).unwrap();
+        assert!((projected.values[0].abs() - 2f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use std::io::{Read, Write};
+
+/// Splits a PGM header into whitespace-separated tokens, skipping `#`
+/// comments, and returns them with the offset just past the last one.
+fn header_tokens(bytes: &[u8], count: usize) -> Result<(Vec<String>, usize), MatrixError> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while tokens.len() < count {
+        while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b'#') {
+            if bytes[pos] == b'#' {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            } else {
+                pos += 1;
+            }
+        }
+        let start = pos;
+        while pos < bytes.len() && !bytes[pos].is_ascii_whitespace() {
+            pos += 1;
+        }
+        if start == pos {
+            return Err(MatrixError::ParseError("Truncated PGM header".to_string()));
+        }
+        tokens.push(String::from_utf8_lossy(&bytes[start..pos]).into_owned());
+    }
+
+    Ok((tokens, pos))
+}
+
+fn parse_number(token: &str) -> Result<usize, MatrixError> {
+    token
+        .parse()
+        .map_err(|_| MatrixError::ParseError(format!("Invalid PGM header value '{}'", token)))
+}
+
+/// One row per image row, so the buffer is exactly that of an `image`
+/// crate `GrayImage`. The crate has no `image` feature, since it has no
+/// dependencies; convert without copying through
+/// `GrayImage::from_raw(cols as u32, rows as u32, matrix.into_vec())` and
+/// `Matrix::try_new(height as usize, width as usize, image.into_raw())`.
+impl Matrix<u8> {
+    /// Reads an 8-bit grayscale PGM image, binary (`P5`) or plain (`P2`),
+    /// as a matrix with one row per image row.
+    pub fn from_pgm<R: Read>(mut reader: R) -> Result<Matrix<u8>, MatrixError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        let (header, end) = header_tokens(&bytes, 4)?;
+        let width = parse_number(&header[1])?;
+        let height = parse_number(&header[2])?;
+        let max_value = parse_number(&header[3])?;
+        if max_value == 0 || max_value > 255 {
+            return Err(MatrixError::ParseError(format!(
+                "Only 8-bit PGM images are supported, got maximum value {}",
+                max_value
+            )));
+        }
+        let len = checked_len(height, width)?;
+
+        let values = match header[0].as_str() {
+            "P5" => {
+                let data = bytes.get(end + 1..end + 1 + len).ok_or_else(|| {
+                    MatrixError::ParseError(format!("PGM data is shorter than {} pixels", len))
+                })?;
+                data.to_vec()
+            }
+            "P2" => {
+                let (tokens, _) = header_tokens(&bytes[end..], len)?;
+                tokens
+                    .iter()
+                    .map(|token| match parse_number(token) {
+                        Ok(value) if value <= max_value => Ok(value as u8),
+                        _ => Err(MatrixError::ParseError(format!(
+                            "Invalid PGM pixel value '{}'",
+                            token
+                        ))),
+                    })
+                    .collect::<Result<Vec<u8>, MatrixError>>()?
+            }
+            magic => {
+                return Err(MatrixError::ParseError(format!(
+                    "Unsupported PGM format '{}'",
+                    magic
+                )))
+            }
+        };
+
+        Ok(Matrix::new(height, width, values))
+    }
+
+    /// Writes a binary (`P5`) PGM image with a maximum value of 255.
+    pub fn to_pgm<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+        write!(writer, "P5\n{} {}\n255\n", self.cols, self.rows)?;
+        writer.write_all(&self.values)?;
+        Ok(())
+    }
+
+    /// Maps pixel values onto `0.0..=1.0`.
+    pub fn to_unit_f32(&self) -> Matrix<f32> {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.values.iter().map(|&v| v as f32 / 255.0).collect(),
+        )
+    }
+}
+
+impl Matrix<f32> {
+    /// The inverse of [`Matrix::to_unit_f32`]: values are scaled by 255,
+    /// rounded and clamped, and NaN becomes 0.
+    pub fn to_u8_clamped(&self) -> Matrix<u8> {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.values
+                .iter()
+                .map(|&v| (v * 255.0).round().clamp(0.0, 255.0) as u8)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_pgm_round_trip() {
+        let image: Matrix<u8> = Matrix::new(2, 3, vec![0, 64, 128, 192, 255, 10]);
+        let mut buffer = Vec::new();
+        image.to_pgm(&mut buffer).unwrap();
+
+        assert!(buffer.starts_with(b"P5\n3 2\n255\n"));
+        assert_eq!(Matrix::from_pgm(buffer.as_slice()).unwrap(), image);
+        assert_eq!(image.to_unit_f32().to_u8_clamped(), image);
+
+        let plain = "P2\n# a comment\n2 2\n15\n0 15\n7 3\n";
+        assert_eq!(
+            Matrix::from_pgm(plain.as_bytes()).unwrap(),
+            Matrix::new(2, 2, vec![0, 15, 7, 3])
+        );
+        assert!(Matrix::from_pgm(&buffer[..buffer.len() - 1]).is_err());
+        assert!(Matrix::from_pgm("P2\n1 1\n15\n16\n".as_bytes()).is_err());
+    }
+}
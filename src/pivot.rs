@@ -0,0 +1,119 @@
+use crate::error::MatrixError;
+use crate::labeled::LabeledMatrix;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The result of [`Matrix::pivot`]: the dense matrix and the label of each
+/// row and column, in order of first appearance.
+#[derive(Debug, Clone)]
+pub struct PivotTable<T, R, C> {
+    pub matrix: Matrix<T>,
+    pub row_labels: Vec<R>,
+    pub col_labels: Vec<C>,
+    pub row_index: HashMap<R, usize>,
+    pub col_index: HashMap<C, usize>,
+}
+
+fn intern<K: Eq + Hash + Clone>(
+    key: K,
+    labels: &mut Vec<K>,
+    index: &mut HashMap<K, usize>,
+) -> usize {
+    *index.entry(key).or_insert_with_key(|key| {
+        labels.push(key.clone());
+        labels.len() - 1
+    })
+}
+
+impl<T: Scalar> Matrix<T> {
+    /// Builds a dense matrix with one row per distinct `row_key` and one
+    /// column per distinct `col_key`. Records that land on the same cell are
+    /// summed, and cells without any record hold `fill`.
+    pub fn pivot<I, R, C>(
+        records: I,
+        row_key: impl Fn(&I::Item) -> R,
+        col_key: impl Fn(&I::Item) -> C,
+        value: impl Fn(&I::Item) -> T,
+        fill: T,
+    ) -> PivotTable<T, R, C>
+    where
+        I: IntoIterator,
+        R: Eq + Hash + Clone,
+        C: Eq + Hash + Clone,
+    {
+        let mut row_labels = Vec::new();
+        let mut col_labels = Vec::new();
+        let mut row_index = HashMap::new();
+        let mut col_index = HashMap::new();
+        let mut cells: HashMap<(usize, usize), T> = HashMap::new();
+
+        for record in records {
+            let row = intern(row_key(&record), &mut row_labels, &mut row_index);
+            let col = intern(col_key(&record), &mut col_labels, &mut col_index);
+            let value = value(&record);
+            match cells.get_mut(&(row, col)) {
+                Some(sum) => *sum += value,
+                None => {
+                    cells.insert((row, col), value);
+                }
+            }
+        }
+
+        let cols = col_labels.len();
+        let mut new_values = vec![fill; row_labels.len() * cols];
+        for ((row, col), value) in cells {
+            new_values[row * cols + col] = value;
+        }
+
+        PivotTable {
+            matrix: Matrix::new(row_labels.len(), cols, new_values),
+            row_labels,
+            col_labels,
+            row_index,
+            col_index,
+        }
+    }
+}
+
+impl<T, R, C> PivotTable<T, R, C>
+where
+    T: Scalar,
+    R: ToString,
+    C: ToString,
+{
+    /// Fails if two labels render to the same string.
+    pub fn into_labeled(self) -> Result<LabeledMatrix<T>, MatrixError> {
+        LabeledMatrix::new(
+            self.matrix,
+            self.row_labels.iter().map(R::to_string).collect(),
+            self.col_labels.iter().map(C::to_string).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_pivot() {
+        let events = vec![
+            ("alice", "login", 1),
+            ("bob", "upload", 3),
+            ("alice", "upload", 2),
+            ("alice", "login", 1),
+        ];
+
+        let table = Matrix::pivot(events, |e| e.0, |e| e.1, |e| e.2, 0);
+
+        assert_eq!(table.row_labels, vec!["alice", "bob"]);
+        assert_eq!(table.col_labels, vec!["login", "upload"]);
+        assert_eq!(table.matrix, Matrix::new(2, 2, vec![2, 2, 0, 3]));
+        assert_eq!(table.col_index["upload"], 1);
+
+        let labeled = table.into_labeled().unwrap();
+        assert_eq!(*labeled.value_by_label("bob", "upload").unwrap(), 3);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use crate::smatrix::SMatrix;
+use crate::view::MatrixView;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element types with no padding and no invalid bit patterns, so their
+/// storage can be reinterpreted as bytes and back. Sealed: the crate only
+/// implements it for the primitive integers and floats, and for
+/// [`SMatrix`] of those, which is laid out exactly like `[[T; C]; R]`.
+pub trait Pod: sealed::Sealed + Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($t:ty),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+            impl Pod for $t {}
+        )*
+    };
+}
+
+impl_pod!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<T: Pod, const R: usize, const C: usize> sealed::Sealed for SMatrix<T, R, C> {}
+impl<T: Pod, const R: usize, const C: usize> Pod for SMatrix<T, R, C> {}
+
+impl<T: Pod, const R: usize, const C: usize> SMatrix<T, R, C> {
+    /// The row-major values as raw bytes in native byte order, ready to
+    /// upload as a uniform or vertex attribute.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `SMatrix` is a transparent wrapper around `[[T; C]; R]`,
+        // which has no padding when `T: Pod`.
+        unsafe {
+            std::slice::from_raw_parts(
+                self as *const Self as *const u8,
+                std::mem::size_of::<Self>(),
+            )
+        }
+    }
+
+    /// Copies a matrix out of native-order bytes, which need not be aligned.
+    pub fn from_bytes(bytes: &[u8]) -> Result<SMatrix<T, R, C>, MatrixError> {
+        if bytes.len() != std::mem::size_of::<Self>() {
+            return Err(MatrixError::LengthMismatch {
+                expected: std::mem::size_of::<Self>(),
+                actual: bytes.len(),
+            });
+        }
+
+        // SAFETY: the length was checked above, the read is unaligned, and
+        // every bit pattern is a valid `T: Pod`.
+        Ok(unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const Self) })
+    }
+}
+
+impl<T: Pod> Matrix<T> {
+    /// The row-major values as raw bytes in native byte order.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: `T: Pod` has no padding, so every byte of the values is
+        // initialized, and `u8` has no alignment requirement.
+        unsafe {
+            std::slice::from_raw_parts(
+                self.values.as_ptr() as *const u8,
+                std::mem::size_of_val(self.values.as_slice()),
+            )
+        }
+    }
+}
+
+impl<'a, T: Pod> MatrixView<'a, T> {
+    /// Reinterprets native-order bytes as a `rows`x`cols` view without
+    /// copying. Fails unless `bytes` is exactly the right length and
+    /// aligned for `T`.
+    pub fn from_bytes_checked(
+        bytes: &'a [u8],
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        let size = std::mem::size_of::<T>();
+        if Some(bytes.len()) != len.checked_mul(size) {
+            return Err(MatrixError::LengthMismatch {
+                expected: len.saturating_mul(size),
+                actual: bytes.len(),
+            });
+        }
+        if bytes.as_ptr().align_offset(std::mem::align_of::<T>()) != 0 {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Buffer is not aligned to {} bytes",
+                std::mem::align_of::<T>()
+            )));
+        }
+
+        // SAFETY: length and alignment were checked above, and every bit
+        // pattern is a valid `T: Pod`.
+        let values = unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const T, len) };
+        MatrixView::from_slice(values, rows, cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::smatrix::{SMatrix, SMatrix2};
+    use crate::view::MatrixView;
+
+    #[test]
+    fn check_byte_reinterpretation() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, -2.0, 0.5, 1e10]);
+        let bytes = matrix.as_bytes();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes[..8], 1.0f64.to_ne_bytes());
+
+        let view = MatrixView::<f64>::from_bytes_checked(bytes, 2, 2).unwrap();
+        assert_eq!(view.to_matrix(), matrix);
+
+        assert!(MatrixView::<f64>::from_bytes_checked(&bytes[..24], 2, 2).is_err());
+        assert!(MatrixView::<f64>::from_bytes_checked(&bytes[1..25], 1, 3).is_err());
+    }
+
+    #[test]
+    fn check_smatrix_bytes() {
+        let transform: SMatrix2<f32> = SMatrix::new([[1.0, 2.0], [3.0, 4.0]]);
+        let bytes = transform.as_bytes();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[12..], 4.0f32.to_ne_bytes());
+
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(bytes);
+        assert_eq!(
+            SMatrix2::<f32>::from_bytes(&shifted[1..]).unwrap(),
+            transform
+        );
+        assert!(SMatrix2::<f32>::from_bytes(&bytes[..8]).is_err());
+
+        let buffer = Matrix::new(1, 2, vec![transform, transform]);
+        assert_eq!(buffer.as_bytes().len(), 32);
+    }
+}
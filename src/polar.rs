@@ -0,0 +1,146 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// A polar decomposition `A = U P`, with `U` having orthonormal columns and
+/// `P` symmetric positive semidefinite. For a square `A`, `U` is the
+/// orthogonal matrix nearest to `A`; it is a rotation when `det A > 0` and
+/// contains a reflection when `det A < 0`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polar<T> {
+    u: Matrix<T>,
+    p: Matrix<T>,
+}
+
+impl<T: Float> Polar<T> {
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    pub fn p(&self) -> &Matrix<T> {
+        &self.p
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The polar decomposition from the SVD `A = W S V^T`, as `U = W V^T`
+    /// and `P = V S V^T`. `P` is always unique; when `A` is rank deficient
+    /// `U` is not, and the directions of `W` belonging to negligible
+    /// singular values are replaced to complete an orthonormal basis.
+    pub fn polar(&self) -> Result<Polar<T>, MatrixError> {
+        let (m, n) = (self.rows, self.cols);
+        if m < n {
+            return Err(MatrixError::InvalidArgument(format!(
+                "A {}x{} matrix has no polar factor with orthonormal columns; decompose its transpose",
+                m, n
+            )));
+        }
+
+        let svd = self.svd()?;
+        let mut w = svd.u().clone();
+        let v_t = svd.v_t();
+        let largest = svd.singular_values().first().copied().unwrap_or(T::zero());
+        let cutoff = T::from_f64(m as f64) * T::epsilon() * largest;
+        let negligible: Vec<usize> = (0..n)
+            .filter(|&j| svd.singular_values()[j] <= cutoff)
+            .collect();
+        for &j in &negligible {
+            for i in 0..m {
+                w.values[i * n + j] = T::zero();
+            }
+        }
+        for &j in &negligible {
+            complete_column(&mut w, j);
+        }
+
+        let mut u = Matrix::new(m, n, vec![T::zero(); m * n]);
+        let mut p = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for (l, &s) in svd.singular_values().iter().enumerate() {
+            for i in 0..m {
+                let left = w.values[i * n + l];
+                for j in 0..n {
+                    u.values[i * n + j] += left * v_t.values[l * n + j];
+                }
+            }
+            for i in 0..n {
+                let scaled = s * v_t.values[l * n + i];
+                for j in 0..n {
+                    p.values[i * n + j] += scaled * v_t.values[l * n + j];
+                }
+            }
+        }
+
+        Ok(Polar { u, p })
+    }
+}
+
+/// Replaces column `j` of `w` with a unit vector orthogonal to its other
+/// columns, taken from the first coordinate axis that is not close to
+/// their span.
+fn complete_column<T: Float>(w: &mut Matrix<T>, j: usize) {
+    let (m, n) = (w.rows, w.cols);
+    for axis in 0..m {
+        let mut candidate = vec![T::zero(); m];
+        candidate[axis] = T::one();
+        // Two passes of Gram-Schmidt keep the result orthogonal to
+        // working precision.
+        for _ in 0..2 {
+            for c in (0..n).filter(|&c| c != j) {
+                let dot = (0..m).fold(T::zero(), |acc, i| acc + w.values[i * n + c] * candidate[i]);
+                for (i, value) in candidate.iter_mut().enumerate() {
+                    *value = *value - dot * w.values[i * n + c];
+                }
+            }
+        }
+        let norm = candidate
+            .iter()
+            .fold(T::zero(), |acc, &x| acc + x * x)
+            .sqrt();
+        if norm > T::from_f64(0.5) {
+            for (i, value) in candidate.iter().enumerate() {
+                w.values[i * n + j] = *value / norm;
+            }
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        for (x, y) in a.values.iter().zip(&b.values) {
+            assert!((x - y).abs() < 1e-10, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_polar() {
+        let (c, s) = (0.6, 0.8);
+        let rotation: Matrix<f64> = Matrix::new(2, 2, vec![c, -s, s, c]);
+        let stretch: Matrix<f64> = Matrix::new(2, 2, vec![2.0, 0.5, 0.5, 1.0]);
+        let deformation = rotation.mult_naive(&stretch).unwrap();
+
+        let polar = deformation.polar().unwrap();
+        assert_close(polar.u(), &rotation);
+        assert_close(polar.p(), &stretch);
+        assert!(Matrix::<f64>::new(2, 3, vec![0.0; 6]).polar().is_err());
+    }
+
+    #[test]
+    fn check_polar_rank_deficient() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![1.0, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 0.0, 0.0]);
+        let polar = matrix.polar().unwrap();
+        let (u, p) = (polar.u(), polar.p());
+
+        assert_close(
+            &u.transpose().mult_naive(u).unwrap(),
+            &Matrix::identity(3).unwrap(),
+        );
+        assert_close(&u.mult_naive(p).unwrap(), &matrix);
+        assert_close(&p.transpose(), p);
+    }
+}
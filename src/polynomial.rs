@@ -0,0 +1,97 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    /// The `points.len()`x`(degree + 1)` matrix whose row `i` is
+    /// `1, x_i, x_i^2, ..., x_i^degree`. Solving `V c = y` in the least
+    /// squares sense fits a polynomial with coefficients in ascending order.
+    pub fn vandermonde(points: &[T], degree: usize) -> Result<Matrix<T>, MatrixError> {
+        let cols = degree.checked_add(1).ok_or_else(|| {
+            MatrixError::SizeOverflow("Vandermonde degree overflows usize".to_string())
+        })?;
+        let mut new_values = Vec::with_capacity(checked_len(points.len(), cols)?);
+        for x in points {
+            let mut power = T::one();
+            for _ in 0..cols {
+                new_values.push(power.clone());
+                power = power * x.clone();
+            }
+        }
+        Ok(Matrix::new(points.len(), cols, new_values))
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The companion matrix of `c_0 + c_1 x + ... + c_n x^n`, coefficients
+    /// in ascending order as [`Matrix::vandermonde`] fits them. Its
+    /// eigenvalues are the polynomial's roots. The leading coefficient must
+    /// be non-zero.
+    pub fn companion(poly_coeffs: &[T]) -> Result<Matrix<T>, MatrixError> {
+        let (&leading, rest) = match poly_coeffs.split_last() {
+            Some(split) if poly_coeffs.len() >= 2 => split,
+            _ => {
+                return Err(MatrixError::InvalidArgument(
+                    "A companion matrix needs a polynomial of degree at least 1".to_string(),
+                ))
+            }
+        };
+        if leading == T::zero() {
+            return Err(MatrixError::InvalidArgument(
+                "The leading polynomial coefficient must be non-zero".to_string(),
+            ));
+        }
+
+        let n = rest.len();
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -rest[i] / leading
+            } else if i == j + 1 {
+                T::one()
+            } else {
+                T::zero()
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_vandermonde_fit() {
+        let vandermonde: Matrix<f64> = Matrix::vandermonde(&[0.0, 1.0, 2.0], 2).unwrap();
+        assert_eq!(
+            vandermonde.values,
+            vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 4.0]
+        );
+
+        // y = 1 - 2x + 3x^2 through three points is recovered exactly.
+        let y = Matrix::new(3, 1, vec![1.0, 2.0, 9.0]);
+        let coeffs = vandermonde.lu().unwrap().solve(&y).unwrap();
+        for (c, expected) in coeffs.values.iter().zip([1.0, -2.0, 3.0]) {
+            assert!((c - expected).abs() < 1e-12);
+        }
+        assert!(Matrix::<i32>::vandermonde(&[1], usize::MAX).is_err());
+    }
+
+    #[test]
+    fn check_companion() {
+        // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6.
+        let companion = Matrix::companion(&[6.0, -7.0, 0.0, 1.0]).unwrap();
+        assert_eq!(companion.get_rows(), 3);
+
+        // Each root r has the left eigenvector (1, r, r^2).
+        for root in [1.0, 2.0, -3.0] {
+            let v = Matrix::vandermonde(&[root], 2).unwrap();
+            let mut scaled = v.clone();
+            scaled.mult_scalar(root);
+            assert_eq!(v.mult(&companion).unwrap(), scaled);
+        }
+
+        assert!(Matrix::companion(&[1.0]).is_err());
+        assert!(Matrix::companion(&[1.0, 0.0]).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    Single,
+    Double,
+}
+
+/// Diagnostics from [`Matrix::solve_escalating`]. `residual` is the
+/// normwise relative backward error `|b - A x| / (|A| |x| + |b|)` in the
+/// infinity norm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EscalationReport {
+    pub precision: Precision,
+    pub refinements: usize,
+    pub residual: f64,
+    pub converged: bool,
+}
+
+const MAX_REFINEMENTS: usize = 10;
+
+fn norm_inf(matrix: &Matrix<f64>) -> f64 {
+    matrix
+        .values
+        .chunks(matrix.cols.max(1))
+        .map(|row| row.iter().map(|v| v.abs()).sum::<f64>())
+        .fold(0.0, f64::max)
+}
+
+impl Matrix<f64> {
+    fn backward_error(&self, x: &Matrix<f64>, b: &Matrix<f64>) -> Result<f64, MatrixError> {
+        let residual = b.subtract(&self.mult_naive(x)?)?;
+        let scale = norm_inf(self) * norm_inf(x) + norm_inf(b);
+        Ok(if scale == 0.0 {
+            0.0
+        } else {
+            norm_inf(&residual) / scale
+        })
+    }
+
+    /// Solves `A X = B` by factoring in `f32` first. If that factorization
+    /// fails or its backward error exceeds `tolerance`, the system is
+    /// refactored in `f64` and the solution is polished with iterative
+    /// refinement until the error is within `tolerance` or stops improving.
+    pub fn solve_escalating(
+        &self,
+        b: &Matrix<f64>,
+        tolerance: f64,
+    ) -> Result<(Matrix<f64>, EscalationReport), MatrixError> {
+        let single = Matrix::new(
+            self.rows,
+            self.cols,
+            self.values.iter().map(|&v| v as f32).collect(),
+        );
+        let b_single = Matrix::new(b.rows, b.cols, b.values.iter().map(|&v| v as f32).collect());
+
+        if let Ok(x) = single.lu().and_then(|lu| lu.solve(&b_single)) {
+            let x = Matrix::new(x.rows, x.cols, x.values.iter().map(|&v| v as f64).collect());
+            let residual = self.backward_error(&x, b)?;
+            if residual <= tolerance {
+                let report = EscalationReport {
+                    precision: Precision::Single,
+                    refinements: 0,
+                    residual,
+                    converged: true,
+                };
+                return Ok((x, report));
+            }
+        }
+
+        let lu = self.lu()?;
+        let mut x = lu.solve(b)?;
+        let mut residual = self.backward_error(&x, b)?;
+        let mut refinements = 0;
+
+        while residual > tolerance && refinements < MAX_REFINEMENTS {
+            let correction = lu.solve(&b.subtract(&self.mult_naive(&x)?)?)?;
+            let candidate = x.add(&correction)?;
+            let candidate_residual = self.backward_error(&candidate, b)?;
+            if candidate_residual >= residual {
+                break;
+            }
+            x = candidate;
+            residual = candidate_residual;
+            refinements += 1;
+        }
+
+        let report = EscalationReport {
+            precision: Precision::Double,
+            refinements,
+            residual,
+            converged: residual <= tolerance,
+        };
+        Ok((x, report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::precision::Precision;
+
+    #[test]
+    fn check_solve_escalating() {
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![4.0, 1.0, 1.0, 3.0]);
+        let b = Matrix::new(2, 1, vec![1.0, 2.0]);
+
+        let (x, report) = matrix.solve_escalating(&b, 1e-6).unwrap();
+        assert_eq!(report.precision, Precision::Single);
+        assert!((x.values[0] - 1.0 / 11.0).abs() < 1e-6);
+
+        let (x, report) = matrix.solve_escalating(&b, 1e-15).unwrap();
+        assert_eq!(report.precision, Precision::Double);
+        assert!(report.converged);
+        assert!((x.values[1] - 7.0 / 11.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn check_solve_escalating_near_singular() {
+        let delta = 1e-9;
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 1.0, 1.0, 1.0 + delta]);
+        let b = Matrix::new(2, 1, vec![2.0, 2.0 + delta]);
+
+        let (x, report) = matrix.solve_escalating(&b, 1e-12).unwrap();
+        assert_eq!(report.precision, Precision::Double);
+        assert!((x.values[0] - 1.0).abs() < 1e-6);
+        assert!((x.values[1] - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,76 @@
+use std::cell::Cell;
+
+/// Work done by instrumented kernels on the current thread.
+///
+/// `flops` counts element additions and multiplications, whatever the
+/// element type. `bytes` is the compulsory memory traffic: every operand
+/// element read once and every result element written once, so cache misses
+/// and repeated reads are not included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Counters {
+    pub flops: u64,
+    pub bytes: u64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const { Cell::new(Counters { flops: 0, bytes: 0 }) };
+}
+
+pub(crate) fn record<T>(flops: usize, elements: usize) {
+    COUNTERS.with(|counters| {
+        let current = counters.get();
+        counters.set(Counters {
+            flops: current.flops.saturating_add(flops as u64),
+            bytes: current
+                .bytes
+                .saturating_add((elements * std::mem::size_of::<T>()) as u64),
+        });
+    });
+}
+
+pub fn snapshot() -> Counters {
+    COUNTERS.with(Cell::get)
+}
+
+pub fn reset() {
+    COUNTERS.with(|counters| counters.set(Counters::default()));
+}
+
+/// Runs `f` and returns the work it did, leaving the running totals intact.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Counters) {
+    let before = snapshot();
+    let result = f();
+    let after = snapshot();
+    let counters = Counters {
+        flops: after.flops.saturating_sub(before.flops),
+        bytes: after.bytes.saturating_sub(before.bytes),
+    };
+    (result, counters)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::profile::{self, Counters};
+
+    #[test]
+    fn check_profile_counters() {
+        let matrix_a: Matrix<f64> = Matrix::new(2, 3, vec![1.0; 6]);
+        let matrix_b: Matrix<f64> = Matrix::new(3, 4, vec![1.0; 12]);
+
+        let (_, counters) = profile::measure(|| matrix_a.mult_naive(&matrix_b).unwrap());
+        assert_eq!(
+            counters,
+            Counters {
+                flops: 2 * 2 * 3 * 4,
+                bytes: 8 * (6 + 12 + 8),
+            }
+        );
+
+        profile::reset();
+        matrix_a.add(&matrix_a).unwrap();
+        assert_eq!(profile::snapshot().flops, 6);
+        assert!(matrix_a.add(&matrix_b).is_err());
+        assert_eq!(profile::snapshot().flops, 6);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::Matrix;
+use crate::rank::RankTolerance;
+
+/// A thin QR factorization `A P = Q R` from Householder reflections.
+///
+/// For an `m`x`n` matrix with `k = min(m, n)`, `Q` is `m`x`k` with
+/// orthonormal columns and `R` is `k`x`n` upper triangular. Column `j` of
+/// `A P` is column `permutation[j]` of `A`; without pivoting the
+/// permutation is the identity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr<T> {
+    q: Matrix<T>,
+    r: Matrix<T>,
+    permutation: Vec<usize>,
+}
+
+impl<T: Float> Qr<T> {
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    pub fn r(&self) -> &Matrix<T> {
+        &self.r
+    }
+
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// The number of diagonal entries of `R` above the tolerance. Only
+    /// meaningful for a factorization from [`Matrix::qr_pivoted`], whose
+    /// diagonal is non-increasing in magnitude.
+    pub fn rank(&self, tolerance: RankTolerance) -> usize {
+        let cutoff = tolerance.cutoff::<T>(self.q.rows, self.r.cols, self.pivot_magnitude(0));
+        (0..self.r.rows)
+            .take_while(|&i| self.pivot_magnitude(i) > cutoff)
+            .count()
+    }
+
+    fn pivot_magnitude(&self, i: usize) -> f64 {
+        if i < self.r.rows {
+            self.r.values[i * self.r.cols + i].abs().to_f64()
+        } else {
+            0.0
+        }
+    }
+
+    /// The basic least-squares solution of a possibly rank-deficient
+    /// system: with `r = rank(tolerance)`, only the first `r` pivoted
+    /// columns are used and the remaining unknowns are zero.
+    pub fn solve_rank_deficient(
+        &self,
+        b: &Matrix<T>,
+        tolerance: RankTolerance,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let (m, n) = (self.q.rows, self.r.cols);
+        if b.rows != m {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (m, n),
+                rhs: (b.rows, b.cols),
+            });
+        }
+
+        let rank = self.rank(tolerance);
+        let qtb = self.q.transpose().mult_naive(b)?;
+        let cols = b.cols;
+        let mut x = vec![T::zero(); n * cols];
+        for j in 0..cols {
+            for i in (0..rank).rev() {
+                let mut sum = qtb.values[i * cols + j];
+                for c in i + 1..rank {
+                    sum = sum - self.r.values[i * n + c] * x[self.permutation[c] * cols + j];
+                }
+                x[self.permutation[i] * cols + j] = sum / self.r.values[i * n + i];
+            }
+        }
+
+        Ok(Matrix::new(n, cols, x))
+    }
+
+    /// Solves the least-squares problem `min |A x - b|` for every column of
+    /// `b`, assuming `A` has full column rank.
+    pub fn solve_least_squares(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let (m, k, n) = (self.q.rows, self.q.cols, self.r.cols);
+        if b.rows != m {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (m, n),
+                rhs: (b.rows, b.cols),
+            });
+        }
+        if k < n {
+            return Err(MatrixError::InvalidArgument(format!(
+                "A {}x{} system is underdetermined",
+                m, n
+            )));
+        }
+
+        let qtb = self.q.transpose().mult_naive(b)?;
+        let cols = b.cols;
+        let mut x = vec![T::zero(); n * cols];
+        for j in 0..cols {
+            for i in (0..n).rev() {
+                let mut sum = qtb.values[i * cols + j];
+                for c in i + 1..n {
+                    sum = sum - self.r.values[i * n + c] * x[self.permutation[c] * cols + j];
+                }
+                let diagonal = self.r.values[i * n + i];
+                if diagonal == T::zero() {
+                    return Err(MatrixError::Singular {
+                        column: self.permutation[i],
+                    });
+                }
+                x[self.permutation[i] * cols + j] = sum / diagonal;
+            }
+        }
+
+        Ok(Matrix::new(n, cols, x))
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn qr(&self) -> Qr<T> {
+        self.householder_qr(false)
+    }
+
+    /// QR with column pivoting: at each step the remaining column of largest
+    /// norm is moved to the front, so `|R[i][i]|` is non-increasing and
+    /// reveals the numerical rank.
+    pub fn qr_pivoted(&self) -> Qr<T> {
+        self.householder_qr(true)
+    }
+
+    fn householder_qr(&self, pivot: bool) -> Qr<T> {
+        let (m, n) = (self.rows, self.cols);
+        let k = m.min(n);
+        let mut r = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut reflectors: Vec<Vec<T>> = Vec::with_capacity(k);
+
+        for j in 0..k {
+            if pivot {
+                let norm = |c: usize| {
+                    (j..m).fold(T::zero(), |acc, i| {
+                        acc + r.values[i * n + c] * r.values[i * n + c]
+                    })
+                };
+                let mut best = j;
+                let mut best_norm = norm(j);
+                for c in j + 1..n {
+                    let candidate = norm(c);
+                    if candidate > best_norm {
+                        best = c;
+                        best_norm = candidate;
+                    }
+                }
+                if best != j {
+                    for i in 0..m {
+                        r.values.swap(i * n + j, i * n + best);
+                    }
+                    permutation.swap(j, best);
+                }
+            }
+
+            let mut v: Vec<T> = (j..m).map(|i| r.values[i * n + j]).collect();
+            let norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x).sqrt();
+            let alpha = if v[0] > T::zero() { -norm } else { norm };
+            v[0] = v[0] - alpha;
+            let v_norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+
+            if v_norm > T::zero() {
+                for c in j..n {
+                    let dot = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * r.values[i * n + c]);
+                    let scale = (dot + dot) / v_norm;
+                    for i in j..m {
+                        r.values[i * n + c] = r.values[i * n + c] - scale * v[i - j];
+                    }
+                }
+                for i in j + 1..m {
+                    r.values[i * n + j] = T::zero();
+                }
+            }
+            reflectors.push(v);
+        }
+
+        let mut q = Matrix::new(m, k, vec![T::zero(); m * k]);
+        for i in 0..k {
+            q.values[i * k + i] = T::one();
+        }
+        for (j, v) in reflectors.iter().enumerate().rev() {
+            let v_norm = v.iter().fold(T::zero(), |acc, &x| acc + x * x);
+            if v_norm == T::zero() {
+                continue;
+            }
+            for c in 0..k {
+                let dot = (j..m).fold(T::zero(), |acc, i| acc + v[i - j] * q.values[i * k + c]);
+                let scale = (dot + dot) / v_norm;
+                for i in j..m {
+                    q.values[i * k + c] = q.values[i * k + c] - scale * v[i - j];
+                }
+            }
+        }
+
+        let r = Matrix::new(k, n, r.values[..k * n].to_vec());
+        Qr { q, r, permutation }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::rank::RankTolerance;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        for (x, y) in a.values.iter().zip(&b.values) {
+            assert!((x - y).abs() < 1e-10, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_qr() {
+        let matrix: Matrix<f64> = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let qr = matrix.qr();
+
+        assert_close(&qr.q().mult_naive(qr.r()).unwrap(), &matrix);
+        assert_close(
+            &qr.q().transpose().mult_naive(qr.q()).unwrap(),
+            &Matrix::identity(2).unwrap(),
+        );
+        assert_eq!(qr.r().values[2], 0.0);
+
+        let b = Matrix::new(3, 1, vec![5.0, 11.0, 17.0]);
+        assert_close(
+            &qr.solve_least_squares(&b).unwrap(),
+            &Matrix::new(2, 1, vec![1.0, 2.0]),
+        );
+    }
+
+    #[test]
+    fn check_qr_pivoted() {
+        let matrix: Matrix<f64> = Matrix::new(2, 3, vec![1.0, 10.0, 2.0, 0.0, 1.0, 4.0]);
+        let qr = matrix.qr_pivoted();
+
+        assert_eq!(qr.permutation()[0], 1);
+        let mut permuted = Matrix::new(2, 3, vec![0.0; 6]);
+        for (j, &source) in qr.permutation().iter().enumerate() {
+            for i in 0..2 {
+                permuted.values[i * 3 + j] = matrix.values[i * 3 + source];
+            }
+        }
+        assert_close(&qr.q().mult_naive(qr.r()).unwrap(), &permuted);
+        assert!(qr.r().values[0].abs() >= qr.r().values[4].abs());
+    }
+
+    #[test]
+    fn check_qr_rank_deficient() {
+        // The third column is the sum of the first two.
+        let matrix: Matrix<f64> = Matrix::new(
+            4,
+            3,
+            vec![1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, -1.0, 1.0],
+        );
+        let qr = matrix.qr_pivoted();
+        assert_eq!(qr.rank(RankTolerance::Default), 2);
+        assert_eq!(qr.rank(RankTolerance::Absolute(10.0)), 0);
+
+        let b = matrix
+            .mult_naive(&Matrix::new(3, 1, vec![1.0, 2.0, 0.0]))
+            .unwrap();
+        let x = qr.solve_rank_deficient(&b, RankTolerance::Default).unwrap();
+        assert_close(&matrix.mult_naive(&x).unwrap(), &b);
+        assert_eq!(x.values.iter().filter(|v| **v == 0.0).count(), 1);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::{checked_len, Matrix};
+
+/// A source of uniformly random 64-bit words. Implement it for any
+/// generator to drive the random constructors; [`SplitMix64`] is the
+/// built-in one. Seeding the generator makes results reproducible.
+pub trait RandomSource {
+    fn next_u64(&mut self) -> u64;
+
+    /// Uniform on `[0, 1)` with 53 random bits.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A standard normal sample by the Box-Muller transform.
+    fn next_gaussian(&mut self) -> f64 {
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+    }
+}
+
+/// SplitMix64, a small generator with good statistical quality for
+/// rounding noise and test data. Not suitable for anything security
+/// related.
+#[derive(Debug, Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> SplitMix64 {
+        SplitMix64(seed)
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// The distribution each element of [`Matrix::random`] is drawn from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Distribution<T> {
+    /// Uniform on `[low, high)`.
+    Uniform {
+        low: T,
+        high: T,
+    },
+    Normal {
+        mean: T,
+        std_dev: T,
+    },
+}
+
+impl<T: Float> Distribution<T> {
+    fn check(&self) -> Result<(), MatrixError> {
+        let valid = match *self {
+            Distribution::Uniform { low, high } => {
+                low.to_f64().is_finite() && high.to_f64().is_finite() && low <= high
+            }
+            Distribution::Normal { mean, std_dev } => {
+                mean.to_f64().is_finite() && std_dev.to_f64().is_finite() && std_dev >= T::zero()
+            }
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(MatrixError::InvalidArgument(format!(
+                "Invalid distribution parameters {:?}",
+                self.map(Float::to_f64)
+            )))
+        }
+    }
+
+    fn map<U>(&self, f: impl Fn(T) -> U) -> Distribution<U> {
+        match *self {
+            Distribution::Uniform { low, high } => Distribution::Uniform {
+                low: f(low),
+                high: f(high),
+            },
+            Distribution::Normal { mean, std_dev } => Distribution::Normal {
+                mean: f(mean),
+                std_dev: f(std_dev),
+            },
+        }
+    }
+
+    fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> T {
+        match *self {
+            Distribution::Uniform { low, high } => low + (high - low) * T::from_f64(rng.next_f64()),
+            Distribution::Normal { mean, std_dev } => {
+                mean + std_dev * T::from_f64(rng.next_gaussian())
+            }
+        }
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// A `rows`x`cols` matrix of independent samples, drawn in row-major
+    /// order.
+    pub fn random<R: RandomSource + ?Sized>(
+        rows: usize,
+        cols: usize,
+        distribution: Distribution<T>,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        distribution.check()?;
+        let len = checked_len(rows, cols)?;
+        let values = (0..len).map(|_| distribution.sample(rng)).collect();
+        Ok(Matrix::new(rows, cols, values))
+    }
+
+    /// Samples uniform on `[0, 1)`.
+    pub fn random_uniform<R: RandomSource + ?Sized>(
+        rows: usize,
+        cols: usize,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let distribution = Distribution::Uniform {
+            low: T::zero(),
+            high: T::one(),
+        };
+        Matrix::random(rows, cols, distribution, rng)
+    }
+
+    /// Standard normal samples.
+    pub fn random_normal<R: RandomSource + ?Sized>(
+        rows: usize,
+        cols: usize,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let distribution = Distribution::Normal {
+            mean: T::zero(),
+            std_dev: T::one(),
+        };
+        Matrix::random(rows, cols, distribution, rng)
+    }
+
+    /// A random `n`x`n` orthogonal matrix, uniformly distributed over the
+    /// orthogonal group: the `Q` of a Gaussian matrix's QR factorization,
+    /// with column signs chosen so `R` has a positive diagonal.
+    pub fn random_orthogonal<R: RandomSource + ?Sized>(
+        n: usize,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let qr = Matrix::<T>::random_normal(n, n, rng)?.qr();
+        let mut q = qr.q().clone();
+        for j in 0..n {
+            if qr.r().values[j * n + j] < T::zero() {
+                for i in 0..n {
+                    q.values[i * n + j] = -q.values[i * n + j];
+                }
+            }
+        }
+        Ok(q)
+    }
+
+    /// A random `n`x`n` symmetric positive-definite matrix `A^T A + epsilon I`
+    /// for a Gaussian `A`. Its smallest eigenvalue is at least `epsilon`,
+    /// which must be positive.
+    pub fn random_spd<R: RandomSource + ?Sized>(
+        n: usize,
+        epsilon: T,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if !epsilon.to_f64().is_finite() || epsilon <= T::zero() {
+            return Err(MatrixError::InvalidArgument(
+                "epsilon must be positive and finite".to_string(),
+            ));
+        }
+        let a = Matrix::<T>::random_normal(n, n, rng)?;
+        let mut spd = a.transpose().mult_naive(&a)?;
+        for i in 0..n {
+            spd.values[i * n + i] += epsilon;
+        }
+        Ok(spd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::random::{Distribution, SplitMix64};
+
+    #[test]
+    fn check_random_matrices() {
+        let a: Matrix<f64> = Matrix::random_uniform(20, 20, &mut SplitMix64::new(3)).unwrap();
+        let b: Matrix<f64> = Matrix::random_uniform(20, 20, &mut SplitMix64::new(3)).unwrap();
+        assert_eq!(a, b);
+        assert!(a.values.iter().all(|v| (0.0..1.0).contains(v)));
+
+        let normal: Matrix<f64> = Matrix::random_normal(100, 100, &mut SplitMix64::new(5)).unwrap();
+        let mean = normal.sum() / 1e4;
+        let variance = normal
+            .values
+            .iter()
+            .map(|v| (v - mean).powi(2))
+            .sum::<f64>()
+            / 1e4;
+        assert!(mean.abs() < 0.05 && (variance - 1.0).abs() < 0.05);
+
+        let ranged = Distribution::Uniform {
+            low: -2.0f32,
+            high: -1.0,
+        };
+        let c = Matrix::random(3, 3, ranged, &mut SplitMix64::new(1)).unwrap();
+        assert!(c.values.iter().all(|v| (-2.0..-1.0).contains(v)));
+    }
+
+    #[test]
+    fn check_random_validation() {
+        let mut rng = SplitMix64::new(0);
+        let backwards = Distribution::Uniform {
+            low: 1.0,
+            high: 0.0,
+        };
+        let negative = Distribution::Normal {
+            mean: 0.0,
+            std_dev: -1.0,
+        };
+
+        assert!(Matrix::random(2, 2, backwards, &mut rng).is_err());
+        assert!(Matrix::random(2, 2, negative, &mut rng).is_err());
+        assert!(Matrix::<f64>::random_uniform(usize::MAX, 2, &mut rng).is_err());
+    }
+
+    #[test]
+    fn check_random_structured() {
+        let mut rng = SplitMix64::new(11);
+
+        let q: Matrix<f64> = Matrix::random_orthogonal(5, &mut rng).unwrap();
+        let gram = q.transpose().mult(&q).unwrap();
+        let identity: Matrix<f64> = Matrix::identity(5).unwrap();
+        assert!(gram
+            .values
+            .iter()
+            .zip(&identity.values)
+            .all(|(a, b)| (a - b).abs() < 1e-12));
+
+        let spd: Matrix<f64> = Matrix::random_spd(5, 1e-3, &mut rng).unwrap();
+        assert_eq!(spd, spd.transpose());
+        assert!(spd.cholesky().is_ok());
+        assert!(Matrix::<f64>::random_spd(5, 0.0, &mut rng).is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// Matrices whose smaller dimension is at most this use the SVD; larger
+/// ones use the cheaper pivoted QR.
+const SVD_MAX_ORDER: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RankTolerance {
+    /// `max(m, n) * epsilon * largest`, the convention of LAPACK and NumPy.
+    Default,
+    /// Values below this are treated as zero.
+    Absolute(f64),
+    /// Values below this fraction of the largest are treated as zero.
+    Relative(f64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankMethod {
+    PivotedQr,
+    Svd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericalRank {
+    pub rank: usize,
+    /// The absolute cutoff that was applied.
+    pub tolerance: f64,
+    pub method: RankMethod,
+}
+
+impl RankTolerance {
+    /// The absolute cutoff for an `rows`x`cols` matrix whose largest
+    /// singular value (or pivot) is `largest`.
+    pub(crate) fn cutoff<T: Float>(self, rows: usize, cols: usize, largest: f64) -> f64 {
+        match self {
+            RankTolerance::Default => rows.max(cols) as f64 * T::epsilon().to_f64() * largest,
+            RankTolerance::Absolute(cutoff) => cutoff,
+            RankTolerance::Relative(fraction) => fraction * largest,
+        }
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The number of singular values (or, for large matrices, diagonal
+    /// entries of a column-pivoted `R`) above the tolerance. Pivoted QR can
+    /// overestimate the rank of contrived matrices, which is why the SVD is
+    /// used whenever it is affordable.
+    pub fn numerical_rank(&self, tolerance: RankTolerance) -> Result<NumericalRank, MatrixError> {
+        let (method, magnitudes): (RankMethod, Vec<f64>) =
+            if self.rows.min(self.cols) <= SVD_MAX_ORDER {
+                let svd = self.svd()?;
+                let values = svd.singular_values().iter().map(|s| s.to_f64()).collect();
+                (RankMethod::Svd, values)
+            } else {
+                let qr = self.qr_pivoted();
+                let r = qr.r();
+                let values = (0..r.rows)
+                    .map(|i| r.values[i * r.cols + i].abs().to_f64())
+                    .collect();
+                (RankMethod::PivotedQr, values)
+            };
+
+        let largest = magnitudes.iter().cloned().fold(0.0, f64::max);
+        let cutoff = tolerance.cutoff::<T>(self.rows, self.cols, largest);
+
+        Ok(NumericalRank {
+            rank: magnitudes.iter().filter(|&&m| m > cutoff).count(),
+            tolerance: cutoff,
+            method,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::rank::{RankMethod, RankTolerance};
+
+    #[test]
+    fn check_numerical_rank() {
+        let matrix: Matrix<f64> =
+            Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let rank = matrix.numerical_rank(RankTolerance::Default).unwrap();
+
+        assert_eq!(rank.rank, 2);
+        assert_eq!(rank.method, RankMethod::Svd);
+        assert!(rank.tolerance > 0.0 && rank.tolerance < 1e-13);
+
+        let nearly: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1e-8]);
+        assert_eq!(
+            nearly.numerical_rank(RankTolerance::Default).unwrap().rank,
+            2
+        );
+        assert_eq!(
+            nearly
+                .numerical_rank(RankTolerance::Relative(1e-6))
+                .unwrap()
+                .rank,
+            1
+        );
+        assert_eq!(
+            nearly
+                .numerical_rank(RankTolerance::Absolute(2.0))
+                .unwrap()
+                .rank,
+            0
+        );
+    }
+
+    #[test]
+    fn check_numerical_rank_large() {
+        let n = 101;
+        let mut values = vec![0.0f64; n * n];
+        for i in 0..n - 1 {
+            values[i * n + i] = 1.0 + i as f64;
+            values[i * n + n - 1] = 1.0 + i as f64;
+        }
+        let matrix = Matrix::new(n, n, values);
+        let rank = matrix.numerical_rank(RankTolerance::Default).unwrap();
+
+        assert_eq!(rank.method, RankMethod::PivotedQr);
+        assert_eq!(rank.rank, n - 1);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+
+/// `k` reduced to a forward shift in `0..len`.
+fn shift_of(k: isize, len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (k as i128).rem_euclid(len as i128) as usize
+    }
+}
+
+fn repeated(count: usize, times: usize, axis: &str) -> Result<usize, MatrixError> {
+    count.checked_mul(times).ok_or_else(|| {
+        MatrixError::SizeOverflow(format!("Repeated {} count overflows usize", axis))
+    })
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Rotated by `k` quarter turns counterclockwise, as in NumPy; negative
+    /// `k` turns clockwise.
+    pub fn rot90(&self, k: i32) -> Matrix<T> {
+        let (rows, cols) = (self.rows, self.cols);
+        let at = |i: usize, j: usize| self.values[i * cols + j].clone();
+        match k.rem_euclid(4) {
+            0 => self.clone(),
+            1 => Matrix::from_fn(cols, rows, |i, j| at(j, cols - 1 - i)),
+            2 => Matrix::from_fn(rows, cols, |i, j| at(rows - 1 - i, cols - 1 - j)),
+            _ => Matrix::from_fn(cols, rows, |i, j| at(rows - 1 - j, i)),
+        }
+    }
+
+    /// Columns in reverse order, mirroring left to right.
+    pub fn fliplr(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            self.values[i * self.cols + self.cols - 1 - j].clone()
+        })
+    }
+
+    /// Rows in reverse order, mirroring top to bottom.
+    pub fn flipud(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            self.values[(self.rows - 1 - i) * self.cols + j].clone()
+        })
+    }
+
+    /// Rows shifted circularly down by `k`, so row `i` moves to row
+    /// `(i + k) mod rows`; negative `k` shifts up.
+    pub fn roll_rows(&self, k: isize) -> Matrix<T> {
+        let mut result = self.clone();
+        result
+            .values
+            .rotate_right(shift_of(k, self.rows) * self.cols);
+        result
+    }
+
+    /// Columns shifted circularly right by `k`; negative `k` shifts left.
+    pub fn roll_cols(&self, k: isize) -> Matrix<T> {
+        let shift = shift_of(k, self.cols);
+        let mut result = self.clone();
+        result.apply_rows(|row| row.rotate_right(shift));
+        result
+    }
+
+    /// This matrix repeated `reps_rows` times down and `reps_cols` times
+    /// across, as a grid of copies.
+    pub fn tile(&self, reps_rows: usize, reps_cols: usize) -> Result<Matrix<T>, MatrixError> {
+        let rows = repeated(self.rows, reps_rows, "row")?;
+        let cols = repeated(self.cols, reps_cols, "column")?;
+        let len = checked_len(rows, cols)?;
+        // With no elements to copy the loops below would only spin, which
+        // for a huge repeat count never ends.
+        if len == 0 {
+            return Ok(Matrix::new(rows, cols, Vec::new()));
+        }
+        let mut new_values = Vec::with_capacity(len);
+        for _ in 0..reps_rows {
+            for i in 0..self.rows {
+                for _ in 0..reps_cols {
+                    new_values.extend_from_slice(self.row_slice(i));
+                }
+            }
+        }
+        Ok(Matrix::new(rows, cols, new_values))
+    }
+
+    /// Each row repeated `times` times in place, so a 1xn row vector becomes
+    /// an explicit `times`xn matrix.
+    pub fn repeat_rows(&self, times: usize) -> Result<Matrix<T>, MatrixError> {
+        let rows = repeated(self.rows, times, "row")?;
+        let len = checked_len(rows, self.cols)?;
+        if len == 0 {
+            return Ok(Matrix::new(rows, self.cols, Vec::new()));
+        }
+        let mut new_values = Vec::with_capacity(len);
+        for i in 0..self.rows {
+            for _ in 0..times {
+                new_values.extend_from_slice(self.row_slice(i));
+            }
+        }
+        Ok(Matrix::new(rows, self.cols, new_values))
+    }
+
+    /// Each column repeated `times` times in place, so an mx1 column vector
+    /// becomes an explicit mx`times` matrix.
+    pub fn repeat_cols(&self, times: usize) -> Result<Matrix<T>, MatrixError> {
+        let cols = repeated(self.cols, times, "column")?;
+        let mut new_values = Vec::with_capacity(checked_len(self.rows, cols)?);
+        for value in &self.values {
+            for _ in 0..times {
+                new_values.push(value.clone());
+            }
+        }
+        Ok(Matrix::new(self.rows, cols, new_values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_rotations_and_flips() {
+        let image: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(image.rot90(1), Matrix::new(3, 2, vec![3, 6, 2, 5, 1, 4]));
+        assert_eq!(image.rot90(-1), Matrix::new(3, 2, vec![4, 1, 5, 2, 6, 3]));
+        assert_eq!(image.rot90(2).values, vec![6, 5, 4, 3, 2, 1]);
+        assert_eq!(image.rot90(4), image);
+        assert_eq!(image.rot90(1).rot90(3), image);
+
+        assert_eq!(image.fliplr().values, vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(image.flipud().values, vec![4, 5, 6, 1, 2, 3]);
+        assert_eq!(image.fliplr().flipud(), image.rot90(2));
+    }
+
+    #[test]
+    fn check_roll() {
+        let grid: Matrix<i32> = Matrix::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(grid.roll_rows(1).values, vec![5, 6, 1, 2, 3, 4]);
+        assert_eq!(grid.roll_rows(-1).values, vec![3, 4, 5, 6, 1, 2]);
+        assert_eq!(grid.roll_rows(3), grid);
+        assert_eq!(grid.roll_cols(1).values, vec![2, 1, 4, 3, 6, 5]);
+        assert_eq!(grid.roll_cols(-3), grid.roll_cols(1));
+
+        let empty: Matrix<i32> = Matrix::new(0, 4, vec![]);
+        assert_eq!(empty.roll_rows(2), empty);
+    }
+
+    #[test]
+    fn check_tile_and_repeat() {
+        let block: Matrix<i32> = Matrix::new(1, 2, vec![1, 2]);
+
+        assert_eq!(
+            block.tile(2, 2).unwrap(),
+            Matrix::new(2, 4, vec![1, 2, 1, 2, 1, 2, 1, 2])
+        );
+        assert_eq!(
+            block.repeat_rows(3).unwrap(),
+            Matrix::new(3, 2, vec![1, 2, 1, 2, 1, 2])
+        );
+        assert_eq!(block.repeat_cols(2).unwrap().values, vec![1, 1, 2, 2]);
+        assert_eq!(block.tile(0, 3).unwrap().get_rows(), 0);
+        assert!(block.tile(usize::MAX, 1).is_err());
+        let empty: Matrix<i32> = Matrix::new(0, 2, vec![]);
+        assert_eq!(empty.tile(usize::MAX, 1).unwrap().get_cols(), 2);
+        let no_cols: Matrix<i32> = Matrix::new(2, 0, vec![]);
+        assert_eq!(
+            no_cols.repeat_rows(usize::MAX / 2).unwrap().get_rows(),
+            usize::MAX - 1
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+
+/// Reductions return their operation's identity for empty input: sums are
+/// zero, products are one, and the row sums of an `m`x`0` matrix are `m`
+/// zeros.
+impl<T: Scalar> Matrix<T> {
+    pub fn sum(&self) -> T {
+        self.values
+            .iter()
+            .fold(T::zero(), |sum, value| sum + value.clone())
+    }
+
+    pub fn product(&self) -> T {
+        self.values
+            .iter()
+            .fold(T::one(), |product, value| product * value.clone())
+    }
+
+    /// The sum of the diagonal. A `0`x`0` matrix has trace zero.
+    pub fn trace(&self) -> Result<T, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+        Ok((0..self.rows).fold(T::zero(), |sum, i| {
+            sum + self.values[i * self.cols + i].clone()
+        }))
+    }
+
+    /// The `rows`x`1` column of per-row sums.
+    pub fn row_sums(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, 1, |i, _| {
+            self.values[i * self.cols..(i + 1) * self.cols]
+                .iter()
+                .fold(T::zero(), |sum, value| sum + value.clone())
+        })
+    }
+
+    /// The `1`x`cols` row of per-column sums.
+    pub fn col_sums(&self) -> Matrix<T> {
+        let mut new_values = vec![T::zero(); self.cols];
+        for row in self.values.chunks(self.cols.max(1)) {
+            for (sum, value) in new_values.iter_mut().zip(row) {
+                *sum += value.clone();
+            }
+        }
+        Matrix::new(1, self.cols, new_values)
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn frobenius_norm(&self) -> T {
+        self.values
+            .iter()
+            .fold(T::zero(), |sum, &value| sum + value * value)
+            .sqrt()
+    }
+
+    /// The largest absolute value of any element, zero for an empty matrix.
+    pub fn max_abs(&self) -> T {
+        self.values.iter().fold(
+            T::zero(),
+            |max, &value| {
+                if value.abs() > max {
+                    value.abs()
+                } else {
+                    max
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_reductions() {
+        let matrix: Matrix<i32> = Matrix::new(2, 3, vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(matrix.sum(), 21);
+        assert_eq!(matrix.product(), 720);
+        assert_eq!(matrix.row_sums(), Matrix::new(2, 1, vec![6, 15]));
+        assert_eq!(matrix.col_sums(), Matrix::new(1, 3, vec![5, 7, 9]));
+        assert!(matrix.trace().is_err());
+        assert_eq!(Matrix::<i32>::identity(4).unwrap().trace().unwrap(), 4);
+
+        let floats: Matrix<f64> = Matrix::new(2, 2, vec![3.0, 0.0, -4.0, 0.0]);
+        assert_eq!(floats.frobenius_norm(), 5.0);
+        assert_eq!(floats.max_abs(), 4.0);
+    }
+
+    #[test]
+    fn check_empty_reductions() {
+        let none: Matrix<f64> = Matrix::new(0, 0, vec![]);
+        let tall: Matrix<f64> = Matrix::new(3, 0, vec![]);
+        let wide: Matrix<f64> = Matrix::new(0, 2, vec![]);
+
+        assert_eq!((none.sum(), none.product()), (0.0, 1.0));
+        assert_eq!(none.trace().unwrap(), 0.0);
+        assert_eq!(none.lu().unwrap().determinant(), 1.0);
+        assert_eq!((tall.frobenius_norm(), tall.max_abs()), (0.0, 0.0));
+        assert_eq!(tall.row_sums(), Matrix::new(3, 1, vec![0.0; 3]));
+        assert_eq!(tall.col_sums(), Matrix::new(1, 0, vec![]));
+        assert_eq!(wide.col_sums(), Matrix::new(1, 2, vec![0.0; 2]));
+        assert_eq!(wide.row_sums(), Matrix::new(0, 1, vec![]));
+        assert!(Matrix::<f64>::try_new(0, 5, vec![]).is_ok());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::config;
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// The loss minimized by [`Matrix::irls`], with its tuning constant in units
+/// of the residual scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RobustLoss {
+    /// Quadratic near zero and linear beyond the constant.
+    Huber(f64),
+    /// Tukey's biweight, which gives residuals beyond the constant no
+    /// weight at all.
+    Tukey(f64),
+}
+
+impl RobustLoss {
+    /// Huber loss with the usual constant of 1.345, which is 95% efficient
+    /// for normal errors.
+    pub fn huber() -> RobustLoss {
+        RobustLoss::Huber(1.345)
+    }
+
+    /// Tukey's biweight with the usual constant of 4.685, which is 95%
+    /// efficient for normal errors.
+    pub fn tukey() -> RobustLoss {
+        RobustLoss::Tukey(4.685)
+    }
+
+    fn weight(self, scaled_residual: f64) -> f64 {
+        let u = scaled_residual.abs();
+        match self {
+            RobustLoss::Huber(c) => {
+                if u <= c {
+                    1.0
+                } else {
+                    c / u
+                }
+            }
+            RobustLoss::Tukey(c) => {
+                if u < c {
+                    let t = 1.0 - (u / c) * (u / c);
+                    t * t
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IrlsFit<T> {
+    pub coefficients: Matrix<T>,
+    /// The final weight of each observation; outliers end up near zero.
+    pub weights: Vec<T>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+impl<T: Float> Matrix<T> {
+    /// Solves `min sum_i weights[i] * (x_i b - y_i)^2` for `b`, where `self`
+    /// is the design matrix and `y` a column vector.
+    pub fn weighted_least_squares(
+        &self,
+        y: &Matrix<T>,
+        weights: &[T],
+    ) -> Result<Matrix<T>, MatrixError> {
+        if y.rows != self.rows || y.cols != 1 {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Solve,
+                lhs: (self.rows, self.cols),
+                rhs: (y.rows, y.cols),
+            });
+        }
+        if weights.len() != self.rows {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.rows,
+                actual: weights.len(),
+            });
+        }
+        if let Some(w) = weights
+            .iter()
+            .find(|&&w| w.partial_cmp(&T::zero()).is_none_or(|o| o.is_lt()))
+        {
+            return Err(MatrixError::InvalidArgument(format!(
+                "Weights must be non-negative, got {}",
+                w.to_f64()
+            )));
+        }
+
+        let mut scaled_x = self.clone();
+        let mut scaled_y = y.clone();
+        for (i, &w) in weights.iter().enumerate() {
+            let root = w.sqrt();
+            for value in &mut scaled_x.values[i * self.cols..(i + 1) * self.cols] {
+                *value = *value * root;
+            }
+            scaled_y.values[i] = scaled_y.values[i] * root;
+        }
+        scaled_x.qr().solve_least_squares(&scaled_y)
+    }
+
+    /// Robust regression by iteratively reweighted least squares, starting
+    /// from ordinary least squares. Residuals are scaled by their median
+    /// absolute deviation each iteration. Stops when no coefficient moves
+    /// by more than the configured tolerance (relative to the largest), or
+    /// after the configured maximum number of iterations.
+    pub fn irls(&self, y: &Matrix<T>, loss: RobustLoss) -> Result<IrlsFit<T>, MatrixError> {
+        let settings = config::current();
+        let mut weights = vec![T::one(); self.rows];
+        let mut coefficients = self.weighted_least_squares(y, &weights)?;
+
+        for iteration in 1..=settings.max_iterations {
+            let fitted = self.mult_naive(&coefficients)?;
+            let residuals: Vec<f64> = (0..self.rows)
+                .map(|i| (y.values[i] - fitted.values[i]).to_f64())
+                .collect();
+
+            let mut deviations: Vec<f64> = residuals.iter().map(|r| r.abs()).collect();
+            deviations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let scale = median(&deviations) / 0.6745;
+            if scale == 0.0 {
+                return Ok(IrlsFit {
+                    coefficients,
+                    weights,
+                    iterations: iteration - 1,
+                    converged: true,
+                });
+            }
+
+            weights = residuals
+                .iter()
+                .map(|r| T::from_f64(loss.weight(r / scale)))
+                .collect();
+            let next = self.weighted_least_squares(y, &weights)?;
+
+            let largest = next
+                .values
+                .iter()
+                .fold(0.0f64, |max, v| max.max(v.to_f64().abs()));
+            let change = next
+                .values
+                .iter()
+                .zip(&coefficients.values)
+                .fold(0.0f64, |max, (a, b)| max.max((*a - *b).to_f64().abs()));
+            coefficients = next;
+            if change <= settings.tolerance * largest.max(1.0) {
+                return Ok(IrlsFit {
+                    coefficients,
+                    weights,
+                    iterations: iteration,
+                    converged: true,
+                });
+            }
+        }
+
+        Ok(IrlsFit {
+            coefficients,
+            weights,
+            iterations: settings.max_iterations,
+            converged: false,
+        })
+    }
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        n if !n.is_multiple_of(2) => sorted[n / 2],
+        n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::regression::RobustLoss;
+
+    fn line_with_outlier() -> (Matrix<f64>, Matrix<f64>) {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let noise = [0.05, -0.03, 0.02, -0.04, 0.01, 0.03, -0.02, 0.0];
+        let design = xs.iter().flat_map(|&x| [1.0, x]).collect();
+        let mut response: Vec<f64> = xs
+            .iter()
+            .zip(noise)
+            .map(|(&x, e)| 1.0 + 2.0 * x + e)
+            .collect();
+        response[6] += 40.0;
+        (Matrix::new(8, 2, design), Matrix::new(8, 1, response))
+    }
+
+    #[test]
+    fn check_weighted_least_squares() {
+        let (x, y) = line_with_outlier();
+        let mut weights = vec![1.0; 8];
+        weights[6] = 0.0;
+
+        let fit = x.weighted_least_squares(&y, &weights).unwrap();
+        assert!((fit.values[0] - 1.0).abs() < 0.1);
+        assert!((fit.values[1] - 2.0).abs() < 0.1);
+        assert!(x.weighted_least_squares(&y, &[1.0; 3]).is_err());
+        assert!(x.weighted_least_squares(&y, &[-1.0; 8]).is_err());
+    }
+
+    #[test]
+    fn check_irls() {
+        let (x, y) = line_with_outlier();
+        let ordinary = x.weighted_least_squares(&y, &[1.0; 8]).unwrap();
+        assert!((ordinary.values[1] - 2.0).abs() > 1.0);
+
+        for loss in [RobustLoss::huber(), RobustLoss::tukey()] {
+            let fit = x.irls(&y, loss).unwrap();
+            assert!(fit.converged);
+            assert!((fit.coefficients.values[0] - 1.0).abs() < 0.2);
+            assert!((fit.coefficients.values[1] - 2.0).abs() < 0.1);
+            assert!(fit.weights[6] < 0.1);
+        }
+    }
+}
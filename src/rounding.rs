@@ -0,0 +1,258 @@
+use crate::error::{MatrixError, Op};
+use crate::matrix::Matrix;
+use crate::random::RandomSource;
+use std::fmt;
+
+/// How a low-precision kernel rounds results that do not fit its format.
+///
+/// Round-to-nearest drops any update smaller than half a unit in the last
+/// place, so long accumulations can stall. Stochastic rounding rounds up
+/// with probability proportional to the discarded fraction, which is
+/// unbiased in expectation. The noise comes from the borrowed generator,
+/// which carries on where the previous call stopped, so a training loop
+/// passes the same generator on every step and sees fresh noise each time.
+pub enum Rounding<'a> {
+    Nearest,
+    Stochastic(&'a mut dyn RandomSource),
+}
+
+impl fmt::Debug for Rounding<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rounding::Nearest => write!(f, "Nearest"),
+            Rounding::Stochastic(_) => write!(f, "Stochastic"),
+        }
+    }
+}
+
+impl Rounding<'_> {
+    /// The generator drawing the rounding noise, or `None` when rounding
+    /// to nearest.
+    pub(crate) fn noise(&mut self) -> Option<&mut dyn RandomSource> {
+        match self {
+            Rounding::Nearest => None,
+            Rounding::Stochastic(rng) => Some(&mut **rng),
+        }
+    }
+}
+
+/// Rounds an `f32` to bfloat16 precision (8 exponent bits, 7 mantissa
+/// bits), keeping it stored as `f32`.
+fn bf16_rounded(value: f32, noise: Option<&mut dyn RandomSource>) -> f32 {
+    if value.is_nan() {
+        return value;
+    }
+    let bits = value.to_bits();
+    let offset = match noise {
+        None => 0x7FFF + ((bits >> 16) & 1),
+        Some(rng) => (rng.next_u64() & 0xFFFF) as u32,
+    };
+    f32::from_bits(bits.wrapping_add(offset) & 0xFFFF_0000)
+}
+
+/// The largest finite IEEE half-precision value.
+const F16_MAX: f32 = 65504.0;
+
+/// Rounds an `f32` to IEEE half precision (5 exponent bits, 10 mantissa
+/// bits), keeping it stored as `f32`. Values below the smallest normal half
+/// keep its spacing of `2^-24`, as half-precision subnormals do, and values
+/// beyond the largest finite half become infinite.
+fn f16_rounded(value: f32, noise: Option<&mut dyn RandomSource>) -> f32 {
+    if !value.is_finite() || value == 0.0 {
+        return value;
+    }
+    let exponent = ((value.to_bits() >> 23) & 0xFF) as i32 - 127;
+    let spacing = 2f32.powi(exponent.max(-14) - 10);
+    // Dividing by a power of two is exact, so only the rounding step below
+    // discards anything.
+    let scaled = value / spacing;
+    let rounded = match noise {
+        None => scaled.round_ties_even(),
+        Some(rng) => (scaled + rng.next_f64() as f32).floor(),
+    } * spacing;
+    if rounded.abs() > F16_MAX {
+        f32::INFINITY.copysign(value)
+    } else {
+        rounded
+    }
+}
+
+impl Matrix<f32> {
+    /// Rounds every element to bfloat16 precision while keeping `f32`
+    /// storage, for simulating bf16 accumulation between kernel calls.
+    pub fn round_to_bf16(&self, rounding: Rounding<'_>) -> Matrix<f32> {
+        self.round_with(rounding, bf16_rounded)
+    }
+
+    /// Rounds every element to IEEE half precision while keeping `f32`
+    /// storage, like [`Matrix::round_to_bf16`].
+    pub fn round_to_f16(&self, rounding: Rounding<'_>) -> Matrix<f32> {
+        self.round_with(rounding, f16_rounded)
+    }
+
+    /// `self <- self + alpha * update` for a bf16 accumulator, rounding each
+    /// sum back to bf16. This is where round-to-nearest stalls: an update
+    /// below half a unit in the last place of its element is dropped on
+    /// every step, while stochastic rounding keeps it in expectation.
+    pub fn add_scaled_bf16(
+        &mut self,
+        alpha: f32,
+        update: &Matrix<f32>,
+        rounding: Rounding<'_>,
+    ) -> Result<&mut Self, MatrixError> {
+        self.add_scaled_with(alpha, update, rounding, bf16_rounded)
+    }
+
+    /// [`Matrix::add_scaled_bf16`] for a half-precision accumulator.
+    pub fn add_scaled_f16(
+        &mut self,
+        alpha: f32,
+        update: &Matrix<f32>,
+        rounding: Rounding<'_>,
+    ) -> Result<&mut Self, MatrixError> {
+        self.add_scaled_with(alpha, update, rounding, f16_rounded)
+    }
+
+    fn round_with(
+        &self,
+        mut rounding: Rounding<'_>,
+        round: fn(f32, Option<&mut dyn RandomSource>) -> f32,
+    ) -> Matrix<f32> {
+        let new_values = self
+            .values
+            .iter()
+            .map(|&v| round(v, rounding.noise()))
+            .collect();
+
+        Matrix::new(self.rows, self.cols, new_values)
+    }
+
+    fn add_scaled_with(
+        &mut self,
+        alpha: f32,
+        update: &Matrix<f32>,
+        mut rounding: Rounding<'_>,
+        round: fn(f32, Option<&mut dyn RandomSource>) -> f32,
+    ) -> Result<&mut Self, MatrixError> {
+        self.check_same_shape(update, Op::Add)?;
+
+        for (value, &u) in self.values.iter_mut().zip(&update.values) {
+            *value = round(*value + alpha * u, rounding.noise());
+        }
+
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::random::SplitMix64;
+    use crate::rounding::Rounding;
+
+    #[test]
+    fn check_bf16_rounding() {
+        let value = 1.0 + 1.0 / 512.0;
+        let matrix: Matrix<f32> = Matrix::new(1, 4000, vec![value; 4000]);
+
+        let nearest = matrix.round_to_bf16(Rounding::Nearest);
+        assert!(nearest.values.iter().all(|&v| v == 1.0));
+        assert_eq!(
+            Matrix::new(1, 2, vec![1.0f32 + 1.0 / 128.0, -2.0]).round_to_bf16(Rounding::Nearest),
+            Matrix::new(1, 2, vec![1.0 + 1.0 / 128.0, -2.0])
+        );
+
+        let mut rng = SplitMix64::new(7);
+        let stochastic = matrix.round_to_bf16(Rounding::Stochastic(&mut rng));
+        assert!(stochastic
+            .values
+            .iter()
+            .all(|&v| v == 1.0 || v == 1.0 + 1.0 / 128.0));
+        let mean = stochastic.values.iter().sum::<f32>() / 4000.0;
+        assert!((mean - value).abs() < 0.001);
+    }
+
+    #[test]
+    fn check_f16_rounding() {
+        let nearest = Matrix::new(
+            1,
+            6,
+            vec![
+                1.0f32 + 1.0 / 4096.0,
+                -3.0,
+                65519.0,
+                65520.0,
+                1e-8,
+                f32::NAN,
+            ],
+        )
+        .round_to_f16(Rounding::Nearest);
+        assert_eq!(
+            nearest.values[..5],
+            [1.0, -3.0, 65504.0, f32::INFINITY, 0.0]
+        );
+        assert!(nearest.values[5].is_nan());
+        // The smallest half-precision subnormal.
+        let tiny = 2f32.powi(-24);
+        assert_eq!(
+            Matrix::new(1, 1, vec![tiny * 0.75]).round_to_f16(Rounding::Nearest),
+            Matrix::new(1, 1, vec![tiny])
+        );
+
+        let value = 1.0 + 1.0 / 4096.0;
+        let matrix: Matrix<f32> = Matrix::new(1, 4000, vec![value; 4000]);
+        let mut rng = SplitMix64::new(3);
+        let stochastic = matrix.round_to_f16(Rounding::Stochastic(&mut rng));
+        assert!(stochastic
+            .values
+            .iter()
+            .all(|&v| v == 1.0 || v == 1.0 + 1.0 / 1024.0));
+        let mean = stochastic.values.iter().sum::<f32>() / 4000.0;
+        assert!((mean - value).abs() < 1e-4);
+    }
+
+    #[test]
+    fn check_add_scaled_bf16() {
+        // Each step adds a quarter of the accumulator's bf16 spacing, with
+        // one generator carried across all the steps.
+        let step: Matrix<f32> = Matrix::new(1, 16, vec![1.0 / 512.0; 16]);
+        let mut nearest: Matrix<f32> = Matrix::new(1, 16, vec![1.0; 16]);
+        let mut stochastic = nearest.clone();
+        let mut rng = SplitMix64::new(42);
+        for _ in 0..400 {
+            nearest
+                .add_scaled_bf16(1.0, &step, Rounding::Nearest)
+                .unwrap();
+            stochastic
+                .add_scaled_bf16(1.0, &step, Rounding::Stochastic(&mut rng))
+                .unwrap();
+        }
+        assert_eq!(nearest.values, [1.0; 16]);
+        // The exact sum is 1.78125, and bf16 has a spacing of 1/128 there.
+        let mean = stochastic.values.iter().sum::<f32>() / 16.0;
+        assert!((mean - 1.78125).abs() < 0.08, "{}", mean);
+
+        assert!(nearest
+            .add_scaled_bf16(1.0, &step.transpose(), Rounding::Nearest)
+            .is_err());
+    }
+
+    #[test]
+    fn check_add_scaled_f16() {
+        let step: Matrix<f32> = Matrix::new(1, 16, vec![1.0 / 4096.0; 16]);
+        let mut nearest: Matrix<f32> = Matrix::new(1, 16, vec![1.0; 16]);
+        let mut stochastic = nearest.clone();
+        let mut rng = SplitMix64::new(42);
+        for _ in 0..400 {
+            nearest
+                .add_scaled_f16(1.0, &step, Rounding::Nearest)
+                .unwrap();
+            stochastic
+                .add_scaled_f16(1.0, &step, Rounding::Stochastic(&mut rng))
+                .unwrap();
+        }
+        assert_eq!(nearest.values, [1.0; 16]);
+        let mean = stochastic.values.iter().sum::<f32>() / 16.0;
+        assert!((mean - (1.0 + 400.0 / 4096.0)).abs() < 0.01, "{}", mean);
+    }
+}
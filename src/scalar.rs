@@ -0,0 +1,57 @@
+use crate::identity_element::IdentityElement;
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+/// The element arithmetic most matrix operations need: a ring with `+`,
+/// `-`, `*` and identities. Implemented automatically for every type that
+/// provides those operations, including [`Complex`](crate::complex::Complex),
+/// [`Fixed32`](crate::fixed::Fixed32) and user-defined rationals or bigints
+/// once they implement [`IdentityElement`] and multiplication by
+/// reference.
+///
+/// The crate has no dependencies, so there is no `num-traits` bridge: a
+/// type from another crate, such as `num_rational::Ratio`, joins through a
+/// newtype whose `zero` and `one` forward to the wrapped type's.
+pub trait Scalar:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + MulRef
+    + Clone
+    + Default
+    + AddAssign
+    + IdentityElement
+{
+}
+
+impl<T> Scalar for T where
+    T: Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + MulRef
+        + Clone
+        + Default
+        + AddAssign
+        + IdentityElement
+{
+}
+
+/// `&a * &b`, so product kernels can multiply elements in their inner
+/// loops without cloning either operand. Implemented for every type whose
+/// references multiply; as a supertrait it also lets generic code bounded
+/// by [`Scalar`] or [`Float`](crate::float::Float) alone reach it.
+pub trait MulRef {
+    fn mul_ref(&self, other: &Self) -> Self;
+}
+
+impl<T> MulRef for T
+where
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    fn mul_ref(&self, other: &T) -> T {
+        self * other
+    }
+}
+
+/// The real-number elements the decompositions and solvers need. Every
+/// `RealScalar` is also a [`Scalar`].
+pub use crate::float::Float as RealScalar;
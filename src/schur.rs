@@ -0,0 +1,345 @@
+use crate::complex::Complex;
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+/// Iterations allowed per eigenvalue before giving up.
+const MAX_ITERATIONS: usize = 100;
+
+/// A real Schur decomposition `A = Q T Q^T`.
+///
+/// `Q` is orthogonal and `T` is quasi-upper-triangular: upper triangular
+/// except for 2x2 blocks on the diagonal, one for each pair of complex
+/// conjugate eigenvalues.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Schur<T> {
+    q: Matrix<T>,
+    t: Matrix<T>,
+}
+
+impl<T: Float> Schur<T> {
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    pub fn t(&self) -> &Matrix<T> {
+        &self.t
+    }
+
+    /// The eigenvalues read off the diagonal blocks of `T`, in order. Each
+    /// complex pair appears with the positive imaginary part first.
+    pub fn eigenvalues(&self) -> Vec<Complex<T>> {
+        let n = self.t.rows;
+        let t = |i: usize, j: usize| self.t.values[i * n + j];
+        let mut values = Vec::with_capacity(n);
+        let mut i = 0;
+        while i < n {
+            if i + 1 < n && t(i + 1, i) != T::zero() {
+                let two = T::from_f64(2.0);
+                let re = (t(i, i) + t(i + 1, i + 1)) / two;
+                let half_gap = (t(i, i) - t(i + 1, i + 1)) / two;
+                let im = (-(half_gap * half_gap + t(i, i + 1) * t(i + 1, i)))
+                    .abs()
+                    .sqrt();
+                values.push(Complex::new(re, im));
+                values.push(Complex::new(re, -im));
+                i += 2;
+            } else {
+                values.push(Complex::new(t(i, i), T::zero()));
+                i += 1;
+            }
+        }
+        values
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// The real Schur decomposition, by Francis double-shift QR iteration
+    /// on [`Matrix::hessenberg`]. Real eigenvalue pairs are split so that
+    /// every remaining 2x2 block holds a complex pair.
+    pub fn schur(&self) -> Result<Schur<T>, MatrixError> {
+        let size = self.rows;
+        let (mut h, mut v) = self.hessenberg()?.into_parts();
+        let mut complex_block = vec![false; size];
+
+        let zero = T::zero();
+        let eps = T::epsilon();
+        let norm = (0..size)
+            .flat_map(|i| (i.saturating_sub(1)..size).map(move |j| (i, j)))
+            .fold(zero, |acc, (i, j)| acc + h.values[i * size + j].abs());
+
+        let mut n = size as isize - 1;
+        let mut iter = 0;
+        let mut exshift = zero;
+        while n >= 0 {
+            let nu = n as usize;
+            let at = |h: &Matrix<T>, i: usize, j: usize| h.values[i * size + j];
+
+            let mut l = nu;
+            while l > 0 {
+                let mut s = at(&h, l - 1, l - 1).abs() + at(&h, l, l).abs();
+                if s == zero {
+                    s = norm;
+                }
+                if at(&h, l, l - 1).abs() < eps * s {
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == nu {
+                h.values[nu * size + nu] = at(&h, nu, nu) + exshift;
+                n -= 1;
+                iter = 0;
+            } else if l + 1 == nu {
+                let m = nu - 1;
+                let w = at(&h, nu, m) * at(&h, m, nu);
+                let p = (at(&h, m, m) - at(&h, nu, nu)) / T::from_f64(2.0);
+                let q = p * p + w;
+                h.values[nu * size + nu] = at(&h, nu, nu) + exshift;
+                h.values[m * size + m] = at(&h, m, m) + exshift;
+
+                if q >= zero {
+                    // Rotates the block so its real eigenvalues split.
+                    let z = q.abs().sqrt();
+                    let z = if p >= zero { p + z } else { p - z };
+                    let x = at(&h, nu, m);
+                    let s = x.abs() + z.abs();
+                    let (p, q) = (x / s, z / s);
+                    let r = (p * p + q * q).sqrt();
+                    let (p, q) = (p / r, q / r);
+
+                    for j in m..size {
+                        let z = at(&h, m, j);
+                        h.values[m * size + j] = q * z + p * at(&h, nu, j);
+                        h.values[nu * size + j] = q * at(&h, nu, j) - p * z;
+                    }
+                    for (matrix, rows) in [(&mut h, nu + 1), (&mut v, size)] {
+                        for i in 0..rows {
+                            let row = &mut matrix.values[i * size..(i + 1) * size];
+                            let z = row[m];
+                            row[m] = q * z + p * row[nu];
+                            row[nu] = q * row[nu] - p * z;
+                        }
+                    }
+                    h.values[nu * size + m] = zero;
+                } else {
+                    complex_block[m] = true;
+                }
+                n -= 2;
+                iter = 0;
+            } else {
+                if iter == MAX_ITERATIONS {
+                    return Err(MatrixError::NonConvergent {
+                        iterations: iter,
+                        residual: at(&h, nu, nu - 1).abs().to_f64(),
+                    });
+                }
+
+                let mut x = at(&h, nu, nu);
+                let mut y = at(&h, nu - 1, nu - 1);
+                let mut w = at(&h, nu, nu - 1) * at(&h, nu - 1, nu);
+
+                // Exceptional shifts break the cycles the standard shift
+                // can fall into.
+                if iter == 10 {
+                    exshift += x;
+                    for i in 0..=nu {
+                        h.values[i * size + i] = at(&h, i, i) - x;
+                    }
+                    let s = at(&h, nu, nu - 1).abs() + at(&h, nu - 1, nu - 2).abs();
+                    x = T::from_f64(0.75) * s;
+                    y = x;
+                    w = T::from_f64(-0.4375) * s * s;
+                }
+                if iter == 30 {
+                    let half = (y - x) / T::from_f64(2.0);
+                    let s = half * half + w;
+                    if s > zero {
+                        let s = s.sqrt();
+                        let s = if y < x { -s } else { s };
+                        let s = x - w / (half + s);
+                        for i in 0..=nu {
+                            h.values[i * size + i] = at(&h, i, i) - s;
+                        }
+                        exshift += s;
+                        x = T::from_f64(0.964);
+                        y = x;
+                        w = x;
+                    }
+                }
+                iter += 1;
+
+                // Looks for two consecutive small subdiagonal entries.
+                let mut m = nu - 2;
+                let (mut p, mut q, mut r);
+                loop {
+                    let z = at(&h, m, m);
+                    let (rr, ss) = (x - z, y - z);
+                    p = (rr * ss - w) / at(&h, m + 1, m) + at(&h, m, m + 1);
+                    q = at(&h, m + 1, m + 1) - z - rr - ss;
+                    r = at(&h, m + 2, m + 1);
+                    let s = p.abs() + q.abs() + r.abs();
+                    p = p / s;
+                    q = q / s;
+                    r = r / s;
+                    if m == l {
+                        break;
+                    }
+                    let lhs = at(&h, m, m - 1).abs() * (q.abs() + r.abs());
+                    let rhs = eps
+                        * (p.abs()
+                            * (at(&h, m - 1, m - 1).abs() + z.abs() + at(&h, m + 1, m + 1).abs()));
+                    if lhs < rhs {
+                        break;
+                    }
+                    m -= 1;
+                }
+                for i in m + 2..=nu {
+                    h.values[i * size + i - 2] = zero;
+                    if i > m + 2 {
+                        h.values[i * size + i - 3] = zero;
+                    }
+                }
+
+                // A double QR step on rows l..=n and columns m..=n.
+                for k in m..nu {
+                    let not_last = k != nu - 1;
+                    let mut scale = T::one();
+                    if k != m {
+                        p = at(&h, k, k - 1);
+                        q = at(&h, k + 1, k - 1);
+                        r = if not_last { at(&h, k + 2, k - 1) } else { zero };
+                        scale = p.abs() + q.abs() + r.abs();
+                        if scale == zero {
+                            continue;
+                        }
+                        p = p / scale;
+                        q = q / scale;
+                        r = r / scale;
+                    }
+                    let s = (p * p + q * q + r * r).sqrt();
+                    let s = if p < zero { -s } else { s };
+                    if s == zero {
+                        continue;
+                    }
+                    if k != m {
+                        h.values[k * size + k - 1] = -s * scale;
+                    } else if l != m {
+                        h.values[k * size + k - 1] = -at(&h, k, k - 1);
+                    }
+                    let p = p + s;
+                    let (x, y, z) = (p / s, q / s, r / s);
+                    let (q, r) = (q / p, r / p);
+
+                    for j in k..size {
+                        let mut p = at(&h, k, j) + q * at(&h, k + 1, j);
+                        if not_last {
+                            p += r * at(&h, k + 2, j);
+                            h.values[(k + 2) * size + j] = at(&h, k + 2, j) - p * z;
+                        }
+                        h.values[k * size + j] = at(&h, k, j) - p * x;
+                        h.values[(k + 1) * size + j] = at(&h, k + 1, j) - p * y;
+                    }
+                    for (matrix, rows) in [(&mut h, nu.min(k + 3) + 1), (&mut v, size)] {
+                        for i in 0..rows {
+                            let row = &mut matrix.values[i * size..(i + 1) * size];
+                            let mut p = x * row[k] + y * row[k + 1];
+                            if not_last {
+                                p += z * row[k + 2];
+                                row[k + 2] = row[k + 2] - p * r;
+                            }
+                            row[k] = row[k] - p;
+                            row[k + 1] = row[k + 1] - p * q;
+                        }
+                    }
+                }
+            }
+        }
+
+        for i in 1..size {
+            if !complex_block[i - 1] {
+                h.values[i * size + i - 1] = zero;
+            }
+            for j in 0..i - 1 {
+                h.values[i * size + j] = zero;
+            }
+        }
+
+        Ok(Schur { q: v, t: h })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!((a.rows, a.cols), (b.rows, b.cols));
+        for (x, y) in a.values.iter().zip(&b.values) {
+            assert!((x - y).abs() < 1e-9, "{} != {}", x, y);
+        }
+    }
+
+    #[test]
+    fn check_schur() {
+        let matrix: Matrix<f64> = Matrix::new(
+            4,
+            4,
+            vec![
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+        );
+        let schur = matrix.schur().unwrap();
+        let (q, t) = (schur.q(), schur.t());
+
+        assert_close(
+            &q.transpose().mult_naive(q).unwrap(),
+            &Matrix::identity(4).unwrap(),
+        );
+        assert_close(
+            &q.mult_naive(t).unwrap().mult_naive(&q.transpose()).unwrap(),
+            &matrix,
+        );
+        for i in 1..4 {
+            assert_eq!(t.values[i * 4 + i - 1], 0.0);
+        }
+    }
+
+    #[test]
+    fn check_schur_complex_pairs() {
+        // Rotation blocks with eigenvalues 1 +- 2i and -3 +- i.
+        let matrix: Matrix<f64> = Matrix::new(
+            5,
+            5,
+            vec![
+                1.0, -2.0, 0.5, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, -3.0, 1.0, 2.0, 0.0,
+                0.0, -1.0, -3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 5.0,
+            ],
+        );
+        let schur = matrix.schur().unwrap();
+        let (q, t) = (schur.q(), schur.t());
+        assert_close(
+            &q.mult_naive(t).unwrap().mult_naive(&q.transpose()).unwrap(),
+            &matrix,
+        );
+
+        let mut values: Vec<(f64, f64)> = schur
+            .eigenvalues()
+            .iter()
+            .map(|value| (value.re, value.im))
+            .collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let expected = [
+            (-3.0, -1.0),
+            (-3.0, 1.0),
+            (1.0, -2.0),
+            (1.0, 2.0),
+            (5.0, 0.0),
+        ];
+        for (value, expected) in values.iter().zip(&expected) {
+            assert!((value.0 - expected.0).abs() < 1e-9 && (value.1 - expected.1).abs() < 1e-9);
+        }
+        assert!(Matrix::<f64>::new(2, 3, vec![0.0; 6]).schur().is_err());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+impl<T: Clone> Matrix<T> {
+    /// A new matrix made of the listed rows, in the order given. Indices may
+    /// repeat, e.g. for bootstrap resampling.
+    pub fn select_rows(&self, rows: &[usize]) -> Result<Matrix<T>, MatrixError> {
+        for &row in rows {
+            self.check_row(row)?;
+        }
+        Ok(Matrix::new(rows.len(), self.cols, self.gather_rows(rows)))
+    }
+
+    /// A new matrix made of the listed columns, in the order given.
+    pub fn select_cols(&self, cols: &[usize]) -> Result<Matrix<T>, MatrixError> {
+        for &col in cols {
+            self.check_col(col)?;
+        }
+
+        let mut new_values = Vec::with_capacity(self.rows * cols.len());
+        for i in 0..self.rows {
+            for &j in cols {
+                new_values.push(self.values[i * self.cols + j].clone());
+            }
+        }
+        Ok(Matrix::new(self.rows, cols.len(), new_values))
+    }
+
+    pub(crate) fn row_slice(&self, row: usize) -> &[T] {
+        &self.values[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The listed rows concatenated, without bounds checks.
+    pub(crate) fn gather_rows(&self, rows: &[usize]) -> Vec<T> {
+        let mut new_values = Vec::with_capacity(rows.len() * self.cols);
+        for &row in rows {
+            new_values.extend_from_slice(self.row_slice(row));
+        }
+        new_values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_select() {
+        let matrix: Matrix<i32> = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert_eq!(
+            matrix.select_rows(&[2, 0, 2]).unwrap(),
+            Matrix::new(3, 3, vec![7, 8, 9, 1, 2, 3, 7, 8, 9])
+        );
+        assert_eq!(
+            matrix.select_cols(&[1]).unwrap(),
+            Matrix::new(3, 1, vec![2, 5, 8])
+        );
+        assert_eq!(matrix.select_rows(&[]).unwrap().get_rows(), 0);
+        assert!(matrix.select_rows(&[3]).is_err());
+        assert!(matches!(
+            matrix.select_cols(&[0, 3]),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (0, 3),
+                dims: (3, 3)
+            })
+        ));
+    }
+}
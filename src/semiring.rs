@@ -0,0 +1,202 @@
+use crate::error::{product_mismatch, MatrixError};
+use crate::identity_element::IdentityElement;
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::MulRef;
+use std::ops::Add;
+
+/// The algebra used by [`Matrix::mult_semiring`]: an associative "addition"
+/// with identity `zero` and a "multiplication" with identity `one` that
+/// distributes over it.
+pub trait Semiring<T> {
+    fn zero() -> T;
+    fn one() -> T;
+    fn add(a: T, b: T) -> T;
+    fn mul(a: &T, b: &T) -> T;
+}
+
+/// Ordinary `+` and `*`.
+pub struct Standard;
+
+/// `min` as addition and `+` as multiplication, with infinity as zero.
+/// Multiplying adjacency matrices in this semiring relaxes shortest paths.
+pub struct MinPlus;
+
+/// `max` as addition and `+` as multiplication, with negative infinity as zero.
+pub struct MaxPlus;
+
+/// `||` as addition and `&&` as multiplication, for reachability queries.
+pub struct Boolean;
+
+impl<T> Semiring<T> for Standard
+where
+    T: Add<Output = T> + MulRef + Clone + IdentityElement,
+{
+    fn zero() -> T {
+        T::zero()
+    }
+    fn one() -> T {
+        T::one()
+    }
+    fn add(a: T, b: T) -> T {
+        a + b
+    }
+    fn mul(a: &T, b: &T) -> T {
+        a.mul_ref(b)
+    }
+}
+
+macro_rules! impl_tropical {
+    ($($t:ty),*) => {
+        $(
+            impl Semiring<$t> for MinPlus {
+                fn zero() -> $t {
+                    <$t>::INFINITY
+                }
+                fn one() -> $t {
+                    0.0
+                }
+                fn add(a: $t, b: $t) -> $t {
+                    a.min(b)
+                }
+                fn mul(a: &$t, b: &$t) -> $t {
+                    a + b
+                }
+            }
+
+            impl Semiring<$t> for MaxPlus {
+                fn zero() -> $t {
+                    <$t>::NEG_INFINITY
+                }
+                fn one() -> $t {
+                    0.0
+                }
+                fn add(a: $t, b: $t) -> $t {
+                    a.max(b)
+                }
+                fn mul(a: &$t, b: &$t) -> $t {
+                    a + b
+                }
+            }
+        )*
+    };
+}
+
+impl_tropical!(f32, f64);
+
+impl Semiring<bool> for Boolean {
+    fn zero() -> bool {
+        false
+    }
+    fn one() -> bool {
+        true
+    }
+    fn add(a: bool, b: bool) -> bool {
+        a || b
+    }
+    fn mul(a: &bool, b: &bool) -> bool {
+        *a && *b
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    pub fn mult_semiring<S: Semiring<T>>(
+        &self,
+        matrix_b: &Matrix<T>,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != matrix_b.rows {
+            return Err(product_mismatch(
+                (self.rows, self.cols),
+                (matrix_b.rows, matrix_b.cols),
+            ));
+        }
+
+        let len = checked_len(self.rows, matrix_b.cols)?;
+        let bt: Vec<T> = (0..matrix_b.cols)
+            .flat_map(|j| {
+                (0..matrix_b.rows).map(move |k| matrix_b.values[k * matrix_b.cols + j].clone())
+            })
+            .collect();
+
+        let mut new_values: Vec<T> = Vec::with_capacity(len);
+
+        for i in 0..self.rows {
+            for j in 0..matrix_b.cols {
+                let mut sum = S::zero();
+                for k in 0..self.cols {
+                    sum = S::add(
+                        sum,
+                        S::mul(&self.values[i * self.cols + k], &bt[j * matrix_b.rows + k]),
+                    );
+                }
+
+                new_values.push(sum);
+            }
+        }
+
+        #[cfg(feature = "profiling")]
+        crate::profile::record::<T>(
+            2 * self.rows * self.cols * matrix_b.cols,
+            self.values.len() + matrix_b.values.len() + new_values.len(),
+        );
+
+        Ok(Matrix::new(self.rows, matrix_b.cols, new_values))
+    }
+
+    pub fn identity_semiring<S: Semiring<T>>(order: usize) -> Result<Matrix<T>, MatrixError> {
+        let mut values: Vec<T> = vec![S::zero(); checked_len(order, order)?];
+
+        for i in 0..order {
+            values[i * order + i] = S::one();
+        }
+
+        Ok(Matrix::new(order, order, values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::semiring::{Boolean, MaxPlus, MinPlus};
+
+    #[test]
+    fn check_min_plus() {
+        let inf = f64::INFINITY;
+        let distances: Matrix<f64> =
+            Matrix::new(3, 3, vec![0.0, 4.0, inf, inf, 0.0, 1.0, 2.0, inf, 0.0]);
+        let expected_result: Matrix<f64> =
+            Matrix::new(3, 3, vec![0.0, 4.0, 5.0, 3.0, 0.0, 1.0, 2.0, 6.0, 0.0]);
+
+        let two_hops = distances.mult_semiring::<MinPlus>(&distances).unwrap();
+        let all_hops = two_hops.mult_semiring::<MinPlus>(&distances).unwrap();
+        assert_eq!(all_hops, expected_result);
+
+        let identity: Matrix<f64> = Matrix::identity_semiring::<MinPlus>(3).unwrap();
+        assert_eq!(
+            distances.mult_semiring::<MinPlus>(&identity).unwrap(),
+            distances
+        );
+
+        let longest = distances.mult_semiring::<MaxPlus>(&distances).unwrap();
+        assert_eq!(longest.value_at(0, 2).unwrap(), &inf);
+    }
+
+    #[test]
+    fn check_boolean_reachability() {
+        let edges: Matrix<bool> = Matrix::new(2, 2, vec![false, true, false, false]);
+        let reach = Matrix::identity_semiring::<Boolean>(2)
+            .unwrap()
+            .mult_semiring::<Boolean>(&edges)
+            .unwrap();
+
+        assert_eq!(reach, edges);
+        assert!(edges
+            .mult_semiring::<Boolean>(&edges)
+            .unwrap()
+            .get_values()
+            .iter()
+            .all(|v| !v));
+        assert!(edges
+            .mult_semiring::<Boolean>(&Matrix::new(1, 1, vec![true]))
+            .is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use crate::error::{MatrixError, Op};
+use crate::float::Float;
+use crate::matrix::{checked_len, Matrix};
+use crate::random::RandomSource;
+use crate::scalar::Scalar;
+
+impl<T: Scalar> Matrix<T> {
+    fn check_state(&self, x0: &[T]) -> Result<(), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare {
+                dims: (self.rows, self.cols),
+            });
+        }
+        if x0.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Step,
+                lhs: (self.rows, self.cols),
+                rhs: (x0.len(), 1),
+            });
+        }
+        Ok(())
+    }
+
+    /// Iterates `x_{k+1} = A x_k` from `x0`, returning a `steps`x`n` matrix
+    /// whose row `k` is `x_k`, so row 0 is `x0` itself.
+    pub fn simulate_linear_system(&self, x0: &[T], steps: usize) -> Result<Matrix<T>, MatrixError> {
+        self.simulate_with(x0, steps, |_| {})
+    }
+
+    fn simulate_with<F: FnMut(&mut [T])>(
+        &self,
+        x0: &[T],
+        steps: usize,
+        mut perturb: F,
+    ) -> Result<Matrix<T>, MatrixError> {
+        self.check_state(x0)?;
+        let n = self.cols;
+        let mut trajectory: Vec<T> = Vec::with_capacity(checked_len(steps, n)?);
+        if steps == 0 {
+            return Ok(Matrix::new(0, n, trajectory));
+        }
+        trajectory.extend_from_slice(x0);
+
+        for step in 1..steps {
+            let previous = &trajectory[(step - 1) * n..step * n];
+            let mut next: Vec<T> = (0..n)
+                .map(|i| {
+                    let row = &self.values[i * n..(i + 1) * n];
+                    let mut sum = T::zero();
+                    for (a, x) in row.iter().zip(previous) {
+                        sum += a.clone() * x.clone();
+                    }
+                    sum
+                })
+                .collect();
+            perturb(&mut next);
+            trajectory.extend(next);
+        }
+
+        Ok(Matrix::new(steps, n, trajectory))
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Iterates `x_{k+1} = A x_k + B w_k`, where each `w_k` has independent
+    /// standard normal entries drawn from `rng`. A generator in the same
+    /// state always reproduces the same trajectory.
+    pub fn simulate_linear_system_with_noise<R: RandomSource + ?Sized>(
+        &self,
+        x0: &[T],
+        steps: usize,
+        noise: &Matrix<T>,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixError> {
+        if noise.rows != self.rows {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.rows,
+                actual: noise.rows,
+            });
+        }
+
+        let mut w = vec![T::zero(); noise.cols];
+        self.simulate_with(x0, steps, |next| {
+            for value in w.iter_mut() {
+                *value = T::from_f64(rng.next_gaussian());
+            }
+            for (i, x) in next.iter_mut().enumerate() {
+                let row = &noise.values[i * noise.cols..(i + 1) * noise.cols];
+                for (b, &wj) in row.iter().zip(&w) {
+                    *x += *b * wj;
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn check_simulate_linear_system() {
+        let rotation: Matrix<i32> = Matrix::new(2, 2, vec![0, -1, 1, 0]);
+        let trajectory = rotation.simulate_linear_system(&[1, 0], 5).unwrap();
+
+        assert_eq!(
+            trajectory,
+            Matrix::new(5, 2, vec![1, 0, 0, 1, -1, 0, 0, -1, 1, 0])
+        );
+        assert_eq!(
+            rotation
+                .simulate_linear_system(&[1, 0], 0)
+                .unwrap()
+                .get_rows(),
+            0
+        );
+        assert!(rotation.simulate_linear_system(&[1, 0, 0], 3).is_err());
+        assert!(matches!(
+            Matrix::new(1, 2, vec![1, 0]).simulate_linear_system(&[1, 0], 3),
+            Err(MatrixError::NotSquare { dims: (1, 2) })
+        ));
+    }
+
+    #[test]
+    fn check_simulate_with_noise() {
+        let decay: Matrix<f64> = Matrix::new(1, 1, vec![0.5]);
+        let noise = Matrix::new(1, 1, vec![0.1]);
+        let a = decay
+            .simulate_linear_system_with_noise(&[1.0], 2000, &noise, &mut SplitMix64::new(7))
+            .unwrap();
+        let b = decay
+            .simulate_linear_system_with_noise(&[1.0], 2000, &noise, &mut SplitMix64::new(7))
+            .unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a.values[0], 1.0);
+        // The stationary variance of x = 0.5 x + 0.1 w is 0.01 / 0.75.
+        let tail = &a.values[100..];
+        let variance = tail.iter().map(|x| x * x).sum::<f64>() / tail.len() as f64;
+        assert!((variance - 0.01 / 0.75).abs() < 0.002);
+        assert!(decay
+            .simulate_linear_system_with_noise(
+                &[1.0],
+                3,
+                &Matrix::new(2, 1, vec![0.0; 2]),
+                &mut SplitMix64::new(7)
+            )
+            .is_err());
+    }
+}
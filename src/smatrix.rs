@@ -0,0 +1,206 @@
+use crate::error::{MatrixError, Op};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::ops::{Add, Mul, Sub};
+
+/// A stack-allocated `R`x`C` matrix whose dimensions are part of its type,
+/// so mismatched shapes in `+`, `-` and `*` are compile-time errors.
+///
+/// Elements must be `Copy`; convert to [`Matrix`] (and back with
+/// `SMatrix::try_from`) to use algorithms only the dynamic type provides.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(transparent)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    values: [[T; C]; R],
+}
+
+pub type SMatrix2<T> = SMatrix<T, 2, 2>;
+pub type SMatrix3<T> = SMatrix<T, 3, 3>;
+pub type SMatrix4<T> = SMatrix<T, 4, 4>;
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C>
+where
+    T: Scalar + Copy,
+{
+    pub fn new(values: [[T; C]; R]) -> SMatrix<T, R, C> {
+        SMatrix { values }
+    }
+
+    pub fn zeros() -> SMatrix<T, R, C> {
+        SMatrix::new([[T::zero(); C]; R])
+    }
+
+    pub fn get_rows(&self) -> usize {
+        R
+    }
+
+    pub fn get_cols(&self) -> usize {
+        C
+    }
+
+    pub fn get_values(&self) -> &[[T; C]; R] {
+        &self.values
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        if row < R && col < C {
+            Ok(&self.values[row][col])
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (R, C),
+            })
+        }
+    }
+
+    pub fn transpose(&self) -> SMatrix<T, C, R> {
+        let mut result = SMatrix::<T, C, R>::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                result.values[j][i] = self.values[i][j];
+            }
+        }
+        result
+    }
+
+    pub fn mult_scalar(&self, num: T) -> SMatrix<T, R, C> {
+        let mut result = *self;
+        for row in result.values.iter_mut() {
+            for value in row.iter_mut() {
+                *value = *value * num;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Scalar + Copy,
+{
+    pub fn identity() -> SMatrix<T, N, N> {
+        let mut result = SMatrix::zeros();
+        for i in 0..N {
+            result.values[i][i] = T::one();
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Add for SMatrix<T, R, C>
+where
+    T: Scalar + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn add(self, matrix_b: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        let mut result = self;
+        for i in 0..R {
+            for j in 0..C {
+                result.values[i][j] += matrix_b.values[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> Sub for SMatrix<T, R, C>
+where
+    T: Scalar + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(self, matrix_b: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        let mut result = self;
+        for i in 0..R {
+            for j in 0..C {
+                result.values[i][j] = self.values[i][j] - matrix_b.values[i][j];
+            }
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const K: usize, const C: usize> Mul<SMatrix<T, K, C>> for SMatrix<T, R, K>
+where
+    T: Scalar + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, matrix_b: SMatrix<T, K, C>) -> SMatrix<T, R, C> {
+        let mut result = SMatrix::<T, R, C>::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                let mut sum = T::zero();
+                for k in 0..K {
+                    sum += self.values[i][k] * matrix_b.values[k][j];
+                }
+                result.values[i][j] = sum;
+            }
+        }
+        result
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T>
+where
+    T: Scalar + Copy,
+{
+    fn from(matrix: SMatrix<T, R, C>) -> Matrix<T> {
+        Matrix::new(R, C, matrix.values.iter().flatten().copied().collect())
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<&Matrix<T>> for SMatrix<T, R, C>
+where
+    T: Scalar + Copy,
+{
+    type Error = MatrixError;
+
+    fn try_from(matrix: &Matrix<T>) -> Result<SMatrix<T, R, C>, MatrixError> {
+        if matrix.rows != R || matrix.cols != C {
+            return Err(MatrixError::DimensionMismatch {
+                op: Op::Convert,
+                lhs: (matrix.rows, matrix.cols),
+                rhs: (R, C),
+            });
+        }
+
+        let mut result = SMatrix::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                result.values[i][j] = matrix.values[i * C + j];
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::smatrix::{SMatrix, SMatrix2};
+
+    #[test]
+    fn check_smatrix_arithmetic() {
+        let a: SMatrix<i32, 2, 3> = SMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b: SMatrix<i32, 3, 2> = SMatrix::new([[7, 8], [9, 10], [11, 12]]);
+
+        assert_eq!(a * b, SMatrix::new([[58, 64], [139, 154]]));
+        assert_eq!(a + a, a.mult_scalar(2));
+        assert_eq!(a - a, SMatrix::zeros());
+        assert_eq!(a.transpose(), SMatrix::new([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!(SMatrix2::identity() * (a * b), a * b);
+        assert!(a.value_at(2, 0).is_err());
+    }
+
+    #[test]
+    fn check_smatrix_conversions() {
+        let a: SMatrix2<f64> = SMatrix::new([[1.0, 2.0], [3.0, 4.0]]);
+        let dynamic: Matrix<f64> = a.into();
+
+        assert_eq!(dynamic, Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(SMatrix2::try_from(&dynamic).unwrap(), a);
+        assert!(SMatrix::<f64, 3, 3>::try_from(&dynamic).is_err());
+    }
+}
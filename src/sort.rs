@@ -0,0 +1,97 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use std::cmp::Ordering;
+
+impl<T: Clone> Matrix<T> {
+    /// The row order that sorts the matrix by `compare`, without moving any
+    /// data. The sort is stable, so equal rows keep their relative order.
+    /// Floating-point keys can be compared with `f64::total_cmp`.
+    pub fn argsort_rows<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let mut order: Vec<usize> = (0..self.rows).collect();
+        order.sort_by(|&a, &b| compare(self.row_slice(a), self.row_slice(b)));
+        order
+    }
+
+    pub fn sort_rows_by<F>(&mut self, compare: F) -> &mut Self
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let order = self.argsort_rows(compare);
+        self.values = self.gather_rows(&order);
+        self
+    }
+
+    /// Sorts the rows by a key such as a timestamp column, e.g.
+    /// `matrix.sort_rows_by_key(|row| row[0])`.
+    pub fn sort_rows_by_key<K: Ord, F: FnMut(&[T]) -> K>(&mut self, mut key: F) -> &mut Self {
+        self.sort_rows_by(|a, b| key(a).cmp(&key(b)))
+    }
+
+    /// A new matrix whose row `i` is row `permutation[i]` of this one.
+    /// `permutation` must contain every row index exactly once.
+    pub fn permute_rows(&self, permutation: &[usize]) -> Result<Matrix<T>, MatrixError> {
+        if permutation.len() != self.rows {
+            return Err(MatrixError::LengthMismatch {
+                expected: self.rows,
+                actual: permutation.len(),
+            });
+        }
+        let mut seen = vec![false; self.rows];
+        for &row in permutation {
+            self.check_row(row)?;
+            if std::mem::replace(&mut seen[row], true) {
+                return Err(MatrixError::InvalidArgument(format!(
+                    "Row {} appears more than once in the permutation",
+                    row
+                )));
+            }
+        }
+
+        Ok(Matrix::new(
+            self.rows,
+            self.cols,
+            self.gather_rows(permutation),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_sort_rows() {
+        let mut matrix: Matrix<f64> =
+            Matrix::new(4, 2, vec![3.0, 0.1, 1.0, 0.2, 2.0, 0.3, 1.0, 0.4]);
+
+        let order = matrix.argsort_rows(|a, b| a[0].total_cmp(&b[0]));
+        assert_eq!(order, vec![1, 3, 2, 0]);
+        assert_eq!(
+            matrix.permute_rows(&order).unwrap(),
+            Matrix::new(4, 2, vec![1.0, 0.2, 1.0, 0.4, 2.0, 0.3, 3.0, 0.1])
+        );
+
+        matrix.sort_rows_by(|a, b| b[1].total_cmp(&a[1]));
+        assert_eq!(matrix.values, vec![1.0, 0.4, 2.0, 0.3, 1.0, 0.2, 3.0, 0.1]);
+
+        let mut ints: Matrix<i32> = Matrix::new(3, 1, vec![5, -2, 9]);
+        ints.sort_rows_by_key(|row| row[0].abs());
+        assert_eq!(ints.values, vec![-2, 5, 9]);
+    }
+
+    #[test]
+    fn check_permute_rows_validation() {
+        let matrix: Matrix<i32> = Matrix::new(3, 1, vec![1, 2, 3]);
+
+        assert!(matrix.permute_rows(&[0, 1]).is_err());
+        assert!(matrix.permute_rows(&[0, 1, 3]).is_err());
+        assert!(matrix.permute_rows(&[0, 1, 1]).is_err());
+        assert_eq!(
+            matrix.permute_rows(&[2, 0, 1]).unwrap().values,
+            vec![3, 1, 2]
+        );
+    }
+}
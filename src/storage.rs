@@ -0,0 +1,283 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A contiguous row-major buffer a [`Matrix`] can keep its elements in.
+///
+/// `Vec<T>` is the default; boxed slices, borrowed slices, `Cow` and the
+/// shared `Arc<Vec<T>>` of [`SharedMatrix`] are provided, and other buffers,
+/// such as device-side ones, can join by implementing this trait. Accessors
+/// and conversions below work for every storage, and in-place operations
+/// such as [`Matrix::add_mut`] and [`Matrix::swap_rows`] for every
+/// [`StorageMut`]. Arithmetic that builds a new matrix and the
+/// decompositions take the owned form, so a borrowed matrix is turned into
+/// one with [`Matrix::to_owned_matrix`].
+pub trait Storage<T> {
+    fn as_slice(&self) -> &[T];
+}
+
+/// A [`Storage`] whose elements can be written in place.
+pub trait StorageMut<T>: Storage<T> {
+    fn as_mut_slice(&mut self) -> &mut [T];
+}
+
+impl<T> Storage<T> for Vec<T> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut<T> for Vec<T> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Storage<T> for Box<[T]> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut<T> for Box<[T]> {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Storage<T> for &[T] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Storage<T> for &mut [T] {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> StorageMut<T> for &mut [T] {
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T: Clone> Storage<T> for Cow<'_, [T]> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Clone> StorageMut<T> for Cow<'_, [T]> {
+    /// Copies borrowed elements on the first write.
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.to_mut()
+    }
+}
+
+impl<T> Storage<T> for Arc<Vec<T>> {
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Clone> StorageMut<T> for Arc<Vec<T>> {
+    /// Copies the elements first if another clone still shares them.
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        let values: &mut Vec<T> = Arc::make_mut(self);
+        values
+    }
+}
+
+/// A matrix whose elements are reference counted, so `clone` is O(1) and
+/// clones can be handed to several threads or analyses for reading. The
+/// first write, through [`Matrix::value_at_mut`] or any in-place operation,
+/// copies the elements if they are still shared.
+pub type SharedMatrix<T> = Matrix<T, Arc<Vec<T>>>;
+
+impl<T> Matrix<T> {
+    /// Moves the elements behind an `Arc` without copying them.
+    pub fn into_shared(self) -> SharedMatrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            values: Arc::new(self.values),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Clone> SharedMatrix<T> {
+    /// Whether another clone currently shares these elements.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.values) > 1
+    }
+
+    /// The owned matrix, copying the elements only if they are shared.
+    pub fn into_owned(self) -> Matrix<T> {
+        let values = Arc::try_unwrap(self.values).unwrap_or_else(|shared| (*shared).clone());
+        Matrix::new(self.rows, self.cols, values)
+    }
+}
+
+impl<T, S: Storage<T>> Matrix<T, S> {
+    pub fn from_storage(rows: usize, cols: usize, values: S) -> Result<Matrix<T, S>, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        if values.as_slice().len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: values.as_slice().len(),
+            });
+        }
+
+        Ok(Matrix {
+            rows,
+            cols,
+            values,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Whether the matrix has no elements, i.e. either dimension is zero.
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.values.as_slice()
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.values
+    }
+
+    pub fn into_storage(self) -> S {
+        self.values
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<&T, MatrixError> {
+        if row < self.rows && col < self.cols {
+            let index = (row * self.cols) + col;
+            Ok(&self.as_slice()[index])
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (self.rows, self.cols),
+            })
+        }
+    }
+
+    /// A matrix borrowing this one's elements.
+    pub fn as_borrowed(&self) -> Matrix<T, &[T]> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            values: self.as_slice(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Copies the elements into an owned matrix, which every algorithm
+    /// accepts.
+    pub fn to_owned_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix::new(self.rows, self.cols, self.as_slice().to_vec())
+    }
+}
+
+impl<T, S: StorageMut<T>> Matrix<T, S> {
+    /// The row-major values. The shape cannot change through the slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.values.as_mut_slice()
+    }
+
+    pub fn value_at_mut(&mut self, row: usize, col: usize) -> Result<&mut T, MatrixError> {
+        if row < self.rows && col < self.cols {
+            let index = (row * self.cols) + col;
+            Ok(&mut self.values.as_mut_slice()[index])
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (self.rows, self.cols),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use std::borrow::Cow;
+
+    #[test]
+    fn check_borrowed_storage() {
+        let values = [1, 2, 3, 4, 5, 6];
+        let borrowed = Matrix::from_storage(2, 3, &values[..]).unwrap();
+        assert_eq!(borrowed.value_at(1, 0).unwrap(), &4);
+        assert!(borrowed.value_at(2, 0).is_err());
+        assert!(Matrix::from_storage(4, 2, &values[..]).is_err());
+
+        let owned = borrowed.to_owned_matrix();
+        assert_eq!(owned.transpose().as_slice(), [1, 4, 2, 5, 3, 6]);
+        assert_eq!(owned.as_borrowed(), borrowed);
+
+        let mut buffer = values;
+        let mut in_place = Matrix::from_storage(3, 2, &mut buffer[..]).unwrap();
+        *in_place.value_at_mut(2, 1).unwrap() = 0;
+        assert_eq!(buffer, [1, 2, 3, 4, 5, 0]);
+    }
+
+    #[test]
+    fn check_cow_storage() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+        let mut cow = Matrix::from_storage(2, 2, Cow::Borrowed(&values[..])).unwrap();
+        assert!(matches!(cow.storage(), Cow::Borrowed(_)));
+
+        *cow.value_at_mut(0, 0).unwrap() = 9.0;
+        assert!(matches!(cow.storage(), Cow::Owned(_)));
+        assert_eq!(cow.as_slice(), [9.0, 2.0, 3.0, 4.0]);
+        assert_eq!(values[0], 1.0);
+
+        let mut swapped = Matrix::from_storage(2, 2, Cow::Borrowed(&values[..])).unwrap();
+        swapped.swap_rows(0, 1).unwrap();
+        assert_eq!(swapped.as_slice(), [3.0, 4.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn check_shared_storage() {
+        let matrix: Matrix<i32> = Matrix::new(2, 2, vec![1, 2, 3, 4]);
+        let shared = matrix.clone().into_shared();
+        let mut copy = shared.clone();
+        assert!(shared.is_shared());
+        assert!(std::ptr::eq(shared.as_slice(), copy.as_slice()));
+
+        *copy.value_at_mut(0, 0).unwrap() = 9;
+        assert!(!shared.is_shared());
+        assert_eq!(shared.as_slice(), [1, 2, 3, 4]);
+        assert_eq!(copy.as_slice(), [9, 2, 3, 4]);
+
+        let mut scaled = shared.clone();
+        scaled.scale_row(1, 10).unwrap().add_mut(&matrix).unwrap();
+        assert_eq!(scaled.as_slice(), [2, 4, 33, 44]);
+        assert_eq!(shared.as_slice(), [1, 2, 3, 4]);
+        drop(scaled);
+
+        let address = shared.as_slice().as_ptr();
+        let owned = shared.into_owned();
+        assert_eq!(owned.as_slice().as_ptr(), address);
+        assert_eq!(owned, matrix);
+    }
+}
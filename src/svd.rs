@@ -0,0 +1,195 @@
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+
+const MAX_SWEEPS: usize = 60;
+
+/// A thin singular value decomposition `A = U diag(s) V^T`, with the
+/// singular values in non-increasing order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd<T> {
+    u: Matrix<T>,
+    singular_values: Vec<T>,
+    v_t: Matrix<T>,
+}
+
+impl<T: Float> Svd<T> {
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    pub fn singular_values(&self) -> &[T] {
+        &self.singular_values
+    }
+
+    pub fn v_t(&self) -> &Matrix<T> {
+        &self.v_t
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// One-sided Jacobi SVD. Slower than bidiagonalization methods but
+    /// simple and accurate to high relative precision, even for tiny
+    /// singular values. Fails with [`MatrixError::NonConvergent`] if some
+    /// pair of columns is still far from orthogonal after `MAX_SWEEPS`
+    /// sweeps.
+    pub fn svd(&self) -> Result<Svd<T>, MatrixError> {
+        if self.rows < self.cols {
+            let transposed = self.transpose().svd()?;
+            return Ok(Svd {
+                u: transposed.v_t.transpose(),
+                singular_values: transposed.singular_values,
+                v_t: transposed.u.transpose(),
+            });
+        }
+
+        let (m, n) = (self.rows, self.cols);
+        let mut u = self.clone();
+        let mut v: Matrix<T> = Matrix::identity_within(n);
+        let column_dot = |matrix: &Matrix<T>, a: usize, b: usize| {
+            (0..matrix.rows).fold(T::zero(), |acc, i| {
+                acc + matrix.values[i * matrix.cols + a] * matrix.values[i * matrix.cols + b]
+            })
+        };
+
+        for sweep in 0..=MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let alpha = column_dot(&u, p, p);
+                    let beta = column_dot(&u, q, q);
+                    let gamma = column_dot(&u, p, q);
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    if sweep == MAX_SWEEPS {
+                        return Err(MatrixError::NonConvergent {
+                            iterations: MAX_SWEEPS,
+                            residual: (gamma.abs() / (alpha * beta).sqrt()).to_f64(),
+                        });
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = T::one() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let t = if zeta < T::zero() { -t } else { t };
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    for matrix in [&mut u, &mut v] {
+                        let cols = matrix.cols;
+                        for i in 0..matrix.rows {
+                            let a = matrix.values[i * cols + p];
+                            let b = matrix.values[i * cols + q];
+                            matrix.values[i * cols + p] = c * a - s * b;
+                            matrix.values[i * cols + q] = s * a + c * b;
+                        }
+                    }
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<T> = (0..n).map(|j| column_dot(&u, j, j).sqrt()).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            norms[b]
+                .partial_cmp(&norms[a])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut u_sorted = Matrix::new(m, n, vec![T::zero(); m * n]);
+        let mut v_t = Matrix::new(n, n, vec![T::zero(); n * n]);
+        for (target, &source) in order.iter().enumerate() {
+            let norm = norms[source];
+            for i in 0..m {
+                if norm > T::zero() {
+                    u_sorted.values[i * n + target] = u.values[i * n + source] / norm;
+                }
+            }
+            for i in 0..n {
+                v_t.values[target * n + i] = v.values[i * n + source];
+            }
+        }
+
+        Ok(Svd {
+            u: u_sorted,
+            singular_values: order.iter().map(|&j| norms[j]).collect(),
+            v_t,
+        })
+    }
+
+    /// The Moore-Penrose pseudo-inverse. Singular values at or below
+    /// `max(m, n) * epsilon * largest` are treated as zero.
+    pub fn pseudo_inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        let svd = self.svd()?;
+        let (m, n) = (self.rows, self.cols);
+        let k = svd.singular_values.len();
+        let largest = svd.singular_values.first().copied().unwrap_or(T::zero());
+        let cutoff = T::from_f64(m.max(n) as f64) * T::epsilon() * largest;
+
+        let mut result = Matrix::new(n, m, vec![T::zero(); n * m]);
+        for (l, &s) in svd.singular_values.iter().enumerate() {
+            if s <= cutoff {
+                continue;
+            }
+            for i in 0..n {
+                let scaled = svd.v_t.values[l * n + i] / s;
+                for j in 0..m {
+                    result.values[i * m + j] += scaled * svd.u.values[j * k + l];
+                }
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn check_svd() {
+        let matrix: Matrix<f64> = Matrix::new(2, 3, vec![3.0, 2.0, 2.0, 2.0, 3.0, -2.0]);
+        let svd = matrix.svd().unwrap();
+
+        let expected = [5.0, 3.0];
+        for (s, e) in svd.singular_values().iter().zip(expected) {
+            assert!((s - e).abs() < 1e-12);
+        }
+
+        let mut scaled = svd.u().clone();
+        for i in 0..2 {
+            for j in 0..2 {
+                scaled.values[i * 2 + j] *= svd.singular_values()[j];
+            }
+        }
+        let product = scaled.mult_naive(svd.v_t()).unwrap();
+        for (a, b) in product.values.iter().zip(&matrix.values) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert!(matches!(
+            Matrix::new(2, 2, vec![1.0, f64::NAN, 0.0, 1.0]).svd(),
+            Err(MatrixError::NonConvergent { iterations: 60, .. })
+        ));
+    }
+
+    #[test]
+    fn check_pseudo_inverse() {
+        let matrix: Matrix<f64> = Matrix::new(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        let pinv = matrix.pseudo_inverse().unwrap();
+
+        let roundtrip = matrix
+            .mult_naive(&pinv)
+            .unwrap()
+            .mult_naive(&matrix)
+            .unwrap();
+        for (a, b) in roundtrip.values.iter().zip(&matrix.values) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert!((pinv.values[0] - 1.0 / 70.0).abs() < 1e-12);
+    }
+}
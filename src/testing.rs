@@ -0,0 +1,2 @@
+pub mod arbitrary;
+pub mod golden;
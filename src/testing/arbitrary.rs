@@ -0,0 +1,144 @@
+use crate::matrix::Matrix;
+use crate::random::{RandomSource, SplitMix64};
+use std::panic::{self, AssertUnwindSafe};
+
+/// A value that can be generated at random for property tests.
+pub trait Arbitrary: Sized {
+    fn arbitrary<R: RandomSource + ?Sized>(rng: &mut R) -> Self;
+}
+
+/// Integers in `-9..=9`, small enough that sums of products over test-sized
+/// shapes cannot overflow.
+macro_rules! impl_arbitrary_int {
+    ($($t:ty),*) => {
+        $(
+            impl Arbitrary for $t {
+                fn arbitrary<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+                    (rng.next_u64() % 19) as $t - 9
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_int!(i16, i32, i64, isize);
+
+/// Uniform on `[-1, 1)`.
+macro_rules! impl_arbitrary_float {
+    ($($t:ty),*) => {
+        $(
+            impl Arbitrary for $t {
+                fn arbitrary<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+                    (2.0 * rng.next_f64() - 1.0) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary_float!(f32, f64);
+
+/// The shapes random matrices are drawn from; each dimension is uniform
+/// on `0..=max`, so empty matrices are covered too.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeStrategy {
+    pub max_rows: usize,
+    pub max_cols: usize,
+}
+
+impl Default for ShapeStrategy {
+    fn default() -> ShapeStrategy {
+        ShapeStrategy {
+            max_rows: 6,
+            max_cols: 6,
+        }
+    }
+}
+
+impl ShapeStrategy {
+    pub fn sample<R: RandomSource + ?Sized>(&self, rng: &mut R) -> (usize, usize) {
+        let mut dimension = |max: usize| match max.checked_add(1) {
+            Some(count) => (rng.next_u64() % count as u64) as usize,
+            None => rng.next_u64() as usize,
+        };
+        (dimension(self.max_rows), dimension(self.max_cols))
+    }
+}
+
+impl<T: Arbitrary> Matrix<T> {
+    /// A matrix of the given shape with arbitrary elements.
+    pub fn arbitrary_with_shape<R: RandomSource + ?Sized>(
+        rows: usize,
+        cols: usize,
+        rng: &mut R,
+    ) -> Matrix<T> {
+        Matrix::from_fn(rows, cols, |_, _| T::arbitrary(rng))
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Matrix<T> {
+    /// A matrix with a shape from the default [`ShapeStrategy`].
+    fn arbitrary<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        let (rows, cols) = ShapeStrategy::default().sample(rng);
+        Matrix::arbitrary_with_shape(rows, cols, rng)
+    }
+}
+
+/// Runs `property` for `cases` independent cases, each with its own
+/// generator seeded from `seed`. A failing case panics again with its seed
+/// in the message, so it can be replayed with `for_all(1, that_seed, ...)`.
+pub fn for_all<F: FnMut(&mut SplitMix64)>(cases: usize, seed: u64, mut property: F) {
+    for case in 0..cases as u64 {
+        let case_seed = seed.wrapping_add(case);
+        let mut rng = SplitMix64::new(case_seed);
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| property(&mut rng))) {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("non-string panic payload");
+            panic!("property failed for case seed {}: {:?}", case_seed, message);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::testing::arbitrary::{for_all, Arbitrary, ShapeStrategy};
+
+    #[test]
+    fn check_transpose_of_product() {
+        let shapes = ShapeStrategy::default();
+        for_all(200, 42, |rng| {
+            let (m, k) = shapes.sample(rng);
+            let n = shapes.sample(rng).1;
+            let a: Matrix<i64> = Matrix::arbitrary_with_shape(m, k, rng);
+            let b: Matrix<i64> = Matrix::arbitrary_with_shape(k, n, rng);
+            assert_eq!(
+                a.mult(&b).unwrap().transpose(),
+                b.transpose().mult(&a.transpose()).unwrap()
+            );
+        });
+    }
+
+    #[test]
+    fn check_arbitrary_values() {
+        for_all(50, 0, |rng| {
+            let matrix = Matrix::<f64>::arbitrary(rng);
+            assert!(matrix.get_rows() <= 6 && matrix.get_cols() <= 6);
+            assert!(matrix.values.iter().all(|v| (-1.0..1.0).contains(v)));
+            assert!((-9..=9).contains(&i32::arbitrary(rng)));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "property failed for case seed 12: \"third case\"")]
+    fn check_failing_seed_in_panic() {
+        let mut calls = 0;
+        for_all(5, 10, |_| {
+            calls += 1;
+            assert!(calls < 3, "third case");
+        });
+    }
+}
@@ -0,0 +1,268 @@
+use crate::dtype::DType;
+use crate::error::MatrixError;
+use crate::float::Float;
+use crate::matrix::Matrix;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// While set, [`check`] writes every fixture instead of comparing, to
+/// create new fixtures or accept an intentional change in results.
+pub const UPDATE_VAR: &str = "LINRUST_UPDATE_GOLDEN";
+
+/// Entries match when `|actual - expected| <= absolute + relative * |expected|`.
+/// NaNs match each other, so a fixture can pin down where NaNs appear.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Tolerance {
+        Tolerance {
+            absolute: 1e-12,
+            relative: 1e-9,
+        }
+    }
+}
+
+impl Tolerance {
+    fn accepts(&self, expected: f64, actual: f64) -> bool {
+        if expected.is_nan() || actual.is_nan() {
+            return expected.is_nan() && actual.is_nan();
+        }
+        expected == actual
+            || (actual - expected).abs() <= self.absolute + self.relative * expected.abs()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryDiff {
+    pub row: usize,
+    pub col: usize,
+    pub expected: f64,
+    pub actual: f64,
+}
+
+/// Why a matrix differs from its fixture.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Diff {
+    Shape {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// Every entry outside the tolerance, in row-major order.
+    Entries {
+        entries: Vec<EntryDiff>,
+        total: usize,
+    },
+}
+
+/// Entries listed before a [`Diff`] summarizes the rest.
+const SHOWN_ENTRIES: usize = 10;
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diff::Shape { expected, actual } => write!(
+                f,
+                "expected a {}x{} matrix, got {}x{}",
+                expected.0, expected.1, actual.0, actual.1
+            ),
+            Diff::Entries { entries, total } => {
+                write!(f, "{} of {} entries differ", entries.len(), total)?;
+                for entry in entries.iter().take(SHOWN_ENTRIES) {
+                    write!(
+                        f,
+                        "\n  ({}, {}): expected {:e}, got {:e} (off by {:e})",
+                        entry.row,
+                        entry.col,
+                        entry.expected,
+                        entry.actual,
+                        (entry.actual - entry.expected).abs()
+                    )?;
+                }
+                if entries.len() > SHOWN_ENTRIES {
+                    write!(f, "\n  ... and {} more", entries.len() - SHOWN_ENTRIES)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GoldenError {
+    /// The fixture could not be read or written.
+    Matrix(MatrixError),
+    /// There is no fixture yet and [`UPDATE_VAR`] is not set.
+    Missing(PathBuf),
+    Mismatch(Diff),
+}
+
+impl fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GoldenError::Matrix(err) => write!(f, "{}", err),
+            GoldenError::Missing(path) => write!(
+                f,
+                "Missing Golden File: {} (set {} to create it)",
+                path.display(),
+                UPDATE_VAR
+            ),
+            GoldenError::Mismatch(diff) => write!(f, "Golden Mismatch: {}", diff),
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {}
+
+impl From<MatrixError> for GoldenError {
+    fn from(err: MatrixError) -> Self {
+        GoldenError::Matrix(err)
+    }
+}
+
+/// The entries of `actual` that differ from `expected`, or `None` if all
+/// match.
+pub fn compare<T: Float>(
+    expected: &Matrix<T>,
+    actual: &Matrix<T>,
+    tolerance: Tolerance,
+) -> Option<Diff> {
+    if (expected.rows, expected.cols) != (actual.rows, actual.cols) {
+        return Some(Diff::Shape {
+            expected: (expected.rows, expected.cols),
+            actual: (actual.rows, actual.cols),
+        });
+    }
+
+    let cols = expected.cols.max(1);
+    let entries: Vec<EntryDiff> = expected
+        .values
+        .iter()
+        .zip(&actual.values)
+        .enumerate()
+        .filter(|(_, (e, a))| !tolerance.accepts(e.to_f64(), a.to_f64()))
+        .map(|(index, (e, a))| EntryDiff {
+            row: index / cols,
+            col: index % cols,
+            expected: e.to_f64(),
+            actual: a.to_f64(),
+        })
+        .collect();
+
+    if entries.is_empty() {
+        None
+    } else {
+        Some(Diff::Entries {
+            entries,
+            total: expected.values.len(),
+        })
+    }
+}
+
+/// Writes `actual` as the fixture at `path`, creating parent directories.
+pub fn update<T: DType>(path: impl AsRef<Path>, actual: &Matrix<T>) -> Result<(), MatrixError> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, actual.to_bytes())?;
+    Ok(())
+}
+
+/// Compares `actual` against the fixture at `path`, a matrix in the
+/// versioned format of [`Matrix::to_bytes`]. While [`UPDATE_VAR`] is set
+/// the fixture is written and accepted instead; otherwise a missing one is
+/// a [`GoldenError::Missing`], so a typo in `path` cannot pass silently.
+pub fn check<T: DType + Float>(
+    path: impl AsRef<Path>,
+    actual: &Matrix<T>,
+    tolerance: Tolerance,
+) -> Result<(), GoldenError> {
+    let path = path.as_ref();
+    if std::env::var_os(UPDATE_VAR).is_some() {
+        return Ok(update(path, actual)?);
+    }
+    if !path.exists() {
+        return Err(GoldenError::Missing(path.to_path_buf()));
+    }
+
+    let expected = Matrix::from_bytes(&std::fs::read(path).map_err(MatrixError::from)?)?;
+    match compare(&expected, actual, tolerance) {
+        Some(diff) => Err(GoldenError::Mismatch(diff)),
+        None => Ok(()),
+    }
+}
+
+/// [`check`] for use in tests, panicking with a readable diff that names
+/// the fixture.
+#[track_caller]
+pub fn assert_golden<T: DType + Float>(
+    path: impl AsRef<Path>,
+    actual: &Matrix<T>,
+    tolerance: Tolerance,
+) {
+    let path = path.as_ref();
+    if let Err(err) = check(path, actual, tolerance) {
+        panic!("golden file {}: {}", path.display(), err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::testing::golden::{self, Diff, GoldenError, Tolerance};
+
+    #[test]
+    fn check_golden_round_trip() {
+        let path = std::env::temp_dir().join("linrust_golden/check_golden_round_trip.lrmx");
+        let _ = std::fs::remove_file(&path);
+        let expected: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 2.0, f64::NAN, 4.0]);
+
+        assert!(matches!(
+            golden::check(&path, &expected, Tolerance::default()),
+            Err(GoldenError::Missing(missing)) if missing == path
+        ));
+        golden::update(&path, &expected).unwrap();
+        golden::check(&path, &expected, Tolerance::default()).unwrap();
+        let nudged = Matrix::new(2, 2, vec![1.0 + 1e-13, 2.0, f64::NAN, 4.0]);
+        golden::assert_golden(&path, &nudged, Tolerance::default());
+
+        let changed = Matrix::new(2, 2, vec![1.0, 2.5, 3.0, 4.0]);
+        let err = golden::check(&path, &changed, Tolerance::default()).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        match &err {
+            GoldenError::Mismatch(Diff::Entries { entries, total }) => {
+                assert_eq!((entries.len(), *total), (2, 4));
+                assert_eq!((entries[0].row, entries[0].col), (0, 1));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert!(err
+            .to_string()
+            .contains("\n  (0, 1): expected 2e0, got 2.5e0 (off by 5e-1)"));
+    }
+
+    #[test]
+    fn check_golden_compare() {
+        let a: Matrix<f32> = Matrix::new(1, 2, vec![100.0, 0.0]);
+        let b: Matrix<f32> = Matrix::new(1, 2, vec![100.5, 0.0]);
+        let loose = Tolerance {
+            absolute: 0.0,
+            relative: 1e-2,
+        };
+
+        assert!(golden::compare(&a, &b, loose).is_none());
+        assert!(golden::compare(&a, &b, Tolerance::default()).is_some());
+        assert_eq!(
+            golden::compare(&a, &a.transpose(), loose),
+            Some(Diff::Shape {
+                expected: (1, 2),
+                actual: (2, 1)
+            })
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use std::fmt;
+
+const SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+/// A physical dimension as integer exponents of the seven SI base units.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Unit {
+    exponents: [i8; 7],
+}
+
+impl Unit {
+    pub const DIMENSIONLESS: Unit = Unit::new([0, 0, 0, 0, 0, 0, 0]);
+    pub const METER: Unit = Unit::new([1, 0, 0, 0, 0, 0, 0]);
+    pub const KILOGRAM: Unit = Unit::new([0, 1, 0, 0, 0, 0, 0]);
+    pub const SECOND: Unit = Unit::new([0, 0, 1, 0, 0, 0, 0]);
+    pub const AMPERE: Unit = Unit::new([0, 0, 0, 1, 0, 0, 0]);
+    pub const KELVIN: Unit = Unit::new([0, 0, 0, 0, 1, 0, 0]);
+    pub const MOLE: Unit = Unit::new([0, 0, 0, 0, 0, 1, 0]);
+    pub const CANDELA: Unit = Unit::new([0, 0, 0, 0, 0, 0, 1]);
+
+    /// Exponents in the order m, kg, s, A, K, mol, cd.
+    pub const fn new(exponents: [i8; 7]) -> Unit {
+        Unit { exponents }
+    }
+
+    pub fn exponents(&self) -> [i8; 7] {
+        self.exponents
+    }
+
+    fn combine(
+        self,
+        other: Unit,
+        op: &str,
+        f: fn(i8, i8) -> Option<i8>,
+    ) -> Result<Unit, MatrixError> {
+        let mut exponents = self.exponents;
+        for (e, o) in exponents.iter_mut().zip(other.exponents) {
+            *e = f(*e, o).ok_or_else(|| {
+                MatrixError::InvalidArgument(format!(
+                    "An exponent of {} {} {} is out of range",
+                    self, op, other
+                ))
+            })?;
+        }
+        Ok(Unit::new(exponents))
+    }
+
+    /// The unit of a product. Fails if an exponent leaves the `i8` range.
+    pub fn checked_mul(self, other: Unit) -> Result<Unit, MatrixError> {
+        self.combine(other, "*", i8::checked_add)
+    }
+
+    pub fn checked_div(self, other: Unit) -> Result<Unit, MatrixError> {
+        self.combine(other, "/", i8::checked_sub)
+    }
+
+    pub fn checked_powi(self, n: i8) -> Result<Unit, MatrixError> {
+        let mut exponents = self.exponents;
+        for e in exponents.iter_mut() {
+            *e = e.checked_mul(n).ok_or_else(|| {
+                MatrixError::InvalidArgument(format!(
+                    "An exponent of ({})^{} is out of range",
+                    self, n
+                ))
+            })?;
+        }
+        Ok(Unit::new(exponents))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .exponents
+            .iter()
+            .zip(SYMBOLS)
+            .filter(|(e, _)| **e != 0)
+            .map(|(e, symbol)| match e {
+                1 => symbol.to_string(),
+                _ => format!("{}^{}", symbol, e),
+            })
+            .collect();
+
+        if parts.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", parts.join(" "))
+        }
+    }
+}
+
+/// A matrix whose elements all share one physical unit.
+///
+/// Addition and subtraction require equal units and multiplication combines
+/// them, so a dimensional mistake surfaces as a `UnitMismatch` instead of a
+/// silently wrong number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuantityMatrix<T> {
+    matrix: Matrix<T>,
+    unit: Unit,
+}
+
+impl<T: Scalar> QuantityMatrix<T> {
+    pub fn new(matrix: Matrix<T>, unit: Unit) -> QuantityMatrix<T> {
+        QuantityMatrix { matrix, unit }
+    }
+
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    pub fn unit(&self) -> Unit {
+        self.unit
+    }
+
+    fn check_unit(&self, matrix_b: &QuantityMatrix<T>) -> Result<(), MatrixError> {
+        if self.unit != matrix_b.unit {
+            return Err(MatrixError::UnitMismatch {
+                lhs: self.unit,
+                rhs: matrix_b.unit,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn add(&self, matrix_b: &QuantityMatrix<T>) -> Result<QuantityMatrix<T>, MatrixError> {
+        self.check_unit(matrix_b)?;
+        Ok(QuantityMatrix::new(
+            self.matrix.add(&matrix_b.matrix)?,
+            self.unit,
+        ))
+    }
+
+    pub fn subtract(&self, matrix_b: &QuantityMatrix<T>) -> Result<QuantityMatrix<T>, MatrixError> {
+        self.check_unit(matrix_b)?;
+        Ok(QuantityMatrix::new(
+            self.matrix.subtract(&matrix_b.matrix)?,
+            self.unit,
+        ))
+    }
+
+    pub fn mult_naive(
+        &self,
+        matrix_b: &QuantityMatrix<T>,
+    ) -> Result<QuantityMatrix<T>, MatrixError> {
+        Ok(QuantityMatrix::new(
+            self.matrix.mult_naive(&matrix_b.matrix)?,
+            self.unit.checked_mul(matrix_b.unit)?,
+        ))
+    }
+
+    pub fn mult_scalar(&self, num: T, unit: Unit) -> Result<QuantityMatrix<T>, MatrixError> {
+        let unit = self.unit.checked_mul(unit)?;
+        let mut matrix = self.matrix.clone();
+        matrix.mult_scalar(num);
+        Ok(QuantityMatrix::new(matrix, unit))
+    }
+
+    pub fn transpose(&self) -> QuantityMatrix<T> {
+        QuantityMatrix::new(self.matrix.transpose(), self.unit)
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<(&T, Unit), MatrixError> {
+        Ok((self.matrix.value_at(row, col)?, self.unit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::matrix::Matrix;
+    use crate::units::{QuantityMatrix, Unit};
+
+    #[test]
+    fn check_unit_algebra() {
+        let newton = Unit::KILOGRAM
+            .checked_mul(Unit::METER)
+            .unwrap()
+            .checked_div(Unit::SECOND.checked_powi(2).unwrap())
+            .unwrap();
+
+        assert_eq!(newton.to_string(), "m kg s^-2");
+        assert_eq!(Unit::DIMENSIONLESS.to_string(), "1");
+        assert_eq!(newton.checked_div(newton).unwrap(), Unit::DIMENSIONLESS);
+
+        let large = Unit::METER.checked_powi(100).unwrap();
+        assert!(large.checked_mul(large).is_err());
+        assert!(Unit::new([-128, 0, 0, 0, 0, 0, 0])
+            .checked_powi(-1)
+            .is_err());
+        assert!(Unit::DIMENSIONLESS
+            .checked_div(Unit::new([-128, 0, 0, 0, 0, 0, 0]))
+            .is_err());
+    }
+
+    #[test]
+    fn check_quantity_matrix() {
+        let lengths = QuantityMatrix::new(Matrix::new(1, 2, vec![1.0, 2.0]), Unit::METER);
+        let times = QuantityMatrix::new(Matrix::new(1, 2, vec![3.0, 4.0]), Unit::SECOND);
+
+        assert!(matches!(
+            lengths.add(&times),
+            Err(MatrixError::UnitMismatch { .. })
+        ));
+        assert_eq!(lengths.add(&lengths).unwrap().unit(), Unit::METER);
+
+        let area = lengths.mult_naive(&lengths.transpose()).unwrap();
+        assert_eq!(area.unit(), Unit::METER.checked_powi(2).unwrap());
+        assert_eq!(
+            area.value_at(0, 0).unwrap(),
+            (&5.0, Unit::METER.checked_powi(2).unwrap())
+        );
+
+        let speed = lengths
+            .mult_scalar(0.5, Unit::SECOND.checked_powi(-1).unwrap())
+            .unwrap();
+        assert_eq!(speed.unit().to_string(), "m s^-1");
+        assert_eq!(speed.matrix(), &Matrix::new(1, 2, vec![0.5, 1.0]));
+    }
+}
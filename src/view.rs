@@ -0,0 +1,461 @@
+use crate::error::MatrixError;
+use crate::matrix::{checked_len, Matrix};
+use crate::scalar::Scalar;
+use crate::storage::Storage;
+use std::ops::Range;
+
+/// A borrowed, possibly strided window into a matrix's values.
+///
+/// Strides are signed, so a view may walk its rows or columns backwards
+/// (e.g. a horizontally flipped block) without copying anything.
+///
+/// A view is a shared borrow, so it is `Copy`, and `Send + Sync` whenever
+/// `T: Sync`: any number of threads may read disjoint or overlapping views
+/// of one matrix at once, for example each computing one block of a
+/// product.
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    values: &'a [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: isize,
+    col_stride: isize,
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+/// Fails to compile if a change to `MatrixView` ever loses `Send + Sync`.
+const _: fn() = || {
+    fn assert_send_sync<V: Send + Sync>() {}
+    assert_send_sync::<MatrixView<'static, f64>>();
+};
+
+impl<T, S: Storage<T>> Matrix<T, S> {
+    pub fn view(
+        &self,
+        row_range: Range<usize>,
+        col_range: Range<usize>,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        if row_range.start > row_range.end
+            || col_range.start > col_range.end
+            || row_range.end > self.rows
+            || col_range.end > self.cols
+        {
+            return Err(MatrixError::RangeOutOfBounds {
+                rows: row_range,
+                cols: col_range,
+                dims: (self.rows, self.cols),
+            });
+        }
+
+        Ok(MatrixView {
+            values: self.as_slice(),
+            offset: row_range.start * self.cols + col_range.start,
+            rows: row_range.len(),
+            cols: col_range.len(),
+            row_stride: self.cols as isize,
+            col_stride: 1,
+        })
+    }
+
+    /// Views `rows`x`cols` elements starting at (`row`, `col`) and stepping
+    /// `row_step`/`col_step` between them. Negative steps walk backwards, so
+    /// `view_strided(n - 1, 0, n, m, -1, 1)` is the matrix flipped upside down.
+    pub fn view_strided(
+        &self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+        row_step: isize,
+        col_step: isize,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        let in_bounds = |start: usize, len: usize, step: isize, dim: usize| {
+            if len == 0 {
+                return true;
+            }
+            let last = isize::try_from(len - 1)
+                .ok()
+                .and_then(|n| n.checked_mul(step))
+                .and_then(|n| n.checked_add(start as isize));
+            match last {
+                Some(last) => start < dim && last >= 0 && (last as usize) < dim,
+                None => false,
+            }
+        };
+
+        if !in_bounds(row, rows, row_step, self.rows) || !in_bounds(col, cols, col_step, self.cols)
+        {
+            return Err(MatrixError::StridedOutOfBounds {
+                start: (row, col),
+                shape: (rows, cols),
+                steps: (row_step, col_step),
+                dims: (self.rows, self.cols),
+            });
+        }
+
+        Ok(MatrixView {
+            values: self.as_slice(),
+            offset: row * self.cols + col,
+            rows,
+            cols,
+            row_stride: row_step.checked_mul(self.cols as isize).ok_or_else(|| {
+                MatrixError::SizeOverflow(format!(
+                    "Row step {} overflows for matrix with {} columns",
+                    row_step, self.cols
+                ))
+            })?,
+            col_stride: col_step,
+        })
+    }
+
+    /// Views of consecutive blocks of `chunk_size` rows, top to bottom; the
+    /// last block holds whatever rows remain. Lets a large matrix be
+    /// processed block by block without offset arithmetic.
+    pub fn row_chunks(
+        &self,
+        chunk_size: usize,
+    ) -> Result<impl Iterator<Item = MatrixView<'_, T>> + '_, MatrixError> {
+        if chunk_size == 0 {
+            return Err(MatrixError::InvalidArgument(
+                "Row chunk size must be at least 1".to_string(),
+            ));
+        }
+        Ok((0..self.rows)
+            .step_by(chunk_size)
+            .map(move |start| self.row_block(start, chunk_size.min(self.rows - start))))
+    }
+
+    /// Rows `start..start + rows`, which the caller has bounds-checked.
+    pub(crate) fn row_block(&self, start: usize, rows: usize) -> MatrixView<'_, T> {
+        MatrixView {
+            values: self.as_slice(),
+            offset: start * self.cols,
+            rows,
+            cols: self.cols,
+            row_stride: self.cols as isize,
+            col_stride: 1,
+        }
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Views a borrowed row-major buffer, such as one filled by a C library
+    /// or a memory-mapped file, as a `rows`x`cols` matrix without copying.
+    pub fn from_slice(
+        values: &'a [T],
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        if values.len() != len {
+            return Err(MatrixError::LengthMismatch {
+                expected: len,
+                actual: values.len(),
+            });
+        }
+
+        Ok(MatrixView {
+            values,
+            offset: 0,
+            rows,
+            cols,
+            row_stride: cols as isize,
+            col_stride: 1,
+        })
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, aligned and point to `rows * cols`
+    /// initialized values of `T` that stay valid and unmodified for `'a`,
+    /// with the same requirements as [`std::slice::from_raw_parts`].
+    pub unsafe fn from_raw_parts(
+        ptr: *const T,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        let len = checked_len(rows, cols)?;
+        // SAFETY: the caller guarantees `ptr` is valid for `len` reads for `'a`.
+        let values = unsafe { std::slice::from_raw_parts(ptr, len) };
+        MatrixView::from_slice(values, rows, cols)
+    }
+
+    /// Views element (`i`, `j`) at `values[i * row_stride + j * col_stride]`,
+    /// so padded rows (a BLAS leading dimension), every other row or column,
+    /// or a column-major buffer (`row_stride = 1`, `col_stride = rows`) can
+    /// be read without copying. Strides may overlap; a stride of 0 repeats
+    /// a row or column.
+    pub fn from_strided(
+        values: &'a [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        let overflow = || {
+            MatrixError::SizeOverflow(format!(
+                "Strides ({}, {}) overflow for a {}x{} view",
+                row_stride, col_stride, rows, cols
+            ))
+        };
+        checked_len(rows, cols)?;
+        if rows > 0 && cols > 0 {
+            let last = (rows - 1)
+                .checked_mul(row_stride)
+                .zip((cols - 1).checked_mul(col_stride))
+                .and_then(|(a, b)| a.checked_add(b))
+                .ok_or_else(overflow)?;
+            if last >= values.len() {
+                return Err(MatrixError::LengthMismatch {
+                    expected: last + 1,
+                    actual: values.len(),
+                });
+            }
+        }
+
+        Ok(MatrixView {
+            values,
+            offset: 0,
+            rows,
+            cols,
+            row_stride: isize::try_from(row_stride).map_err(|_| overflow())?,
+            col_stride: isize::try_from(col_stride).map_err(|_| overflow())?,
+        })
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must be non-null, aligned and point to `len` initialized values
+    /// of `T` that stay valid and unmodified for `'a`, with the same
+    /// requirements as [`std::slice::from_raw_parts`].
+    pub unsafe fn from_strided_raw_parts(
+        ptr: *const T,
+        len: usize,
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        // SAFETY: the caller guarantees `ptr` is valid for `len` reads for `'a`.
+        let values = unsafe { std::slice::from_raw_parts(ptr, len) };
+        MatrixView::from_strided(values, rows, cols, row_stride, col_stride)
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn get_cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows == 0 || self.cols == 0
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        (self.offset as isize + row as isize * self.row_stride + col as isize * self.col_stride)
+            as usize
+    }
+
+    /// Element (`row`, `col`), which the caller has bounds-checked.
+    pub(crate) fn element(&self, row: usize, col: usize) -> &'a T {
+        &self.values[self.index(row, col)]
+    }
+
+    pub fn value_at(&self, row: usize, col: usize) -> Result<&'a T, MatrixError> {
+        if row < self.rows && col < self.cols {
+            Ok(self.element(row, col))
+        } else {
+            Err(MatrixError::IndexOutOfBounds {
+                index: (row, col),
+                dims: (self.rows, self.cols),
+            })
+        }
+    }
+
+    pub fn flip_rows(&self) -> MatrixView<'a, T> {
+        let mut view = *self;
+        if self.rows > 0 {
+            view.offset = self.index(self.rows - 1, 0);
+        }
+        view.row_stride = -self.row_stride;
+        view
+    }
+
+    pub fn flip_cols(&self) -> MatrixView<'a, T> {
+        let mut view = *self;
+        if self.cols > 0 {
+            view.offset = self.index(0, self.cols - 1);
+        }
+        view.col_stride = -self.col_stride;
+        view
+    }
+
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            values: self.values,
+            offset: self.offset,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.rows)
+            .flat_map(move |i| (0..self.cols).map(move |j| &self.values[self.index(i, j)]))
+    }
+}
+
+impl<T: Scalar> MatrixView<'_, T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::new(self.rows, self.cols, self.iter().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::MatrixError;
+    use crate::expr::MatrixExpr;
+    use crate::matrix::Matrix;
+    use crate::view::MatrixView;
+
+    #[test]
+    fn check_view() {
+        let matrix: Matrix<i32> = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let view = matrix.view(1..3, 0..2).unwrap();
+        let expected_result: Matrix<i32> = Matrix::new(2, 2, vec![4, 5, 7, 8]);
+
+        assert_eq!(view.to_matrix(), expected_result);
+        assert_eq!(view.value_at(1, 1).unwrap(), &8);
+        assert!(view.value_at(2, 0).is_err());
+        assert!(matches!(
+            matrix.view(0..4, 0..1),
+            Err(MatrixError::RangeOutOfBounds { dims: (3, _), .. })
+        ));
+    }
+
+    #[test]
+    fn check_row_chunks() {
+        let matrix: Matrix<i32> = Matrix::new(5, 2, (0..10).collect());
+        let blocks: Vec<Matrix<i32>> = matrix
+            .row_chunks(2)
+            .unwrap()
+            .map(|view| view.to_matrix())
+            .collect();
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1], Matrix::new(2, 2, vec![4, 5, 6, 7]));
+        assert_eq!(blocks[2], Matrix::new(1, 2, vec![8, 9]));
+        assert_eq!(matrix.row_chunks(10).unwrap().count(), 1);
+        assert!(matrix.row_chunks(0).is_err());
+    }
+
+    #[test]
+    fn check_reversed_views() {
+        let matrix: Matrix<i32> = Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let flipped = matrix.view(0..2, 1..3).unwrap().flip_cols();
+        assert_eq!(flipped.to_matrix(), Matrix::new(2, 2, vec![3, 2, 6, 5]));
+
+        let upside_down = matrix.view_strided(2, 0, 3, 3, -1, 1).unwrap();
+        assert_eq!(
+            upside_down.to_matrix(),
+            Matrix::new(3, 3, vec![7, 8, 9, 4, 5, 6, 1, 2, 3])
+        );
+        assert_eq!(upside_down.flip_rows().to_matrix(), matrix);
+
+        let corners = matrix.view_strided(2, 2, 2, 2, -2, -2).unwrap();
+        assert_eq!(corners.to_matrix(), Matrix::new(2, 2, vec![9, 7, 3, 1]));
+        assert!(matches!(
+            matrix.view_strided(1, 0, 3, 1, -1, 1),
+            Err(MatrixError::StridedOutOfBounds { steps: (-1, 1), .. })
+        ));
+
+        let transposed = matrix.view(0..3, 0..2).unwrap().flip_rows().transpose();
+        assert_eq!(
+            transposed.to_matrix(),
+            Matrix::new(2, 3, vec![7, 4, 1, 8, 5, 2])
+        );
+    }
+
+    #[test]
+    fn check_borrowed_buffers() {
+        let buffer = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let view = MatrixView::from_slice(&buffer, 2, 3).unwrap();
+        assert_eq!(view.transpose().value_at(2, 1).unwrap(), &6.0);
+        assert!(MatrixView::from_slice(&buffer, 4, 2).is_err());
+
+        let raw = unsafe { MatrixView::from_raw_parts(buffer.as_ptr(), 3, 2) }.unwrap();
+        assert_eq!(raw.to_matrix(), Matrix::new(3, 2, buffer.to_vec()));
+
+        let mut matrix = raw.to_matrix();
+        matrix.as_mut_slice()[0] = 10.0;
+        assert_eq!(matrix.as_slice()[..2], [10.0, 2.0]);
+        assert_eq!(matrix.into_vec().len(), 6);
+    }
+
+    #[test]
+    fn check_strided_buffers() {
+        let padded = [1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9];
+        let view = MatrixView::from_strided(&padded, 3, 3, 4, 1).unwrap();
+        assert_eq!(
+            view.to_matrix(),
+            Matrix::new(3, 3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9])
+        );
+
+        let every_other = MatrixView::from_strided(&padded, 2, 2, 8, 2).unwrap();
+        assert_eq!(every_other.to_matrix(), Matrix::new(2, 2, vec![1, 3, 7, 9]));
+
+        let transposed = MatrixView::from_strided(&padded, 3, 3, 1, 4).unwrap();
+        assert_eq!(transposed.to_matrix(), view.transpose().to_matrix());
+
+        let raw = unsafe {
+            MatrixView::from_strided_raw_parts(padded.as_ptr(), padded.len(), 2, 3, 0, 1)
+        }
+        .unwrap();
+        assert_eq!(raw.to_matrix(), Matrix::new(2, 3, vec![1, 2, 3, 1, 2, 3]));
+
+        assert!(MatrixView::from_strided(&padded, 3, 4, 4, 1).is_err());
+        assert!(MatrixView::from_strided(&padded, 2, 2, usize::MAX, 1).is_err());
+        assert!(MatrixView::from_strided(&padded[..0], 0, 5, 4, 1).is_ok());
+    }
+
+    #[test]
+    fn check_concurrent_views() {
+        let a: Matrix<i64> = Matrix::new(6, 4, (0..24).collect());
+        let b: Matrix<i64> = Matrix::new(4, 6, (0..24).map(|v| v % 5 - 2).collect());
+        let expected = a.mult(&b).unwrap();
+
+        // Every thread reads overlapping views of `a` and `b` and computes
+        // one 3x3 block of the product.
+        let blocks: Vec<((usize, usize), Matrix<i64>)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = [(0, 0), (0, 3), (3, 0), (3, 3)]
+                .into_iter()
+                .map(|(i, j)| {
+                    let rows = a.view(i..i + 3, 0..4).unwrap();
+                    let cols = b.view(0..4, j..j + 3).unwrap();
+                    scope.spawn(move || ((i, j), rows.mult(cols).eval().unwrap()))
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        for ((i, j), block) in blocks {
+            assert_eq!(
+                block,
+                expected.view(i..i + 3, j..j + 3).unwrap().to_matrix()
+            );
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    Grayscale,
+    Viridis,
+    Coolwarm,
+}
+
+const VIRIDIS: [(f64, f64, f64); 5] = [
+    (68.0, 1.0, 84.0),
+    (59.0, 82.0, 139.0),
+    (33.0, 145.0, 140.0),
+    (94.0, 201.0, 98.0),
+    (253.0, 231.0, 37.0),
+];
+
+const COOLWARM: [(f64, f64, f64); 3] = [
+    (59.0, 76.0, 192.0),
+    (221.0, 221.0, 221.0),
+    (180.0, 4.0, 38.0),
+];
+
+fn interpolate(stops: &[(f64, f64, f64)], t: f64) -> [u8; 3] {
+    let scaled = t * (stops.len() - 1) as f64;
+    let i = (scaled.floor() as usize).min(stops.len() - 2);
+    let frac = scaled - i as f64;
+    let (r0, g0, b0) = stops[i];
+    let (r1, g1, b1) = stops[i + 1];
+    [
+        (r0 + (r1 - r0) * frac).round() as u8,
+        (g0 + (g1 - g0) * frac).round() as u8,
+        (b0 + (b1 - b0) * frac).round() as u8,
+    ]
+}
+
+impl Colormap {
+    /// Maps `t` in `[0, 1]` to an RGB colour. NaN renders as magenta so that
+    /// it stands out from every colormap.
+    pub fn color(&self, t: f64) -> [u8; 3] {
+        if t.is_nan() {
+            return [255, 0, 255];
+        }
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Colormap::Grayscale => {
+                let v = (t * 255.0).round() as u8;
+                [v, v, v]
+            }
+            Colormap::Viridis => interpolate(&VIRIDIS, t),
+            Colormap::Coolwarm => interpolate(&COOLWARM, t),
+        }
+    }
+}
+
+impl<T: Clone + Into<f64>> Matrix<T> {
+    fn colors(&self, colormap: Colormap) -> Vec<[u8; 3]> {
+        let values: Vec<f64> = self.values.iter().map(|v| v.clone().into()).collect();
+        let finite = values.iter().filter(|v| v.is_finite());
+        let min = finite.clone().fold(f64::INFINITY, |a, b| a.min(*b));
+        let max = finite.fold(f64::NEG_INFINITY, |a, b| a.max(*b));
+        let range = if max > min { max - min } else { 1.0 };
+
+        values
+            .iter()
+            .map(|v| {
+                let t = if v.is_nan() {
+                    f64::NAN
+                } else if max > min {
+                    (v - min) / range
+                } else {
+                    0.5
+                };
+                colormap.color(t)
+            })
+            .collect()
+    }
+
+    pub fn to_svg(&self, colormap: Colormap) -> String {
+        let cell = 10;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            self.cols * cell,
+            self.rows * cell,
+            self.cols * cell,
+            self.rows * cell
+        );
+
+        for (index, [r, g, b]) in self.colors(colormap).into_iter().enumerate() {
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                (index % self.cols) * cell,
+                (index / self.cols) * cell,
+                cell,
+                cell,
+                r,
+                g,
+                b
+            ));
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn to_heatmap_png<P: AsRef<Path>>(
+        &self,
+        path: P,
+        colormap: Colormap,
+    ) -> Result<(), MatrixError> {
+        if self.rows == 0 || self.cols == 0 {
+            return Err(MatrixError::EmptyMatrix);
+        }
+
+        let cell = (512 / self.rows.max(self.cols)).max(1);
+        let width = self.cols * cell;
+        let height = self.rows * cell;
+        let colors = self.colors(colormap);
+
+        let mut pixels = Vec::with_capacity(height * (width * 3 + 1));
+        for y in 0..height {
+            pixels.push(0);
+            let row = y / cell;
+            for x in 0..width {
+                pixels.extend_from_slice(&colors[row * self.cols + x / cell]);
+            }
+        }
+
+        let mut file = File::create(path)?;
+        file.write_all(&encode_png(width as u32, height as u32, &pixels))?;
+        Ok(())
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+// Uncompressed (stored-block) deflate keeps the encoder dependency-free;
+// heatmaps are small enough that the size does not matter.
+fn encode_png(width: u32, height: u32, scanlines: &[u8]) -> Vec<u8> {
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+    push_chunk(&mut png, b"IHDR", &header);
+
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = scanlines.chunks(65535).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none() as u8;
+        let len = block.len() as u16;
+        zlib.push(last);
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(scanlines).to_be_bytes());
+    push_chunk(&mut png, b"IDAT", &zlib);
+
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::Matrix;
+    use crate::visualize::{crc32, Colormap};
+
+    #[test]
+    fn check_colormaps() {
+        assert_eq!(Colormap::Grayscale.color(0.0), [0, 0, 0]);
+        assert_eq!(Colormap::Grayscale.color(1.0), [255, 255, 255]);
+        assert_eq!(Colormap::Viridis.color(1.0), [253, 231, 37]);
+        assert_eq!(Colormap::Coolwarm.color(0.5), [221, 221, 221]);
+        assert_eq!(Colormap::Viridis.color(f64::NAN), [255, 0, 255]);
+    }
+
+    #[test]
+    fn check_svg() {
+        let matrix: Matrix<i32> = Matrix::new(1, 2, vec![0, 10]);
+        let svg = matrix.to_svg(Colormap::Grayscale);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"10\" height=\"10\" fill=\"#000000\"/>"));
+        assert!(
+            svg.contains("<rect x=\"10\" y=\"0\" width=\"10\" height=\"10\" fill=\"#ffffff\"/>")
+        );
+    }
+
+    #[test]
+    fn check_png() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+
+        let matrix: Matrix<f64> = Matrix::new(2, 2, vec![0.0, 1.0, 2.0, 3.0]);
+        let path = std::env::temp_dir().join("linrust_check_png.png");
+        matrix.to_heatmap_png(&path, Colormap::Viridis).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            &bytes[..8],
+            &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+        );
+        assert_eq!(&bytes[16..24], &[0, 0, 2, 0, 0, 0, 2, 0]);
+    }
+}